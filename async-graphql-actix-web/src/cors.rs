@@ -0,0 +1,196 @@
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{self, HeaderMap, HeaderValue};
+use actix_web::http::Method;
+use actix_web::{Error, HttpResponse};
+use futures::future::{ok, FutureExt, LocalBoxFuture, Ready};
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// CORS middleware for the GraphQL endpoint
+///
+/// Answers `OPTIONS` preflight requests and adds the `Access-Control-*` headers to the responses of
+/// cross-origin `GET` and `POST` requests. By default any origin is allowed, along with the
+/// `Content-Type` and `Authorization` request headers.
+///
+/// Preflight requests are answered by the middleware, so use route guards rather than a resource
+/// guard, which would reject `OPTIONS` requests before they reach it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{web, App, HttpResponse};
+/// use async_graphql_actix_web::GQLCors;
+///
+/// async fn index() -> HttpResponse {
+///     HttpResponse::Ok().finish()
+/// }
+///
+/// App::new().service(
+///     web::resource("/")
+///         .wrap(
+///             GQLCors::new()
+///                 .allowed_origin("https://example.com")
+///                 .allowed_header("X-Request-Id")
+///                 .max_age(3600),
+///         )
+///         .route(web::post().to(index)),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct GQLCors {
+    inner: Rc<Inner>,
+}
+
+struct Inner {
+    origins: Option<HashSet<String>>,
+    headers: Vec<String>,
+    max_age: Option<usize>,
+}
+
+impl Default for GQLCors {
+    fn default() -> Self {
+        GQLCors {
+            inner: Rc::new(Inner {
+                origins: None,
+                headers: vec!["Content-Type".to_string(), "Authorization".to_string()],
+                max_age: None,
+            }),
+        }
+    }
+}
+
+impl GQLCors {
+    /// Create a CORS middleware that allows any origin.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Allow requests from this origin.
+    ///
+    /// Once an origin has been added, requests from any other origin will not get CORS headers.
+    pub fn allowed_origin(mut self, origin: impl Into<String>) -> Self {
+        Rc::get_mut(&mut self.inner)
+            .expect("Multiple copies exist")
+            .origins
+            .get_or_insert_with(Default::default)
+            .insert(origin.into());
+        self
+    }
+
+    /// Allow this request header in addition to `Content-Type` and `Authorization`.
+    pub fn allowed_header(mut self, name: impl Into<String>) -> Self {
+        Rc::get_mut(&mut self.inner)
+            .expect("Multiple copies exist")
+            .headers
+            .push(name.into());
+        self
+    }
+
+    /// Set how long (in seconds) the result of a preflight request can be cached.
+    pub fn max_age(mut self, seconds: usize) -> Self {
+        Rc::get_mut(&mut self.inner)
+            .expect("Multiple copies exist")
+            .max_age = Some(seconds);
+        self
+    }
+}
+
+impl Inner {
+    fn allowed_origin(&self, req: &ServiceRequest) -> Option<HeaderValue> {
+        let origin = req.headers().get(header::ORIGIN)?;
+        match &self.origins {
+            Some(origins) if !origins.contains(origin.to_str().ok()?) => None,
+            _ => Some(origin.clone()),
+        }
+    }
+
+    fn add_headers(&self, headers: &mut HeaderMap, origin: HeaderValue) {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+        headers.insert(header::VARY, HeaderValue::from_static("Origin"));
+    }
+
+    fn add_preflight_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_METHODS,
+            HeaderValue::from_static("GET, POST, OPTIONS"),
+        );
+        if let Ok(value) = HeaderValue::from_str(&self.headers.join(", ")) {
+            headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, value);
+        }
+        if let Some(max_age) = self.max_age {
+            headers.insert(header::ACCESS_CONTROL_MAX_AGE, max_age.into());
+        }
+    }
+}
+
+impl<S, B> Transform<S> for GQLCors
+where
+    S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Request = ServiceRequest;
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = GQLCorsMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(GQLCorsMiddleware {
+            service,
+            inner: self.inner.clone(),
+        })
+    }
+}
+
+#[doc(hidden)]
+pub struct GQLCorsMiddleware<S> {
+    service: S,
+    inner: Rc<Inner>,
+}
+
+impl<S, B> Service for GQLCorsMiddleware<S>
+where
+    S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Request = ServiceRequest;
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: ServiceRequest) -> Self::Future {
+        let inner = self.inner.clone();
+        let origin = inner.allowed_origin(&req);
+
+        if req.method() == Method::OPTIONS
+            && req
+                .headers()
+                .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD)
+        {
+            let mut res = HttpResponse::NoContent().finish();
+            if let Some(origin) = origin {
+                inner.add_headers(res.headers_mut(), origin);
+                inner.add_preflight_headers(res.headers_mut());
+            }
+            return ok(req.into_response(res.into_body())).boxed_local();
+        }
+
+        let fut = self.service.call(req);
+        async move {
+            let mut res = fut.await?;
+            if let Some(origin) = origin {
+                inner.add_headers(res.headers_mut(), origin);
+            }
+            Ok(res)
+        }
+        .boxed_local()
+    }
+}
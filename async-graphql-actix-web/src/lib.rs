@@ -1,31 +1,99 @@
 //! Async-graphql integration with Actix-web
+//!
+//! ## Feature flags
+//!
+//! * `actix-web-2` (default) - Use actix-web 2, actix 0.9 and actix-web-actors 2.
+//! * `actix-web-3` - Use actix-web 3, actix 0.10 and actix-web-actors 3. Disable the default
+//!   features to avoid building actix-web 2 as well, it takes precedence if both are enabled.
 
 #![warn(missing_docs)]
+#![allow(clippy::type_complexity)]
+#![allow(clippy::needless_doctest_main)]
 #![forbid(unsafe_code)]
 
+#[cfg(not(any(feature = "actix-web-2", feature = "actix-web-3")))]
+compile_error!("one of the `actix-web-2` and `actix-web-3` features must be enabled");
+
+#[cfg(feature = "actix-web-3")]
+extern crate actix010 as actix;
+#[cfg(feature = "actix-web-3")]
+extern crate actix_http2 as actix_http;
+#[cfg(feature = "actix-web-3")]
+extern crate actix_web3 as actix_web;
+#[cfg(feature = "actix-web-3")]
+extern crate actix_web_actors3 as actix_web_actors;
+
+mod cors;
+mod service;
 mod subscription;
 
-use actix_web::body::BodyStream;
+use actix_web::body::{Body, BodyStream};
 use actix_web::dev::{HttpResponseBuilder, Payload, PayloadStream};
+use actix_web::error::PayloadError;
+use actix_web::http::header::HeaderValue;
 use actix_web::http::StatusCode;
 use actix_web::{http, web, Error, FromRequest, HttpRequest, HttpResponse, Responder};
 use async_graphql::http::{multipart_stream, StreamBody};
+use async_graphql::parser::query::OperationType;
 use async_graphql::{
-    IntoQueryBuilder, IntoQueryBuilderOpts, ParseRequestError, QueryBuilder, QueryResponse,
-    StreamResponse,
+    BatchQueryBuilder, BatchQueryResponse, CacheControl, IntoBatchQueryBuilder, IntoQueryBuilder,
+    IntoQueryBuilderOpts, ObjectType, ParseRequestError, QueryBuilder, QueryError, QueryResponse,
+    Schema, StreamResponse, SubscriptionType,
 };
+use bytes::Bytes;
+pub use cors::{GQLCors, GQLCorsMiddleware};
 use futures::channel::mpsc;
 use futures::future::Ready;
-use futures::{Future, SinkExt, StreamExt, TryFutureExt};
+use futures::{Future, SinkExt, Stream, StreamExt, TryFutureExt};
 use http::Method;
+pub use service::GQLService;
 use std::convert::Infallible;
+use std::net::SocketAddr;
 use std::pin::Pin;
-pub use subscription::WSSubscription;
+use std::sync::Arc;
+pub use subscription::{WSConnectionLimit, WSSubscription};
 
 /// Extractor for GraphQL request
 ///
 /// It's a wrapper of `QueryBuilder`, you can use `GQLRequest::into_inner` unwrap it to `QueryBuilder`.
 /// `async_graphql::IntoQueryBuilderOpts` allows to configure extraction process.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{guard, web, App, HttpServer};
+/// use async_graphql::*;
+/// use async_graphql_actix_web::{GQLRequest, GQLResponse};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// type MySchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+///
+/// async fn index(schema: web::Data<MySchema>, req: GQLRequest) -> GQLResponse {
+///     req.into_inner().execute(&schema).await.into()
+/// }
+///
+/// #[actix_rt::main]
+/// async fn main() -> std::io::Result<()> {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     HttpServer::new(move || {
+///         App::new()
+///             .data(schema.clone())
+///             .service(web::resource("/").guard(guard::Post()).to(index))
+///             .service(web::resource("/").guard(guard::Get()).to(index))
+///     })
+///     .bind("127.0.0.1:8000")?
+///     .run()
+///     .await
+/// }
+/// ```
 pub struct GQLRequest(QueryBuilder);
 
 impl GQLRequest {
@@ -43,53 +111,371 @@ impl FromRequest for GQLRequest {
     fn from_request(req: &HttpRequest, payload: &mut Payload<PayloadStream>) -> Self::Future {
         let config = req.app_data::<Self::Config>().cloned().unwrap_or_default();
 
+        let req = req.clone();
+
+        if let Err(err) = check_csrf(&req).and_then(|_| response_content_type(&req).map(|_| ())) {
+            return Box::pin(futures::future::err(err));
+        }
+
         if req.method() == Method::GET {
-            let res = web::Query::<async_graphql::http::GQLRequest>::from_query(req.query_string());
+            let res = query_string_request(&req);
             Box::pin(async move {
-                let gql_request = res?;
-                gql_request
-                    .into_inner()
+                let gql_request = res.map_err(|err| parse_request_error(&req, err))?;
+                let builder = gql_request
                     .into_query_builder_opts(&config)
-                    .map_ok(GQLRequest)
-                    .map_err(actix_web::error::ErrorBadRequest)
-                    .await
+                    .map_err(|err| parse_request_error(&req, err))
+                    .await?;
+                let operation_type = builder.operation_type();
+                check_get_mutation(&req, operation_type)?;
+                if operation_type == Some(OperationType::Query) {
+                    req.extensions_mut().insert(CacheableRequest);
+                }
+                Ok(GQLRequest(request_data(&req, builder)))
             })
         } else {
-            let content_type = req
+            let body = request_body(&req, payload);
+            Box::pin(async move {
+                body.into_query_builder_opts(&config)
+                    .map_ok(|builder| GQLRequest(request_data(&req, builder)))
+                    .map_err(|err| parse_request_error(&req, err))
+                    .await
+            })
+        }
+    }
+}
+
+/// Information about the client connection
+///
+/// It is added to the context data of every query extracted with `GQLRequest` or `GQLBatchRequest`,
+/// and of every subscription started with `WSSubscription`, so resolvers can access it with
+/// `Context::data`.
+#[derive(Debug, Clone)]
+pub struct GQLConnectionInfo {
+    /// The address of the peer of the socket, may be a proxy.
+    pub remote_addr: Option<SocketAddr>,
+
+    /// The address of the client from the `Forwarded` or `X-Forwarded-For` headers if present,
+    /// otherwise the address of the peer.
+    ///
+    /// The headers are set by the client, so this address can be spoofed. Only use it when the
+    /// server is behind a proxy that overwrites these headers.
+    pub forwarded_remote_addr: Option<String>,
+
+    /// The addresses in the `X-Forwarded-For` header, can be spoofed like `forwarded_remote_addr`.
+    pub forwarded_for: Vec<String>,
+
+    /// The scheme of the request, such as `http` or `https`.
+    pub scheme: String,
+
+    /// The host of the request.
+    pub host: String,
+}
+
+impl GQLConnectionInfo {
+    /// Create the connection information of a request.
+    pub fn new(req: &HttpRequest) -> Self {
+        let info = req.connection_info();
+        #[cfg(not(feature = "actix-web-3"))]
+        let forwarded_remote_addr = info.remote();
+        #[cfg(feature = "actix-web-3")]
+        let forwarded_remote_addr = info.realip_remote_addr();
+        GQLConnectionInfo {
+            remote_addr: req.peer_addr(),
+            forwarded_remote_addr: forwarded_remote_addr.map(ToString::to_string),
+            forwarded_for: req
                 .headers()
-                .get(http::header::CONTENT_TYPE)
-                .and_then(|value| value.to_str().ok())
-                .map(|value| value.to_string());
+                .get_all("x-forwarded-for")
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(|addr| addr.trim().to_string())
+                .filter(|addr| !addr.is_empty())
+                .collect(),
+            scheme: info.scheme().to_string(),
+            host: info.host().to_string(),
+        }
+    }
+}
 
-            let (mut tx, rx) = mpsc::channel(16);
+/// Hook that adds context data from the incoming request
+///
+/// It is called with the `HttpRequest` and the `QueryBuilder` of every query extracted with
+/// `GQLRequest` or `GQLBatchRequest`, so values such as the session or the identity of the user
+/// (with `actix-session` or `actix-identity`) can be added to the context data once for all
+/// handlers. Register this hook with `App::app_data`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{App, HttpRequest};
+/// use async_graphql::QueryBuilder;
+/// use async_graphql_actix_web::GQLRequestData;
+///
+/// struct UserId(String);
+///
+/// App::new().app_data(GQLRequestData::new(|req: &HttpRequest, builder: QueryBuilder| {
+///     match req.headers().get("x-user-id").and_then(|value| value.to_str().ok()) {
+///         Some(user_id) => builder.data(UserId(user_id.to_string())),
+///         None => builder,
+///     }
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct GQLRequestData(Arc<dyn Fn(&HttpRequest, QueryBuilder) -> QueryBuilder>);
 
-            // Because Payload is !Send, so forward it to mpsc::Sender
-            let mut payload = web::Payload(payload.take());
-            actix_rt::spawn(async move {
-                while let Some(item) = payload.next().await {
-                    if tx.send(item).await.is_err() {
-                        return;
-                    }
-                }
-            });
+impl GQLRequestData {
+    /// Create a hook with a function that returns the `QueryBuilder` with the added context data.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&HttpRequest, QueryBuilder) -> QueryBuilder + 'static,
+    {
+        GQLRequestData(Arc::new(f))
+    }
+}
 
+fn request_data(req: &HttpRequest, builder: QueryBuilder) -> QueryBuilder {
+    let builder = builder.data(GQLConnectionInfo::new(req));
+    match req.app_data::<GQLRequestData>() {
+        Some(GQLRequestData(f)) => f(req, builder),
+        None => builder,
+    }
+}
+
+/// Handler for health check requests
+///
+/// Executes `{ __typename }` against the schema registered with `App::data`, and responds with
+/// `200 OK` if it succeeds or `503 Service Unavailable` if it fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{web, App};
+/// use async_graphql::*;
+/// use async_graphql_actix_web::health_check;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[field(desc = "Returns the sum of a and b")]
+///     async fn add(&self, a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// App::new()
+///     .data(Schema::new(QueryRoot, EmptyMutation, EmptySubscription))
+///     .service(
+///         web::resource("/health")
+///             .to(health_check::<QueryRoot, EmptyMutation, EmptySubscription>),
+///     );
+/// ```
+pub async fn health_check<Query, Mutation, Subscription>(
+    schema: web::Data<Schema<Query, Mutation, Subscription>>,
+) -> HttpResponse
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    match schema.execute("{ __typename }").await {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(_) => HttpResponse::ServiceUnavailable().finish(),
+    }
+}
+
+/// Extractor for GraphQL batch request
+///
+/// Similar to `GQLRequest`, but the request body can be a single operation or a list of operations.
+pub struct GQLBatchRequest(BatchQueryBuilder);
+
+impl GQLBatchRequest {
+    /// Unwrap it to `BatchQueryBuilder`.
+    pub fn into_inner(self) -> BatchQueryBuilder {
+        self.0
+    }
+}
+
+impl FromRequest for GQLBatchRequest {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<GQLBatchRequest, Error>>>>;
+    type Config = IntoQueryBuilderOpts;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload<PayloadStream>) -> Self::Future {
+        if req.method() == Method::GET {
+            let fut = GQLRequest::from_request(req, payload);
             Box::pin(async move {
-                (content_type, StreamBody::new(rx))
-                    .into_query_builder_opts(&config)
-                    .map_ok(GQLRequest)
-                    .map_err(|err| match err {
-                        ParseRequestError::PayloadTooLarge => {
-                            actix_web::error::ErrorPayloadTooLarge(err)
-                        }
-                        _ => actix_web::error::ErrorBadRequest(err),
+                let GQLRequest(builder) = fut.await?;
+                Ok(GQLBatchRequest(BatchQueryBuilder::Single(builder)))
+            })
+        } else {
+            if let Err(err) = check_csrf(req).and_then(|_| response_content_type(req).map(|_| ())) {
+                return Box::pin(futures::future::err(err));
+            }
+            let config = req.app_data::<Self::Config>().cloned().unwrap_or_default();
+            let body = request_body(req, payload);
+            let req = req.clone();
+            Box::pin(async move {
+                body.into_batch_query_builder_opts(&config)
+                    .map_ok(|builder| {
+                        GQLBatchRequest(match builder {
+                            BatchQueryBuilder::Single(builder) => {
+                                BatchQueryBuilder::Single(request_data(&req, builder))
+                            }
+                            BatchQueryBuilder::Batch(builders) => BatchQueryBuilder::Batch(
+                                builders
+                                    .into_iter()
+                                    .map(|builder| request_data(&req, builder))
+                                    .collect(),
+                            ),
+                        })
                     })
+                    .map_err(|err| parse_request_error(&req, err))
                     .await
             })
         }
     }
 }
 
+fn request_body(
+    req: &HttpRequest,
+    payload: &mut Payload<PayloadStream>,
+) -> (
+    Option<String>,
+    StreamBody<mpsc::Receiver<Result<Bytes, PayloadError>>>,
+) {
+    let content_type = req
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    let (mut tx, rx) = mpsc::channel(16);
+
+    // Because Payload is !Send, so forward it to mpsc::Sender
+    let mut payload = web::Payload(payload.take());
+    actix_rt::spawn(async move {
+        while let Some(item) = payload.next().await {
+            if tx.send(item).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    (content_type, StreamBody::new(rx))
+}
+
+fn query_string_request(
+    req: &HttpRequest,
+) -> Result<async_graphql::http::GQLRequest, ParseRequestError> {
+    async_graphql::http::parse_query_string(req.query_string())
+}
+
+fn check_csrf(req: &HttpRequest) -> Result<(), Error> {
+    let csrf = match req.app_data::<GQLCsrfPrevention>() {
+        Some(csrf) => csrf,
+        None => return Ok(()),
+    };
+    let non_simple_content_type = req
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|essence| essence.trim().to_ascii_lowercase())
+        .map(|essence| {
+            !essence.is_empty()
+                && !matches!(
+                    essence.as_str(),
+                    "application/x-www-form-urlencoded" | "multipart/form-data" | "text/plain"
+                )
+        })
+        .unwrap_or_default();
+    if non_simple_content_type
+        || csrf
+            .headers
+            .iter()
+            .any(|name| req.headers().contains_key(name.as_str()))
+    {
+        Ok(())
+    } else {
+        Err(actix_web::error::ErrorBadRequest(format!(
+            "This operation has been blocked as a potential Cross-Site Request Forgery (CSRF). \
+             Please either specify a 'content-type' header that is not 'text/plain', \
+             'application/x-www-form-urlencoded' or 'multipart/form-data', \
+             or provide one of the following headers: {}",
+            csrf.headers.join(", ")
+        )))
+    }
+}
+
+fn parse_request_error(req: &HttpRequest, err: ParseRequestError) -> Error {
+    if let Some(handler) = req.app_data::<GQLRequestErrorHandler>() {
+        return (handler.0)(err, req);
+    }
+    let resp = HttpResponse::build(parse_request_error_status(&err))
+        .header("cache-control", "no-store")
+        .body(err.to_string());
+    actix_web::error::InternalError::from_response(err, resp).into()
+}
+
+fn parse_request_error_status(err: &ParseRequestError) -> StatusCode {
+    match err {
+        ParseRequestError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// Error handler for `GQLRequest` and `GQLBatchRequest`
+///
+/// By default, a request that cannot be parsed is rejected with a plain text `400 Bad Request`
+/// (or `413 Payload Too Large`) response. Register this handler with `App::app_data` to customize the response,
+/// or use `GQLRequestErrorHandler::json` to respond with a GraphQL error response.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{App, HttpResponse};
+/// use async_graphql_actix_web::GQLRequestErrorHandler;
+///
+/// App::new().app_data(GQLRequestErrorHandler::new(|err, _req| {
+///     actix_web::error::InternalError::from_response(
+///         err.to_string(),
+///         HttpResponse::BadRequest().json(serde_json::json!({ "message": err.to_string() })),
+///     )
+///     .into()
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct GQLRequestErrorHandler(Arc<dyn Fn(ParseRequestError, &HttpRequest) -> Error>);
+
+impl GQLRequestErrorHandler {
+    /// Create an error handler with a function that converts the `ParseRequestError` to an actix-web `Error`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(ParseRequestError, &HttpRequest) -> Error + 'static,
+    {
+        GQLRequestErrorHandler(Arc::new(f))
+    }
+
+    /// Create an error handler that responds with a GraphQL response, such as
+    /// `{"errors": [{"message": "..."}]}`, instead of plain text.
+    pub fn json() -> Self {
+        GQLRequestErrorHandler::new(|err, _| {
+            let resp = HttpResponse::build(parse_request_error_status(&err))
+                .header("cache-control", "no-store")
+                .json(serde_json::json!({ "errors": [{ "message": err.to_string() }] }));
+            actix_web::error::InternalError::from_response(err, resp).into()
+        })
+    }
+}
+
 /// Responder for GraphQL response
+///
+/// If the request is a `GET` request for a query operation and the cache control policy of the
+/// response is public, an `ETag` header is added, and a request with a matching `If-None-Match`
+/// header gets a `304 Not Modified` response without a body.
+///
+/// The response does not set `Content-Encoding`, so wrap the app with `actix_web::middleware::Compress`
+/// to compress large responses according to the `Accept-Encoding` header of the request.
 pub struct GQLResponse(async_graphql::Result<QueryResponse>);
 
 impl From<async_graphql::Result<QueryResponse>> for GQLResponse {
@@ -102,17 +488,291 @@ impl Responder for GQLResponse {
     type Error = Error;
     type Future = Ready<Result<HttpResponse, Error>>;
 
-    fn respond_to(self, _req: &HttpRequest) -> Self::Future {
-        let mut res = HttpResponse::build(StatusCode::OK);
-        res.content_type("application/json");
+    fn respond_to(self, req: &HttpRequest) -> Self::Future {
+        let content_type = match response_content_type(req) {
+            Ok(content_type) => content_type,
+            Err(err) => return futures::future::err(err),
+        };
+        let status = if content_type == GRAPHQL_RESPONSE_JSON {
+            response_status(&self.0)
+        } else {
+            StatusCode::OK
+        };
+        let mut res = HttpResponse::build(status);
+        res.content_type(content_type);
         add_cache_control(&mut res, &self.0);
-        let res =
-            res.body(serde_json::to_string(&async_graphql::http::GQLResponse(self.0)).unwrap());
-        futures::future::ok(res)
+        let mut res = res.finish();
+        if let Some(hook) = req.app_data::<GQLResponseHook>() {
+            (hook.0)(&self.0, &mut res);
+        }
+        let cacheable = req.method() == Method::GET
+            && req.extensions().get::<CacheableRequest>().is_some()
+            && matches!(&self.0, Ok(QueryResponse { cache_control, .. }) if cache_control.public && cache_control.max_age > 0);
+        let body = serde_json::to_string(&async_graphql::http::GQLResponse(self.0)).unwrap();
+        if cacheable {
+            let etag = etag(&body);
+            let not_modified = req
+                .headers()
+                .get(http::header::IF_NONE_MATCH)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| {
+                    value
+                        .split(',')
+                        .any(|tag| tag.trim() == etag || tag.trim() == "*")
+                })
+                .unwrap_or_default();
+            if let Ok(value) = HeaderValue::from_str(&etag) {
+                res.headers_mut().insert(http::header::ETAG, value);
+            }
+            if not_modified {
+                *res.status_mut() = StatusCode::NOT_MODIFIED;
+                res.headers_mut().remove(http::header::CONTENT_TYPE);
+                return futures::future::ok(res);
+            }
+        }
+        futures::future::ok(res.set_body(Body::from(body)))
+    }
+}
+
+/// Marks a `GET` request for a query operation, whose response may be cached with an `ETag`.
+struct CacheableRequest;
+
+fn etag(body: &str) -> String {
+    format!("\"{}\"", sha1::Sha1::from(body).digest())
+}
+
+/// Strict [GraphQL over HTTP](https://graphql.github.io/graphql-over-http/draft/) mode
+///
+/// When this is registered with `App::app_data`, `GQLRequest`, `GQLBatchRequest`, `GQLResponse`
+/// and `GQLBatchResponse` follow the GraphQL over HTTP specification:
+///
+/// * Mutations in `GET` requests are rejected with `405 Method Not Allowed`.
+/// * The response has the `application/graphql-response+json` content type if the `Accept` header
+///   allows it, or `application/json` if it only allows that. Requests that allow neither are
+///   rejected with `406 Not Acceptable`. Without an `Accept` header, `application/json` is used.
+/// * An `application/graphql-response+json` response without data has the status
+///   `400 Bad Request` for invalid requests and documents, `500 Internal Server Error` for
+///   errors during execution, `502 Bad Gateway` for errors of a delegated field, and
+///   `504 Gateway Timeout` if the query timed out.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::App;
+/// use async_graphql_actix_web::GQLHttpConformance;
+///
+/// App::new().app_data(GQLHttpConformance);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GQLHttpConformance;
+
+const GRAPHQL_RESPONSE_JSON: &str = "application/graphql-response+json";
+
+fn response_content_type(req: &HttpRequest) -> Result<&'static str, Error> {
+    if req.app_data::<GQLHttpConformance>().is_none() {
+        return Ok("application/json");
+    }
+    let accept = match req
+        .headers()
+        .get(http::header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(accept) => accept,
+        None => return Ok("application/json"),
+    };
+    let media_types: Vec<&str> = accept
+        .split(',')
+        .map(|value| value.split(';').next().unwrap_or_default().trim())
+        .collect();
+    if media_types
+        .iter()
+        .any(|ty| matches!(*ty, GRAPHQL_RESPONSE_JSON | "application/*" | "*/*"))
+    {
+        Ok(GRAPHQL_RESPONSE_JSON)
+    } else if media_types.contains(&"application/json") {
+        Ok("application/json")
+    } else {
+        Err(actix_web::error::ErrorNotAcceptable(
+            "Accept must allow application/graphql-response+json or application/json",
+        ))
+    }
+}
+
+fn response_status(resp: &async_graphql::Result<QueryResponse>) -> StatusCode {
+    match resp {
+        Ok(_) => StatusCode::OK,
+        Err(async_graphql::Error::Query {
+            err: QueryError::FieldError { .. },
+            ..
+        })
+        | Err(async_graphql::Error::Query {
+            err: QueryError::EntityNotFound,
+            ..
+        })
+        | Err(async_graphql::Error::Query {
+            err: QueryError::TypeNameNotExists,
+            ..
+        }) => StatusCode::INTERNAL_SERVER_ERROR,
+        Err(async_graphql::Error::Remote { .. }) => StatusCode::BAD_GATEWAY,
+        Err(async_graphql::Error::Timeout) => StatusCode::GATEWAY_TIMEOUT,
+        Err(_) => StatusCode::BAD_REQUEST,
+    }
+}
+
+fn check_get_mutation(
+    req: &HttpRequest,
+    operation_type: Option<OperationType>,
+) -> Result<(), Error> {
+    if req.app_data::<GQLHttpConformance>().is_some()
+        && operation_type == Some(OperationType::Mutation)
+    {
+        return Err(actix_web::error::InternalError::from_response(
+            "Mutations are not allowed in GET requests",
+            HttpResponse::MethodNotAllowed()
+                .header(http::header::ALLOW, "POST")
+                .finish(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// CSRF prevention for `GQLRequest` and `GQLBatchRequest`
+///
+/// Browsers send `GET` requests and `POST` requests with a `text/plain`,
+/// `application/x-www-form-urlencoded` or `multipart/form-data` body across origins without a CORS
+/// preflight. When this is registered with `App::app_data`, such requests are rejected with
+/// `400 Bad Request` unless they carry one of the required headers, which by default are
+/// `X-Apollo-Operation-Name` and `Apollo-Require-Preflight`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::App;
+/// use async_graphql_actix_web::GQLCsrfPrevention;
+///
+/// App::new().app_data(GQLCsrfPrevention::new().required_headers(&["X-Requested-With"]));
+/// ```
+#[derive(Clone)]
+pub struct GQLCsrfPrevention {
+    headers: Vec<String>,
+}
+
+impl Default for GQLCsrfPrevention {
+    fn default() -> Self {
+        GQLCsrfPrevention {
+            headers: vec![
+                "X-Apollo-Operation-Name".to_string(),
+                "Apollo-Require-Preflight".to_string(),
+            ],
+        }
+    }
+}
+
+impl GQLCsrfPrevention {
+    /// Create a CSRF prevention config with the default required headers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the headers that allow a request without a non-simple content type.
+    pub fn required_headers<T: AsRef<str>>(self, headers: &[T]) -> Self {
+        GQLCsrfPrevention {
+            headers: headers
+                .iter()
+                .map(|name| name.as_ref().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Hook for the outgoing GraphQL response
+///
+/// It is called with the execution result and the `HttpResponse` before the response is returned,
+/// so it can be used to add headers computed during execution. For batch requests it is called once
+/// for every operation. Register this hook with `App::app_data`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::http::header::{HeaderName, HeaderValue};
+/// use actix_web::App;
+/// use async_graphql_actix_web::GQLResponseHook;
+///
+/// App::new().app_data(GQLResponseHook::new(|resp, http_resp| {
+///     if let Ok(resp) = resp {
+///         let request_id = resp.extensions.as_ref()
+///             .and_then(|ext| ext.get("requestId"))
+///             .and_then(|id| id.as_str());
+///         if let Some(request_id) = request_id {
+///             http_resp.headers_mut().insert(
+///                 HeaderName::from_static("x-request-id"),
+///                 HeaderValue::from_str(request_id).unwrap(),
+///             );
+///         }
+///     }
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct GQLResponseHook(Arc<dyn Fn(&async_graphql::Result<QueryResponse>, &mut HttpResponse)>);
+
+impl GQLResponseHook {
+    /// Create a response hook with a function that can modify the `HttpResponse`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&async_graphql::Result<QueryResponse>, &mut HttpResponse) + 'static,
+    {
+        GQLResponseHook(Arc::new(f))
+    }
+}
+
+/// Responder for GraphQL batch response
+pub struct GQLBatchResponse(BatchQueryResponse);
+
+impl From<BatchQueryResponse> for GQLBatchResponse {
+    fn from(resp: BatchQueryResponse) -> Self {
+        GQLBatchResponse(resp)
+    }
+}
+
+impl Responder for GQLBatchResponse {
+    type Error = Error;
+    type Future = Ready<Result<HttpResponse, Error>>;
+
+    fn respond_to(self, req: &HttpRequest) -> Self::Future {
+        match self.0 {
+            BatchQueryResponse::Single(resp) => GQLResponse(resp).respond_to(req),
+            resp => {
+                let content_type = match response_content_type(req) {
+                    Ok(content_type) => content_type,
+                    Err(err) => return futures::future::err(err),
+                };
+                let mut res = HttpResponse::build(StatusCode::OK);
+                res.content_type(content_type);
+                if let BatchQueryResponse::Batch(responses) = &resp {
+                    add_batch_cache_control(&mut res, responses);
+                }
+                let mut res = res.finish();
+                if let (Some(hook), BatchQueryResponse::Batch(responses)) =
+                    (req.app_data::<GQLResponseHook>(), &resp)
+                {
+                    for resp in responses {
+                        (hook.0)(resp, &mut res);
+                    }
+                }
+                let body =
+                    serde_json::to_string(&async_graphql::http::GQLBatchResponse(resp)).unwrap();
+                futures::future::ok(res.set_body(Body::from(body)))
+            }
+        }
     }
 }
 
 /// Responder for GraphQL response stream
+///
+/// If the query has `@defer` or `@stream` directives and the `Accept` header of the request includes
+/// `multipart/mixed`, the incremental results are streamed as the parts of a `multipart/mixed` response.
+/// Otherwise, the complete result is returned once all the incremental results are ready.
 pub struct GQLResponseStream(StreamResponse);
 
 impl From<StreamResponse> for GQLResponseStream {
@@ -123,29 +783,173 @@ impl From<StreamResponse> for GQLResponseStream {
 
 impl Responder for GQLResponseStream {
     type Error = Error;
-    type Future = Ready<Result<HttpResponse, Error>>;
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, Error>>>>;
 
     fn respond_to(self, req: &HttpRequest) -> Self::Future {
         match self.0 {
-            StreamResponse::Single(resp) => GQLResponse(resp).respond_to(req),
-            StreamResponse::Stream(stream) => {
-                let body =
-                    BodyStream::new(multipart_stream(stream).map(Result::<_, Infallible>::Ok));
+            StreamResponse::Single(resp) => Box::pin(GQLResponse(resp).respond_to(req)),
+            StreamResponse::Stream(stream) if accepts_multipart_mixed(req) => {
+                let body = BodyStream::new(
+                    multipart_stream(stream)
+                        .map(Result::<_, Infallible>::Ok)
+                        .boxed_local(),
+                );
                 let mut res = HttpResponse::build(StatusCode::OK);
                 res.content_type("multipart/mixed; boundary=\"-\"");
-                futures::future::ok(res.body(body))
+                Box::pin(futures::future::ok(res.body(body)))
+            }
+            resp => {
+                let req = req.clone();
+                Box::pin(async move {
+                    GQLResponse(resp.into_complete().await)
+                        .respond_to(&req)
+                        .await
+                })
             }
         }
     }
 }
 
+/// Responder for GraphQL subscription over Server-Sent Events
+///
+/// The results of the subscription are sent as `next` events, followed by a `complete` event when
+/// the stream ends, as described by the
+/// [GraphQL over Server-Sent Events Protocol](https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md).
+/// If the subscription cannot be created, a regular GraphQL error response is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{guard, web, App};
+/// use async_graphql::*;
+/// use async_graphql_actix_web::{GQLRequest, GQLSubscriptionSse};
+/// use futures::{stream, Stream};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct SubscriptionRoot;
+///
+/// #[Subscription]
+/// impl SubscriptionRoot {
+///     async fn values(&self) -> impl Stream<Item = i32> {
+///         stream::iter(0..10)
+///     }
+/// }
+///
+/// type MySchema = Schema<QueryRoot, EmptyMutation, SubscriptionRoot>;
+///
+/// async fn index_sse(schema: web::Data<MySchema>, req: GQLRequest) -> GQLSubscriptionSse {
+///     req.into_inner().execute_subscription(&schema).await.into()
+/// }
+///
+/// App::new()
+///     .data(Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot))
+///     .service(web::resource("/sse").to(index_sse));
+/// ```
+pub struct GQLSubscriptionSse(
+    async_graphql::Result<
+        Pin<Box<dyn Stream<Item = async_graphql::Result<serde_json::Value>> + Send + 'static>>,
+    >,
+);
+
+impl<S> From<async_graphql::Result<S>> for GQLSubscriptionSse
+where
+    S: Stream<Item = async_graphql::Result<serde_json::Value>> + Send + 'static,
+{
+    fn from(resp: async_graphql::Result<S>) -> Self {
+        GQLSubscriptionSse(resp.map(|stream| Box::pin(stream) as _))
+    }
+}
+
+impl Responder for GQLSubscriptionSse {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, Error>>>>;
+
+    fn respond_to(self, req: &HttpRequest) -> Self::Future {
+        match self.0 {
+            Ok(stream) => {
+                let events = stream
+                    .map(|res| {
+                        let payload = match res {
+                            Ok(data) => serde_json::json!({ "data": data }),
+                            Err(err) => {
+                                serde_json::json!({ "errors": async_graphql::http::GQLError(&err) })
+                            }
+                        };
+                        Bytes::from(format!("event: next\ndata: {}\n\n", payload))
+                    })
+                    .chain(futures::stream::once(async {
+                        Bytes::from_static(b"event: complete\ndata:\n\n")
+                    }))
+                    .map(Result::<_, Infallible>::Ok)
+                    .boxed_local();
+                let mut res = HttpResponse::build(StatusCode::OK);
+                res.content_type("text/event-stream")
+                    .header("cache-control", "no-cache");
+                Box::pin(futures::future::ok(res.body(BodyStream::new(events))))
+            }
+            Err(err) => Box::pin(GQLResponse(Err(err)).respond_to(req)),
+        }
+    }
+}
+
+fn accepts_multipart_mixed(req: &HttpRequest) -> bool {
+    req.headers()
+        .get_all(http::header::ACCEPT)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|value| value.trim().starts_with("multipart/mixed"))
+}
+
 fn add_cache_control(
     builder: &mut HttpResponseBuilder,
     resp: &async_graphql::Result<QueryResponse>,
 ) {
-    if let Ok(QueryResponse { cache_control, .. }) = resp {
-        if let Some(cache_control) = cache_control.value() {
-            builder.header("cache-control", cache_control);
+    match resp {
+        Ok(QueryResponse { cache_control, .. }) => {
+            if let Some(cache_control) = cache_control.value() {
+                builder.header("cache-control", cache_control);
+            }
+        }
+        Err(_) => {
+            builder.header("cache-control", "no-store");
         }
     }
 }
+
+/// Uses the most restrictive cache control of the responses, because a cached batch response is
+/// reused for all of its operations.
+fn add_batch_cache_control(
+    builder: &mut HttpResponseBuilder,
+    responses: &[async_graphql::Result<QueryResponse>],
+) {
+    let mut batch_cache_control: Option<CacheControl> = None;
+    for resp in responses {
+        match resp {
+            Ok(QueryResponse { cache_control, .. }) => {
+                batch_cache_control = Some(match batch_cache_control {
+                    Some(batch_cache_control) => CacheControl {
+                        public: batch_cache_control.public && cache_control.public,
+                        max_age: batch_cache_control.max_age.min(cache_control.max_age),
+                    },
+                    None => *cache_control,
+                });
+            }
+            Err(_) => {
+                builder.header("cache-control", "no-store");
+                return;
+            }
+        }
+    }
+    if let Some(cache_control) = batch_cache_control.and_then(|cache_control| cache_control.value())
+    {
+        builder.header("cache-control", cache_control);
+    }
+}
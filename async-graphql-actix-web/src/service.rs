@@ -0,0 +1,104 @@
+use crate::{GQLBatchRequest, GQLBatchResponse};
+use actix_web::dev::{AppService, HttpServiceFactory};
+use actix_web::http::{header, Method};
+use actix_web::{web, HttpResponse, Resource};
+use async_graphql::{ObjectType, Schema, SubscriptionType};
+
+/// Service for GraphQL queries and mutations
+///
+/// It handles `GET` and `POST` requests on a path, including batch requests, so it can be registered
+/// with `App::service` and composes with middleware like any other actix-web service.
+/// Use `GQLService::into_resource` to wrap only this service with middleware.
+///
+/// The configuration registered with `App::app_data`, such as `IntoQueryBuilderOpts`,
+/// `GQLRequestErrorHandler` and `GQLResponseHook`, applies to every `GQLService` of the app,
+/// so several schemas can be served with the same configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{middleware, App, HttpServer};
+/// use async_graphql::*;
+/// use async_graphql_actix_web::{GQLCors, GQLService};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[field(desc = "Returns the sum of a and b")]
+///     async fn add(&self, a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// #[actix_rt::main]
+/// async fn main() -> std::io::Result<()> {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     HttpServer::new(move || {
+///         App::new()
+///             .wrap(middleware::Logger::default())
+///             .service(
+///                 GQLService::new("/", schema.clone())
+///                     .into_resource()
+///                     .wrap(GQLCors::new()),
+///             )
+///     })
+///     .bind("127.0.0.1:8000")?
+///     .run()
+///     .await
+/// }
+/// ```
+pub struct GQLService<Query, Mutation, Subscription> {
+    path: String,
+    schema: Schema<Query, Mutation, Subscription>,
+}
+
+impl<Query, Mutation, Subscription> GQLService<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    /// Create a service for the schema on this path.
+    pub fn new(path: impl Into<String>, schema: Schema<Query, Mutation, Subscription>) -> Self {
+        GQLService {
+            path: path.into(),
+            schema,
+        }
+    }
+
+    /// Convert to a `Resource` with the `GET` and `POST` routes.
+    ///
+    /// `HEAD` requests get an empty `200 OK` response and `OPTIONS` requests get an empty
+    /// `204 No Content` response with an `Allow` header.
+    pub fn into_resource(self) -> Resource {
+        let get_schema = self.schema.clone();
+        let post_schema = self.schema;
+        web::resource(&self.path)
+            .route(web::get().to(move |req: GQLBatchRequest| {
+                let schema = get_schema.clone();
+                async move { GQLBatchResponse::from(req.into_inner().execute(&schema).await) }
+            }))
+            .route(web::post().to(move |req: GQLBatchRequest| {
+                let schema = post_schema.clone();
+                async move { GQLBatchResponse::from(req.into_inner().execute(&schema).await) }
+            }))
+            .route(web::head().to(|| HttpResponse::Ok().content_type("application/json").finish()))
+            .route(web::method(Method::OPTIONS).to(|| {
+                HttpResponse::NoContent()
+                    .header(header::ALLOW, "GET, POST, HEAD, OPTIONS")
+                    .finish()
+            }))
+    }
+}
+
+impl<Query, Mutation, Subscription> HttpServiceFactory for GQLService<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    fn register(self, config: &mut AppService) {
+        self.into_resource().register(config)
+    }
+}
@@ -1,21 +1,160 @@
+use crate::GQLConnectionInfo;
 use actix::{
     Actor, ActorContext, ActorFuture, AsyncContext, ContextFutureSpawner, StreamHandler, WrapFuture,
 };
-use actix_web_actors::ws::{Message, ProtocolError, WebsocketContext};
-use async_graphql::{Data, FieldResult, ObjectType, Schema, SubscriptionType, WebSocketTransport};
+use actix_http::ws::Codec;
+use actix_web::{Error, HttpRequest, HttpResponse};
+use actix_web_actors::ws::{
+    self, CloseCode, CloseReason, Message, ProtocolError, WebsocketContext,
+};
+use async_graphql::{
+    Data, FieldResult, ObjectType, Schema, SubscriptionType, WebSocketCloseReason,
+    WebSocketProtocols, WebSocketTransport, ALL_WEBSOCKET_PROTOCOLS,
+};
 use bytes::Bytes;
 use futures::channel::mpsc;
 use futures::SinkExt;
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
 const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// Limiter for the number of active websocket connections
+///
+/// Create it once and pass it to `WSSubscription::connection_limit` for every connection.
+/// The clones share the count of active connections.
+#[derive(Clone)]
+pub struct WSConnectionLimit {
+    inner: Arc<ConnectionLimitInner>,
+    close_code: CloseCode,
+}
+
+struct ConnectionLimitInner {
+    max_connections: usize,
+    active: AtomicUsize,
+}
+
+impl WSConnectionLimit {
+    /// Create a limiter that allows up to `max_connections` active connections.
+    ///
+    /// Connections over the limit are closed with the `1013` (try again later) close code.
+    pub fn new(max_connections: usize) -> Self {
+        WSConnectionLimit {
+            inner: Arc::new(ConnectionLimitInner {
+                max_connections,
+                active: AtomicUsize::new(0),
+            }),
+            close_code: CloseCode::Again,
+        }
+    }
+
+    /// Set the close code for connections over the limit.
+    ///
+    /// It only applies to this limiter and the clones made from it afterwards.
+    pub fn close_code(self, close_code: CloseCode) -> Self {
+        WSConnectionLimit { close_code, ..self }
+    }
+
+    /// Returns the number of active connections.
+    pub fn active(&self) -> usize {
+        self.inner.active.load(Ordering::SeqCst)
+    }
+
+    fn acquire(&self) -> bool {
+        let max_connections = self.inner.max_connections;
+        self.inner
+            .active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+                if active < max_connections {
+                    Some(active + 1)
+                } else {
+                    None
+                }
+            })
+            .is_ok()
+    }
+
+    fn release(&self) {
+        self.inner.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 /// Actor for subscription via websocket
+///
+/// It only performs the websocket upgrade, so it can be mounted on its own route, independently of
+/// the handler for queries and mutations.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{guard, web, App, HttpRequest, HttpResponse, HttpServer, Result};
+/// use async_graphql::*;
+/// use async_graphql_actix_web::WSSubscription;
+/// use futures::{stream, Stream};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct SubscriptionRoot;
+///
+/// #[Subscription]
+/// impl SubscriptionRoot {
+///     async fn values(&self) -> impl Stream<Item = i32> {
+///         stream::iter(0..10)
+///     }
+/// }
+///
+/// type MySchema = Schema<QueryRoot, EmptyMutation, SubscriptionRoot>;
+///
+/// async fn index_ws(
+///     schema: web::Data<MySchema>,
+///     req: HttpRequest,
+///     payload: web::Payload,
+/// ) -> Result<HttpResponse> {
+///     WSSubscription::new(&schema).start(&req, payload)
+/// }
+///
+/// #[actix_rt::main]
+/// async fn main() -> std::io::Result<()> {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+///     HttpServer::new(move || {
+///         App::new()
+///             .data(schema.clone())
+///             .service(
+///                 web::resource("/subscriptions")
+///                     .guard(guard::Get())
+///                     .guard(guard::Header("upgrade", "websocket"))
+///                     .to(index_ws),
+///             )
+///     })
+///     .bind("127.0.0.1:8001")?
+///     .run()
+///     .await
+/// }
+/// ```
 pub struct WSSubscription<Query, Mutation, Subscription> {
     schema: Schema<Query, Mutation, Subscription>,
     hb: Instant,
+    hb_interval: Duration,
+    client_timeout: Duration,
+    keepalive: Option<Duration>,
+    max_message_size: Option<usize>,
+    max_query_length: Option<usize>,
+    connection_limit: Option<WSConnectionLimit>,
+    acquired: bool,
     sink: Option<mpsc::UnboundedSender<Bytes>>,
+    close_reason: WebSocketCloseReason,
+    protocol: WebSocketProtocols,
+    data: Option<Data>,
     init_context_data: Option<Box<dyn Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync>>,
 }
 
@@ -30,12 +169,96 @@ where
         Self {
             schema: schema.clone(),
             hb: Instant::now(),
+            hb_interval: HEARTBEAT_INTERVAL,
+            client_timeout: CLIENT_TIMEOUT,
+            keepalive: None,
+            max_message_size: None,
+            max_query_length: None,
+            connection_limit: None,
+            acquired: false,
             sink: None,
+            close_reason: Default::default(),
+            protocol: WebSocketProtocols::SubscriptionsTransportWS,
+            data: None,
             init_context_data: None,
         }
     }
 
+    /// Add a context data that can be accessed in the `Context`, you access it with `Context::data`.
+    ///
+    /// **This data is valid for all subscriptions of this connection**, so it can be used to pass
+    /// information from the `HttpRequest`, such as cookies or headers, to the subscription resolvers.
+    pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
+        self.data.get_or_insert_with(Default::default).insert(data);
+        self
+    }
+
+    /// Set the interval for sending websocket ping frames, the default is 5 seconds.
+    pub fn heartbeat_interval(self, interval: Duration) -> Self {
+        Self {
+            hb_interval: interval,
+            ..self
+        }
+    }
+
+    /// Close the connection if the client has not responded within this time, the default is 10 seconds.
+    pub fn client_timeout(self, timeout: Duration) -> Self {
+        Self {
+            client_timeout: timeout,
+            ..self
+        }
+    }
+
+    /// Periodically send keep alive messages.
+    ///
+    /// They are `ka` messages under the `graphql-ws` sub-protocol (subscriptions-transport-ws), and
+    /// `ping` messages under the `graphql-transport-ws` sub-protocol, which has no `ka` message.
+    ///
+    /// Some load balancers and proxies only consider data messages as activity,
+    /// so the ping frames alone may not keep the connection alive.
+    pub fn keepalive(self, interval: Duration) -> Self {
+        Self {
+            keepalive: Some(interval),
+            ..self
+        }
+    }
+
+    /// Set the maximum size of an incoming websocket frame, the default is 64 KiB.
+    ///
+    /// If a larger frame is received, the connection is closed with the `1009` (message too big) close code.
+    pub fn max_message_size(self, size: usize) -> Self {
+        Self {
+            max_message_size: Some(size),
+            ..self
+        }
+    }
+
+    /// Set the maximum length of the query in an incoming `start` or `subscribe` message.
+    ///
+    /// If a longer query is received, the connection is closed with the `1008` (policy violation) close code.
+    pub fn max_query_length(self, length: usize) -> Self {
+        Self {
+            max_query_length: Some(length),
+            ..self
+        }
+    }
+
+    /// Limit the number of active connections with a limiter shared by all connections.
+    ///
+    /// If the limit has been reached, the new connection is closed right after the upgrade with the
+    /// close code of the limiter.
+    pub fn connection_limit(self, limit: &WSConnectionLimit) -> Self {
+        Self {
+            connection_limit: Some(limit.clone()),
+            ..self
+        }
+    }
+
     /// Set a context data initialization function.
+    ///
+    /// It receives the payload of the `connection_init` message. If it returns an error,
+    /// the connection is rejected with a `connection_error` message and closed, or with the
+    /// `4403` (forbidden) close code under the `graphql-transport-ws` sub-protocol.
     pub fn init_context_data<F>(self, f: F) -> Self
     where
         F: Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync + 'static,
@@ -46,13 +269,45 @@ where
         }
     }
 
+    /// Start the websocket connection.
+    ///
+    /// Both the `graphql-ws` and `graphql-transport-ws` sub-protocols are supported,
+    /// the protocol is selected according to the `Sec-WebSocket-Protocol` header of the request.
+    pub fn start<T>(mut self, req: &HttpRequest, payload: T) -> Result<HttpResponse, Error>
+    where
+        T: futures::Stream<Item = Result<Bytes, actix_web::error::PayloadError>> + 'static,
+    {
+        self.protocol = req
+            .headers()
+            .get("sec-websocket-protocol")
+            .and_then(|value| value.to_str().ok())
+            .map(WebSocketProtocols::from_sec_websocket_protocol)
+            .unwrap_or_default();
+        self = self.data(GQLConnectionInfo::new(req));
+        let mut codec = Codec::new();
+        if let Some(max_message_size) = self.max_message_size {
+            codec = codec.max_size(max_message_size);
+        }
+        Ok(ws::handshake_with_protocols(req, &ALL_WEBSOCKET_PROTOCOLS)?
+            .streaming(WebsocketContext::with_codec(self, payload, codec)))
+    }
+
     fn hb(&self, ctx: &mut WebsocketContext<Self>) {
-        ctx.run_interval(HEARTBEAT_INTERVAL, |act, ctx| {
-            if Instant::now().duration_since(act.hb) > CLIENT_TIMEOUT {
+        ctx.run_interval(self.hb_interval, |act, ctx| {
+            if Instant::now().duration_since(act.hb) > act.client_timeout {
                 ctx.stop();
             }
             ctx.ping(b"");
         });
+        if let Some(keepalive) = self.keepalive {
+            let message = match self.protocol {
+                WebSocketProtocols::SubscriptionsTransportWS => r#"{"type":"ka"}"#,
+                WebSocketProtocols::GraphQLWS => r#"{"type":"ping"}"#,
+            };
+            ctx.run_interval(keepalive, move |_, ctx| {
+                ctx.text(message);
+            });
+        }
     }
 }
 
@@ -65,18 +320,40 @@ where
     type Context = WebsocketContext<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
+        if let Some(limit) = &self.connection_limit {
+            if !limit.acquire() {
+                ctx.close(Some(limit.close_code.into()));
+                ctx.stop();
+                return;
+            }
+            self.acquired = true;
+        }
+
         self.hb(ctx);
         let schema = self.schema.clone();
-        let (sink, stream) = schema.subscription_connection(
-            if let Some(init_with_payload) = self.init_context_data.take() {
-                WebSocketTransport::new(init_with_payload)
-            } else {
-                WebSocketTransport::default()
-            },
-        );
+        let mut transport = if let Some(init_with_payload) = self.init_context_data.take() {
+            WebSocketTransport::new(init_with_payload)
+        } else {
+            WebSocketTransport::default()
+        }
+        .with_protocol(self.protocol);
+        if let Some(max_query_length) = self.max_query_length {
+            transport = transport.with_max_query_length(max_query_length);
+        }
+        if let Some(data) = self.data.take() {
+            transport = transport.with_data(data);
+        }
+        self.close_reason = transport.close_reason();
+        let (sink, stream) = schema.subscription_connection(transport);
         ctx.add_stream(stream);
         self.sink = Some(sink);
     }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        if let (Some(limit), true) = (&self.connection_limit, self.acquired) {
+            limit.release();
+        }
+    }
 }
 
 impl<Query, Mutation, Subscription> StreamHandler<Result<Message, ProtocolError>>
@@ -88,6 +365,11 @@ where
 {
     fn handle(&mut self, msg: Result<Message, ProtocolError>, ctx: &mut Self::Context) {
         let msg = match msg {
+            Err(ProtocolError::Overflow) => {
+                ctx.close(Some(CloseCode::Size.into()));
+                ctx.stop();
+                return;
+            }
             Err(_) => {
                 ctx.stop();
                 return;
@@ -131,4 +413,14 @@ where
             ctx.text(text);
         }
     }
+
+    fn finished(&mut self, ctx: &mut Self::Context) {
+        if let Some((code, description)) = self.close_reason.get() {
+            ctx.close(Some(CloseReason {
+                code: code.into(),
+                description: Some(description),
+            }));
+        }
+        ctx.stop();
+    }
 }
@@ -0,0 +1,1081 @@
+#[cfg(feature = "actix-web-3")]
+extern crate actix_web3 as actix_web;
+#[cfg(feature = "actix-web-3")]
+extern crate actix_web_actors3 as actix_web_actors;
+#[cfg(feature = "actix-web-3")]
+extern crate awc2 as awc;
+
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{guard, test, web, App, HttpRequest, HttpResponse};
+use async_graphql::*;
+use async_graphql_actix_web::{
+    health_check, GQLBatchRequest, GQLBatchResponse, GQLConnectionInfo, GQLCors, GQLCsrfPrevention,
+    GQLHttpConformance, GQLRequest, GQLRequestData, GQLRequestErrorHandler, GQLResponse,
+    GQLResponseHook, GQLResponseStream, GQLService, GQLSubscriptionSse, WSConnectionLimit,
+    WSSubscription,
+};
+use serde_json::json;
+
+struct UserId(String);
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[field(cache_control(max_age = 60))]
+    async fn add(&self, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    async fn forwarded_for(&self, ctx: &Context<'_>) -> Vec<String> {
+        ctx.data::<GQLConnectionInfo>().forwarded_for.clone()
+    }
+
+    async fn remote_addr(&self, ctx: &Context<'_>) -> Option<String> {
+        ctx.data::<GQLConnectionInfo>()
+            .remote_addr
+            .map(|addr| addr.to_string())
+    }
+
+    async fn forwarded_remote_addr(&self, ctx: &Context<'_>) -> Option<String> {
+        ctx.data::<GQLConnectionInfo>()
+            .forwarded_remote_addr
+            .clone()
+    }
+
+    async fn user_id(&self, ctx: &Context<'_>) -> Option<String> {
+        ctx.data_opt::<UserId>().map(|user_id| user_id.0.clone())
+    }
+}
+
+type MySchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+async fn index(schema: web::Data<MySchema>, req: GQLRequest) -> GQLResponse {
+    req.into_inner().execute(&schema).await.into()
+}
+
+async fn index_batch(schema: web::Data<MySchema>, req: GQLBatchRequest) -> GQLBatchResponse {
+    req.into_inner().execute(&schema).await.into()
+}
+
+fn schema() -> MySchema {
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription)
+}
+
+#[actix_rt::test]
+async fn test_get() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .service(web::resource("/").guard(guard::Get()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/?query=query%20(%24a%3A%20Int!)%20%7B%20add(a%3A%20%24a%2C%20b%3A%2020)%20%7D&variables=%7B%22a%22%3A%2010%7D")
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"add": 30}}));
+}
+
+#[actix_rt::test]
+async fn test_batch() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .service(web::resource("/").guard(guard::Post()).to(index_batch)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"[{"query": "{ add(a: 1, b: 2) }"}, {"query": "{ add(a: 3, b: 4) }"}]"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!([{"data": {"add": 3}}, {"data": {"add": 7}}]));
+}
+
+#[actix_rt::test]
+async fn test_error_handler() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .app_data(GQLRequestErrorHandler::new(|err, _| {
+                actix_web::error::InternalError::from_response(
+                    err.to_string(),
+                    HttpResponse::UnprocessableEntity().json(json!({ "message": "bad request" })),
+                )
+                .into()
+            }))
+            .service(web::resource("/").guard(guard::Post()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload("{")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body: serde_json::Value = serde_json::from_slice(&test::read_body(resp).await).unwrap();
+    assert_eq!(body, json!({ "message": "bad request" }));
+}
+
+#[actix_rt::test]
+async fn test_response_hook() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .app_data(GQLResponseHook::new(|resp, http_resp| {
+                let value = resp.as_ref().unwrap().data["add"].to_string();
+                http_resp.headers_mut().insert(
+                    HeaderName::from_static("x-result"),
+                    HeaderValue::from_str(&value).unwrap(),
+                );
+            }))
+            .service(web::resource("/").guard(guard::Post()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.headers().get("x-result").unwrap(), "3");
+    let body: serde_json::Value = serde_json::from_slice(&test::read_body(resp).await).unwrap();
+    assert_eq!(body, json!({"data": {"add": 3}}));
+}
+
+#[actix_rt::test]
+async fn test_cors() {
+    let mut app = test::init_service(
+        App::new().data(schema()).service(
+            web::resource("/")
+                .wrap(GQLCors::new().allowed_origin("https://example.com"))
+                .route(web::post().to(index)),
+        ),
+    )
+    .await;
+
+    let req = test::TestRequest::with_uri("/")
+        .method(actix_web::http::Method::OPTIONS)
+        .header("origin", "https://example.com")
+        .header("access-control-request-method", "POST")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 204);
+    assert_eq!(
+        resp.headers().get("access-control-allow-origin").unwrap(),
+        "https://example.com"
+    );
+    assert_eq!(
+        resp.headers().get("access-control-allow-headers").unwrap(),
+        "Content-Type, Authorization"
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("origin", "https://example.com")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(
+        resp.headers().get("access-control-allow-origin").unwrap(),
+        "https://example.com"
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("origin", "https://evil.com")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert!(resp.headers().get("access-control-allow-origin").is_none());
+}
+
+#[actix_rt::test]
+async fn test_csrf_prevention() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .app_data(GQLCsrfPrevention::new())
+            .service(web::resource("/").to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "text/plain")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%202)%20%7D")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%202)%20%7D")
+        .header("apollo-require-preflight", "true")
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"add": 3}}));
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"add": 3}}));
+}
+
+#[actix_rt::test]
+async fn test_health_check() {
+    let mut app = test::init_service(App::new().data(schema()).service(
+        web::resource("/health").to(health_check::<QueryRoot, EmptyMutation, EmptySubscription>),
+    ))
+    .await;
+
+    let req = test::TestRequest::get().uri("/health").to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 200);
+}
+
+#[actix_rt::test]
+async fn test_cache_control() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .service(web::resource("/").guard(guard::Get()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%202)%20%7D")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.headers().get("cache-control").unwrap(), "max-age=60");
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20unknown%20%7D")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.headers().get("cache-control").unwrap(), "no-store");
+
+    let req = test::TestRequest::get().uri("/").to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 400);
+    assert_eq!(resp.headers().get("cache-control").unwrap(), "no-store");
+}
+
+#[actix_rt::test]
+async fn test_batch_cache_control() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .service(web::resource("/").guard(guard::Post()).to(index_batch)),
+    )
+    .await;
+
+    let batch = |queries: &[&str]| {
+        let body: Vec<_> = queries
+            .iter()
+            .map(|query| json!({ "query": query }))
+            .collect();
+        test::TestRequest::post()
+            .uri("/")
+            .header("content-type", "application/json")
+            .set_payload(serde_json::to_string(&body).unwrap())
+            .to_request()
+    };
+
+    let resp = test::call_service(
+        &mut app,
+        batch(&["{ add(a: 1, b: 2) }", "{ add(a: 3, b: 4) }"]),
+    )
+    .await;
+    assert_eq!(resp.headers().get("cache-control").unwrap(), "max-age=60");
+
+    let resp = test::call_service(&mut app, batch(&["{ add(a: 1, b: 2) }", "{ userId }"])).await;
+    assert!(resp.headers().get("cache-control").is_none());
+
+    let resp = test::call_service(&mut app, batch(&["{ add(a: 1, b: 2) }", "{ unknown }"])).await;
+    assert_eq!(resp.headers().get("cache-control").unwrap(), "no-store");
+}
+
+#[actix_rt::test]
+async fn test_json_error_handler() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .app_data(GQLRequestErrorHandler::json())
+            .service(web::resource("/").guard(guard::Post()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"variables": {}}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 400);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&test::read_body(resp).await).unwrap();
+    assert!(body["errors"][0]["message"].is_string());
+}
+
+#[actix_rt::test]
+async fn test_ws_message_limits() {
+    use actix_web_actors::ws;
+    use futures::{SinkExt, StreamExt};
+
+    async fn index_ws(
+        schema: web::Data<MySchema>,
+        req: HttpRequest,
+        payload: web::Payload,
+    ) -> actix_web::Result<HttpResponse> {
+        WSSubscription::new(&schema)
+            .max_message_size(100)
+            .max_query_length(10)
+            .start(&req, payload)
+    }
+
+    let mut srv = test::start(|| {
+        App::new()
+            .data(schema())
+            .route("/", web::get().to(index_ws))
+    });
+
+    let mut framed = srv.ws_at("/").await.unwrap();
+    framed
+        .send(ws::Message::Text(format!(
+            r#"{{"type": "connection_init", "payload": "{}"}}"#,
+            "a".repeat(100)
+        )))
+        .await
+        .unwrap();
+    match framed.next().await {
+        Some(Ok(ws::Frame::Close(Some(reason)))) => assert_eq!(reason.code, ws::CloseCode::Size),
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+
+    let mut framed = srv.ws_at("/").await.unwrap();
+    framed
+        .send(ws::Message::Text(
+            r#"{"type": "start", "id": "1", "payload": {"query": "subscription { values }"}}"#
+                .to_string(),
+        ))
+        .await
+        .unwrap();
+    match framed.next().await {
+        Some(Ok(ws::Frame::Close(Some(reason)))) => {
+            assert_eq!(reason.code, ws::CloseCode::Policy)
+        }
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+}
+
+#[actix_rt::test]
+async fn test_ws_max_message_size_above_default_frame_size() {
+    use actix_web_actors::ws;
+    use futures::{SinkExt, StreamExt};
+
+    async fn index_ws(
+        schema: web::Data<MySchema>,
+        req: HttpRequest,
+        payload: web::Payload,
+    ) -> actix_web::Result<HttpResponse> {
+        WSSubscription::new(&schema)
+            .max_message_size(256 * 1024)
+            .start(&req, payload)
+    }
+
+    let mut srv = test::start(|| {
+        App::new()
+            .data(schema())
+            .route("/", web::get().to(index_ws))
+    });
+
+    let mut framed = srv.ws_at("/").await.unwrap();
+    framed
+        .send(ws::Message::Text(format!(
+            r#"{{"type": "connection_init", "payload": {{"token": "{}"}}}}"#,
+            "a".repeat(128 * 1024)
+        )))
+        .await
+        .unwrap();
+    match framed.next().await {
+        Some(Ok(ws::Frame::Text(text))) => assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&text).unwrap(),
+            json!({"type": "connection_ack"})
+        ),
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+
+    let mut framed = srv.ws_at("/").await.unwrap();
+    framed
+        .send(ws::Message::Text(format!(
+            r#"{{"type": "connection_init", "payload": {{"token": "{}"}}}}"#,
+            "a".repeat(256 * 1024)
+        )))
+        .await
+        .unwrap();
+    match framed.next().await {
+        Some(Ok(ws::Frame::Close(Some(reason)))) => assert_eq!(reason.code, ws::CloseCode::Size),
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+}
+
+async fn check_ws_keepalive(protocol: &str, message: serde_json::Value) {
+    use actix_web_actors::ws;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    async fn index_ws(
+        schema: web::Data<MySchema>,
+        req: HttpRequest,
+        payload: web::Payload,
+    ) -> actix_web::Result<HttpResponse> {
+        WSSubscription::new(&schema)
+            .keepalive(Duration::from_millis(50))
+            .start(&req, payload)
+    }
+
+    let srv = test::start(|| {
+        App::new()
+            .data(schema())
+            .route("/", web::get().to(index_ws))
+    });
+
+    let (resp, mut framed) = awc::Client::new()
+        .ws(srv.url("/"))
+        .protocols([protocol])
+        .connect()
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.headers().get("sec-websocket-protocol").unwrap(),
+        protocol
+    );
+    loop {
+        match framed.next().await {
+            Some(Ok(ws::Frame::Text(text))) => {
+                assert_eq!(
+                    serde_json::from_slice::<serde_json::Value>(&text).unwrap(),
+                    message
+                );
+                break;
+            }
+            Some(Ok(ws::Frame::Ping(_))) => {}
+            frame => panic!("unexpected frame: {:?}", frame),
+        }
+    }
+}
+
+#[actix_rt::test]
+async fn test_ws_keepalive_graphql_ws() {
+    check_ws_keepalive("graphql-ws", json!({"type": "ka"})).await;
+}
+
+#[actix_rt::test]
+async fn test_ws_keepalive_graphql_transport_ws() {
+    check_ws_keepalive("graphql-transport-ws", json!({"type": "ping"})).await;
+}
+
+#[actix_rt::test]
+async fn test_ws_close_code_graphql_transport_ws() {
+    use actix_web_actors::ws;
+    use futures::{SinkExt, StreamExt};
+
+    async fn index_ws(
+        schema: web::Data<MySchema>,
+        req: HttpRequest,
+        payload: web::Payload,
+    ) -> actix_web::Result<HttpResponse> {
+        WSSubscription::new(&schema).start(&req, payload)
+    }
+
+    let srv = test::start(|| {
+        App::new()
+            .data(schema())
+            .route("/", web::get().to(index_ws))
+    });
+
+    let (_, mut framed) = awc::Client::new()
+        .ws(srv.url("/"))
+        .protocols(["graphql-transport-ws"])
+        .connect()
+        .await
+        .unwrap();
+    framed
+        .send(ws::Message::Text(
+            r#"{"type": "subscribe", "id": "1", "payload": {"query": "{ add(a: 1, b: 2) }"}}"#
+                .to_string(),
+        ))
+        .await
+        .unwrap();
+    loop {
+        match framed.next().await {
+            Some(Ok(ws::Frame::Close(Some(reason)))) => {
+                assert_eq!(reason.code, ws::CloseCode::Other(4401));
+                break;
+            }
+            Some(Ok(ws::Frame::Ping(_))) => {}
+            frame => panic!("unexpected frame: {:?}", frame),
+        }
+    }
+}
+
+#[actix_rt::test]
+async fn test_ws_connection_limit() {
+    use actix_web_actors::ws;
+    use futures::{SinkExt, StreamExt};
+
+    async fn index_ws(
+        schema: web::Data<MySchema>,
+        limit: web::Data<WSConnectionLimit>,
+        req: HttpRequest,
+        payload: web::Payload,
+    ) -> actix_web::Result<HttpResponse> {
+        WSSubscription::new(&schema)
+            .connection_limit(&limit)
+            .start(&req, payload)
+    }
+
+    let limit = WSConnectionLimit::new(1);
+    let mut srv = test::start({
+        let limit = limit.clone();
+        move || {
+            App::new()
+                .data(schema())
+                .data(limit.clone())
+                .route("/", web::get().to(index_ws))
+        }
+    });
+
+    let mut framed = srv.ws_at("/").await.unwrap();
+    framed
+        .send(ws::Message::Text(
+            r#"{"type": "connection_init"}"#.to_string(),
+        ))
+        .await
+        .unwrap();
+    match framed.next().await {
+        Some(Ok(ws::Frame::Text(text))) => assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&text).unwrap(),
+            json!({"type": "connection_ack"})
+        ),
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+    assert_eq!(limit.active(), 1);
+
+    let mut framed2 = srv.ws_at("/").await.unwrap();
+    match framed2.next().await {
+        Some(Ok(ws::Frame::Close(Some(reason)))) => assert_eq!(reason.code, ws::CloseCode::Again),
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+    assert_eq!(limit.active(), 1);
+}
+
+#[actix_rt::test]
+async fn test_ws_connection_limit_close_code() {
+    use actix_web_actors::ws;
+    use futures::StreamExt;
+
+    async fn index_ws(
+        schema: web::Data<MySchema>,
+        limit: web::Data<WSConnectionLimit>,
+        req: HttpRequest,
+        payload: web::Payload,
+    ) -> actix_web::Result<HttpResponse> {
+        WSSubscription::new(&schema)
+            .connection_limit(&limit)
+            .start(&req, payload)
+    }
+
+    let limit = WSConnectionLimit::new(0);
+    let limit_with_code = limit.clone().close_code(ws::CloseCode::Policy);
+    let mut srv = test::start(move || {
+        App::new()
+            .data(schema())
+            .data(limit_with_code.clone())
+            .route("/", web::get().to(index_ws))
+    });
+
+    let mut framed = srv.ws_at("/").await.unwrap();
+    match framed.next().await {
+        Some(Ok(ws::Frame::Close(Some(reason)))) => {
+            assert_eq!(reason.code, ws::CloseCode::Policy)
+        }
+        frame => panic!("unexpected frame: {:?}", frame),
+    }
+    assert_eq!(limit.active(), 0);
+}
+
+#[actix_rt::test]
+async fn test_connection_info() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .service(web::resource("/").guard(guard::Post()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .header("x-forwarded-for", "1.1.1.1, 2.2.2.2")
+        .set_payload(r#"{"query": "{ forwardedFor }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(
+        resp,
+        json!({"data": {"forwardedFor": ["1.1.1.1", "2.2.2.2"]}})
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .peer_addr("127.0.0.1:8000".parse().unwrap())
+        .header("content-type", "application/json")
+        .header("x-forwarded-for", "1.1.1.1")
+        .set_payload(r#"{"query": "{ remoteAddr forwardedRemoteAddr }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(
+        resp,
+        json!({"data": {"remoteAddr": "127.0.0.1:8000", "forwardedRemoteAddr": "1.1.1.1"}})
+    );
+}
+
+#[actix_rt::test]
+async fn test_service() {
+    let mut app = test::init_service(App::new().service(GQLService::new("/", schema()))).await;
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%202)%20%7D")
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"add": 3}}));
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ add(a: 3, b: 4) }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"add": 7}}));
+
+    let req = test::TestRequest::with_uri("/")
+        .method(actix_web::http::Method::HEAD)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(test::read_body(resp).await.is_empty());
+
+    let req = test::TestRequest::with_uri("/")
+        .method(actix_web::http::Method::OPTIONS)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 204);
+    assert_eq!(
+        resp.headers().get("allow").unwrap(),
+        "GET, POST, HEAD, OPTIONS"
+    );
+
+    let req = test::TestRequest::put().uri("/").to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 405);
+}
+
+#[actix_rt::test]
+async fn test_etag() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .service(web::resource("/").to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%202)%20%7D")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 200);
+    let etag = resp.headers().get("etag").unwrap().clone();
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%202)%20%7D")
+        .header("if-none-match", etag.clone())
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 304);
+    assert_eq!(resp.headers().get("etag").unwrap(), &etag);
+    assert!(test::read_body(resp).await.is_empty());
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20add(a%3A%201%2C%20b%3A%203)%20%7D")
+        .header("if-none-match", etag.clone())
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_ne!(resp.headers().get("etag").unwrap(), &etag);
+
+    let req = test::TestRequest::get()
+        .uri("/?query=%7B%20forwardedFor%20%7D")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert!(resp.headers().get("etag").is_none());
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .header("if-none-match", etag.clone())
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(resp.headers().get("etag").is_none());
+}
+
+#[actix_rt::test]
+async fn test_defer() {
+    struct DeferQueryRoot;
+
+    #[Object]
+    impl DeferQueryRoot {
+        async fn value(&self) -> Deferred<i32> {
+            10.into()
+        }
+    }
+
+    type DeferSchema = Schema<DeferQueryRoot, EmptyMutation, EmptySubscription>;
+
+    async fn index_stream(schema: web::Data<DeferSchema>, req: GQLRequest) -> GQLResponseStream {
+        req.into_inner().execute_stream(&schema).await.into()
+    }
+
+    let mut app = test::init_service(
+        App::new()
+            .data(Schema::new(
+                DeferQueryRoot,
+                EmptyMutation,
+                EmptySubscription,
+            ))
+            .service(web::resource("/").guard(guard::Post()).to(index_stream)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ value @defer }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"value": 10}}));
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .header("accept", "multipart/mixed, application/json")
+        .set_payload(r#"{"query": "{ value @defer }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert!(resp
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .starts_with("multipart/mixed"));
+}
+
+#[actix_rt::test]
+async fn test_subscription_sse() {
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl futures::Stream<Item = i32> {
+            futures::stream::iter(0..2)
+        }
+    }
+
+    type SubscriptionSchema = Schema<QueryRoot, EmptyMutation, SubscriptionRoot>;
+
+    async fn index_sse(
+        schema: web::Data<SubscriptionSchema>,
+        req: GQLRequest,
+    ) -> GQLSubscriptionSse {
+        req.into_inner().execute_subscription(&schema).await.into()
+    }
+
+    let mut app = test::init_service(
+        App::new()
+            .data(Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot))
+            .service(web::resource("/").guard(guard::Post()).to(index_sse)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "subscription { values }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+    assert_eq!(
+        test::read_body(resp).await,
+        "event: next\ndata: {\"data\":{\"values\":0}}\n\n\
+         event: next\ndata: {\"data\":{\"values\":1}}\n\n\
+         event: complete\ndata:\n\n"
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert!(resp["errors"].is_array());
+}
+
+#[actix_rt::test]
+async fn test_compress() {
+    let mut app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Compress::default())
+            .data(schema())
+            .service(web::resource("/").guard(guard::Post()).to(index)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/json")
+        .header("accept-encoding", "gzip")
+        .set_payload(r#"{"query": "{ add(a: 1, b: 2) }"}"#)
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.headers().get("content-encoding").unwrap(), "gzip");
+}
+
+#[actix_rt::test]
+async fn test_service_shared_config() {
+    struct OtherQueryRoot;
+
+    #[Object]
+    impl OtherQueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let mut app = test::init_service(
+        App::new()
+            .app_data(IntoQueryBuilderOpts {
+                max_request_size: Some(64),
+                ..Default::default()
+            })
+            .app_data(GQLRequestErrorHandler::json())
+            .service(GQLService::new("/public", schema()))
+            .service(GQLService::new(
+                "/internal",
+                Schema::new(OtherQueryRoot, EmptyMutation, EmptySubscription),
+            )),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/internal")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"query": "{ value }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"value": 10}}));
+
+    for path in &["/public", "/internal"] {
+        let req = test::TestRequest::post()
+            .uri(path)
+            .header("content-type", "application/json")
+            .set_payload(format!(r#"{{"query": "{{ {} }}"}}"#, "a".repeat(64)))
+            .to_request();
+        let resp = test::call_service(&mut app, req).await;
+        assert_eq!(resp.status(), 413);
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+    }
+}
+
+#[actix_rt::test]
+async fn test_request_data() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .app_data(GQLRequestData::new(|req: &HttpRequest, builder| match req
+                .headers()
+                .get("x-user-id")
+                .and_then(|value| value.to_str().ok())
+            {
+                Some(user_id) => builder.data(UserId(user_id.to_string())),
+                None => builder,
+            }))
+            .service(web::resource("/").guard(guard::Post()).to(index_batch)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("x-user-id", "alice")
+        .set_payload(r#"[{"query": "{ userId }"}, {"query": "{ userId }"}]"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(
+        resp,
+        json!([{"data": {"userId": "alice"}}, {"data": {"userId": "alice"}}])
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .set_payload(r#"{"query": "{ userId }"}"#)
+        .to_request();
+    let resp: serde_json::Value = test::read_response_json(&mut app, req).await;
+    assert_eq!(resp, json!({"data": {"userId": null}}));
+}
+
+#[actix_rt::test]
+async fn test_http_conformance() {
+    let mut app = test::init_service(
+        App::new()
+            .data(schema())
+            .app_data(GQLHttpConformance)
+            .service(web::resource("/").to(index)),
+    )
+    .await;
+
+    let post = |accept: Option<&str>, query: &str| {
+        let req = test::TestRequest::post()
+            .uri("/")
+            .header("content-type", "application/json");
+        match accept {
+            Some(accept) => req.header("accept", accept),
+            None => req,
+        }
+        .set_payload(json!({ "query": query }).to_string())
+        .to_request()
+    };
+
+    let resp = test::call_service(
+        &mut app,
+        post(
+            Some("application/graphql-response+json"),
+            "{ add(a: 10, b: 20) }",
+        ),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/graphql-response+json"
+    );
+
+    let resp = test::call_service(&mut app, post(Some("*/*"), "{ add(a: 10")).await;
+    assert_eq!(resp.status(), 400);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/graphql-response+json"
+    );
+
+    let resp = test::call_service(
+        &mut app,
+        post(Some("application/graphql-response+json"), "{ unknown }"),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    let resp = test::call_service(&mut app, post(Some("application/json"), "{ unknown }")).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let resp = test::call_service(&mut app, post(None, "{ add(a: 10, b: 20) }")).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let resp = test::call_service(&mut app, post(Some("text/html"), "{ add(a: 10, b: 20) }")).await;
+    assert_eq!(resp.status(), 406);
+
+    let req = test::TestRequest::get()
+        .uri("/?query=mutation%20%7B%20add%20%7D")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+    assert_eq!(resp.status(), 405);
+    assert_eq!(resp.headers().get("allow").unwrap(), "POST");
+}
+
+#[actix_rt::test]
+async fn test_http_conformance_server_errors() {
+    async fn timeout() -> GQLResponse {
+        Err(Error::Timeout).into()
+    }
+
+    async fn remote() -> GQLResponse {
+        Err(Error::Remote {
+            errors: vec![ServerError {
+                message: "remote".to_string(),
+                locations: Vec::new(),
+                path: Vec::new(),
+                extensions: None,
+            }],
+        })
+        .into()
+    }
+
+    let mut app = test::init_service(
+        App::new()
+            .app_data(GQLHttpConformance)
+            .service(web::resource("/timeout").to(timeout))
+            .service(web::resource("/remote").to(remote)),
+    )
+    .await;
+
+    for (uri, status) in &[("/timeout", 504), ("/remote", 502)] {
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .header("accept", "application/graphql-response+json")
+            .to_request();
+        let resp = test::call_service(&mut app, req).await;
+        assert_eq!(resp.status(), *status);
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .header("accept", "application/json")
+            .to_request();
+        let resp = test::call_service(&mut app, req).await;
+        assert_eq!(resp.status(), 200);
+    }
+}
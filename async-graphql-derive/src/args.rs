@@ -2,6 +2,7 @@ use crate::utils::{
     get_rustdoc, parse_default, parse_default_with, parse_guards, parse_post_guards,
     parse_validator,
 };
+use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Attribute, AttributeArgs, Error, Lit, Meta, MetaList, NestedMeta, Result, Type};
@@ -61,12 +62,273 @@ impl CacheControl {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct DocOptions {
+    pub max_lines: Option<usize>,
+    pub hide_prefix: Option<String>,
+}
+
+impl DocOptions {
+    pub fn parse(ls: &MetaList) -> Result<Self> {
+        let mut doc = Self::default();
+
+        for meta in &ls.nested {
+            match meta {
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("first_line") => {
+                    doc.max_lines = Some(1);
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) => {
+                    if nv.path.is_ident("max_lines") {
+                        if let Lit::Int(n) = &nv.lit {
+                            match n.base10_parse::<usize>() {
+                                Ok(n) => doc.max_lines = Some(n),
+                                Err(err) => {
+                                    return Err(Error::new_spanned(&nv.lit, err));
+                                }
+                            }
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'max_lines' must be integer.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("hide_prefix") {
+                        if let Lit::Str(lit) = &nv.lit {
+                            doc.hide_prefix = Some(lit.value());
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'hide_prefix' should be a string.",
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(doc)
+    }
+
+    /// Get the rustdoc of the attributes as a GraphQL description.
+    pub fn rustdoc(&self, attrs: &[Attribute]) -> Result<Option<String>> {
+        let doc = match get_rustdoc(attrs)? {
+            Some(doc) => doc,
+            None => return Ok(None),
+        };
+        let lines = doc.lines().filter(|line| match &self.hide_prefix {
+            Some(prefix) => !line.starts_with(prefix.as_str()),
+            None => true,
+        });
+        let lines: Vec<_> = match self.max_lines {
+            Some(max_lines) => lines.take(max_lines).collect(),
+            None => lines.collect(),
+        };
+        let doc = lines.join("\n").trim().to_string();
+        Ok(if doc.is_empty() { None } else { Some(doc) })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    pub fn parse(lit: &Lit) -> Result<Self> {
+        match lit {
+            Lit::Str(value) => match value.value().as_str() {
+                "lowercase" => Ok(Self::Lower),
+                "UPPERCASE" => Ok(Self::Upper),
+                "PascalCase" => Ok(Self::Pascal),
+                "camelCase" => Ok(Self::Camel),
+                "snake_case" => Ok(Self::Snake),
+                "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+                _ => Err(Error::new_spanned(
+                    lit,
+                    "Rename rule should be one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\" or \"SCREAMING_SNAKE_CASE\".",
+                )),
+            },
+            _ => Err(Error::new_spanned(lit, "Rename rule should be a string.")),
+        }
+    }
+
+    pub fn rename(self, name: &str) -> String {
+        match self {
+            Self::Lower => name.to_lowercase(),
+            Self::Upper => name.to_uppercase(),
+            Self::Pascal => name.to_pascal_case(),
+            Self::Camel => name.to_camel_case(),
+            Self::Snake => name.to_snake_case(),
+            Self::ScreamingSnake => name.to_screaming_snake_case(),
+        }
+    }
+}
+
+pub struct ConcreteType {
+    pub name: String,
+    pub params: Vec<Type>,
+}
+
+impl ConcreteType {
+    pub fn parse(ls: &MetaList) -> Result<Self> {
+        let mut name = None;
+        let mut params = Vec::new();
+
+        for meta in &ls.nested {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                    if let syn::Lit::Str(lit) = &nv.lit {
+                        name = Some(lit.value());
+                    } else {
+                        return Err(Error::new_spanned(
+                            &nv.lit,
+                            "Attribute 'name' should be a string.",
+                        ));
+                    }
+                }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("params") => {
+                    for meta in &ls.nested {
+                        if let NestedMeta::Meta(Meta::Path(p)) = meta {
+                            params.push(Type::Path(syn::TypePath {
+                                qself: None,
+                                path: p.clone(),
+                            }));
+                        } else {
+                            return Err(Error::new_spanned(meta, "Invalid type parameter."));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match name {
+            Some(name) => Ok(Self { name, params }),
+            None => Err(Error::new_spanned(ls, "Missing name.")),
+        }
+    }
+}
+
+pub struct AppliedDirective {
+    pub name: String,
+    pub args: Vec<(String, Lit)>,
+}
+
+impl AppliedDirective {
+    pub fn parse(ls: &MetaList) -> Result<Vec<Self>> {
+        let mut directives = Vec::new();
+
+        for meta in &ls.nested {
+            match meta {
+                NestedMeta::Meta(Meta::Path(p)) if p.get_ident().is_some() => {
+                    directives.push(Self {
+                        name: p.get_ident().unwrap().to_string(),
+                        args: Vec::new(),
+                    });
+                }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.get_ident().is_some() => {
+                    let mut args = Vec::new();
+                    for meta in &ls.nested {
+                        match meta {
+                            NestedMeta::Meta(Meta::NameValue(nv))
+                                if nv.path.get_ident().is_some() =>
+                            {
+                                args.push((
+                                    nv.path.get_ident().unwrap().to_string(),
+                                    nv.lit.clone(),
+                                ));
+                            }
+                            _ => {
+                                return Err(Error::new_spanned(
+                                    meta,
+                                    "Directive arguments should be like `name = value`.",
+                                ))
+                            }
+                        }
+                    }
+                    directives.push(Self {
+                        name: ls.path.get_ident().unwrap().to_string(),
+                        args,
+                    });
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "Directives should be like `directive(tag(name = \"internal\"))`.",
+                    ))
+                }
+            }
+        }
+
+        Ok(directives)
+    }
+
+    /// Parse the shorthands for the Apollo Federation v2 directives, `shareable`, `inaccessible`,
+    /// `override_from = "subgraph"` and `tag = "name"`.
+    pub fn parse_federation(meta: &NestedMeta) -> Result<Option<Self>> {
+        match meta {
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("shareable") => Ok(Some(Self {
+                name: "shareable".to_string(),
+                args: Vec::new(),
+            })),
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("inaccessible") => Ok(Some(Self {
+                name: "inaccessible".to_string(),
+                args: Vec::new(),
+            })),
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("override_from") => {
+                if let Lit::Str(_) = &nv.lit {
+                    Ok(Some(Self {
+                        name: "override".to_string(),
+                        args: vec![("from".to_string(), nv.lit.clone())],
+                    }))
+                } else {
+                    Err(Error::new_spanned(
+                        &nv.lit,
+                        "Attribute 'override_from' should be a string.",
+                    ))
+                }
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("tag") => {
+                if let Lit::Str(_) = &nv.lit {
+                    Ok(Some(Self {
+                        name: "tag".to_string(),
+                        args: vec![("name".to_string(), nv.lit.clone())],
+                    }))
+                } else {
+                    Err(Error::new_spanned(
+                        &nv.lit,
+                        "Attribute 'tag' should be a string.",
+                    ))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
 pub struct Object {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
     pub cache_control: CacheControl,
     pub extends: bool,
+    pub concretes: Vec<ConcreteType>,
+    pub rename_fields: RenameRule,
+    pub rename_args: RenameRule,
+    pub complex: bool,
+    pub visible: Option<syn::Path>,
+    pub directives: Vec<AppliedDirective>,
+    pub input_name: Option<String>,
+    pub doc: DocOptions,
+    pub use_type_description: bool,
+    pub by_ref: bool,
+    pub remote: Option<Type>,
 }
 
 impl Object {
@@ -76,15 +338,39 @@ impl Object {
         let mut desc = None;
         let mut cache_control = CacheControl::default();
         let mut extends = false;
+        let mut concretes = Vec::new();
+        let mut rename_fields = RenameRule::Camel;
+        let mut rename_args = RenameRule::Camel;
+        let mut complex = false;
+        let mut visible = None;
+        let mut directives = Vec::new();
+        let mut input_name = None;
+        let mut doc = DocOptions::default();
+        let mut use_type_description = false;
+        let mut by_ref = false;
+        let mut remote = None;
 
         for arg in args {
+            if let Some(directive) = AppliedDirective::parse_federation(&arg)? {
+                directives.push(directive);
+                continue;
+            }
             match arg {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
                     internal = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("complex") => {
+                    complex = true;
+                }
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("extends") => {
                     extends = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("use_type_description") => {
+                    use_type_description = true;
+                }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("by_ref") => {
+                    by_ref = true;
+                }
                 NestedMeta::Meta(Meta::NameValue(nv)) => {
                     if nv.path.is_ident("name") {
                         if let syn::Lit::Str(lit) = nv.lit {
@@ -104,11 +390,48 @@ impl Object {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("rename_fields") {
+                        rename_fields = RenameRule::parse(&nv.lit)?;
+                    } else if nv.path.is_ident("rename_args") {
+                        rename_args = RenameRule::parse(&nv.lit)?;
+                    } else if nv.path.is_ident("input_name") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            input_name = Some(lit.value());
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'input_name' should be a string.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("remote") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            remote = Some(lit.parse::<Type>()?);
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'remote' should be a string.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("visible") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            visible = Some(lit.parse::<syn::Path>()?);
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'visible' should be a string.",
+                            ));
+                        }
                     }
                 }
                 NestedMeta::Meta(Meta::List(ls)) => {
                     if ls.path.is_ident("cache_control") {
                         cache_control = CacheControl::parse(&ls)?;
+                    } else if ls.path.is_ident("concrete") {
+                        concretes.push(ConcreteType::parse(&ls)?);
+                    } else if ls.path.is_ident("directive") {
+                        directives.extend(AppliedDirective::parse(&ls)?);
+                    } else if ls.path.is_ident("doc") {
+                        doc = DocOptions::parse(&ls)?;
                     }
                 }
                 _ => {}
@@ -121,6 +444,17 @@ impl Object {
             desc,
             cache_control,
             extends,
+            concretes,
+            rename_fields,
+            rename_args,
+            complex,
+            visible,
+            directives,
+            input_name,
+            doc,
+            use_type_description,
+            by_ref,
+            remote,
         })
     }
 }
@@ -130,6 +464,7 @@ pub struct Argument {
     pub desc: Option<String>,
     pub default: Option<TokenStream>,
     pub validator: TokenStream,
+    pub secret: bool,
 }
 
 impl Argument {
@@ -138,6 +473,7 @@ impl Argument {
         let mut desc = None;
         let mut default = None;
         let mut validator = quote! { None };
+        let mut secret = false;
 
         for attr in attrs {
             match attr.parse_meta()? {
@@ -146,6 +482,8 @@ impl Argument {
                         if let NestedMeta::Meta(Meta::Path(p)) = meta {
                             if p.is_ident("default") {
                                 default = Some(quote! { Default::default() });
+                            } else if p.is_ident("secret") {
+                                secret = true;
                             }
                         } else if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
                             if nv.path.is_ident("name") {
@@ -185,6 +523,7 @@ impl Argument {
             desc,
             default,
             validator,
+            secret,
         })
     }
 }
@@ -197,14 +536,24 @@ pub struct Field {
     pub external: bool,
     pub provides: Option<String>,
     pub requires: Option<String>,
-    pub is_ref: bool,
+    pub is_ref: Option<bool>,
     pub guard: Option<TokenStream>,
     pub post_guard: Option<TokenStream>,
     pub features: Vec<String>,
+    pub flatten: bool,
+    pub visible: Option<syn::Path>,
+    pub directives: Vec<AppliedDirective>,
+    pub with: Option<syn::Path>,
+    pub ty: Option<Type>,
+    pub getter: Option<syn::Path>,
 }
 
 impl Field {
-    pub fn parse(crate_name: &TokenStream, attrs: &[Attribute]) -> Result<Option<Self>> {
+    pub fn parse(
+        crate_name: &TokenStream,
+        attrs: &[Attribute],
+        doc: &DocOptions,
+    ) -> Result<Option<Self>> {
         let mut name = None;
         let mut desc = None;
         let mut deprecation = None;
@@ -213,9 +562,15 @@ impl Field {
         let mut provides = None;
         let mut requires = None;
         let mut features = Vec::new();
-        let mut is_ref = false;
+        let mut is_ref = None;
         let mut guard = None;
         let mut post_guard = None;
+        let mut flatten = false;
+        let mut visible = None;
+        let mut directives = Vec::new();
+        let mut with = None;
+        let mut ty = None;
+        let mut getter = None;
 
         for attr in attrs {
             match attr.parse_meta()? {
@@ -223,6 +578,10 @@ impl Field {
                     guard = parse_guards(crate_name, &ls)?;
                     post_guard = parse_post_guards(crate_name, &ls)?;
                     for meta in &ls.nested {
+                        if let Some(directive) = AppliedDirective::parse_federation(meta)? {
+                            directives.push(directive);
+                            continue;
+                        }
                         match meta {
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("skip") => {
                                 return Ok(None);
@@ -231,7 +590,25 @@ impl Field {
                                 external = true;
                             }
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("ref") => {
-                                is_ref = true;
+                                if is_ref == Some(false) {
+                                    return Err(Error::new_spanned(
+                                        p,
+                                        "Attributes 'ref' and 'owned' can't be used together.",
+                                    ));
+                                }
+                                is_ref = Some(true);
+                            }
+                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("owned") => {
+                                if is_ref == Some(true) {
+                                    return Err(Error::new_spanned(
+                                        p,
+                                        "Attributes 'ref' and 'owned' can't be used together.",
+                                    ));
+                                }
+                                is_ref = Some(false);
+                            }
+                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("flatten") => {
+                                flatten = true;
                             }
                             NestedMeta::Meta(Meta::NameValue(nv)) => {
                                 if nv.path.is_ident("name") {
@@ -243,6 +620,33 @@ impl Field {
                                             "Attribute 'name' should be a string.",
                                         ));
                                     }
+                                } else if nv.path.is_ident("with") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        with = Some(lit.parse::<syn::Path>()?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'with' should be a string.",
+                                        ));
+                                    }
+                                } else if nv.path.is_ident("getter") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        getter = Some(lit.parse::<syn::Path>()?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'getter' should be a string.",
+                                        ));
+                                    }
+                                } else if nv.path.is_ident("type") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        ty = Some(lit.parse::<Type>()?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'type' should be a string.",
+                                        ));
+                                    }
                                 } else if nv.path.is_ident("desc") {
                                     if let syn::Lit::Str(lit) = &nv.lit {
                                         desc = Some(lit.value());
@@ -293,11 +697,22 @@ impl Field {
                                             "Attribute 'feature' should be a string.",
                                         ));
                                     }
+                                } else if nv.path.is_ident("visible") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        visible = Some(lit.parse::<syn::Path>()?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'visible' should be a string.",
+                                        ));
+                                    }
                                 }
                             }
                             NestedMeta::Meta(Meta::List(ls)) => {
                                 if ls.path.is_ident("cache_control") {
                                     cache_control = CacheControl::parse(ls)?;
+                                } else if ls.path.is_ident("directive") {
+                                    directives.extend(AppliedDirective::parse(ls)?);
                                 }
                             }
                             _ => {}
@@ -309,7 +724,7 @@ impl Field {
         }
 
         if desc.is_none() {
-            desc = get_rustdoc(attrs)?;
+            desc = doc.rustdoc(attrs)?;
         }
 
         Ok(Some(Self {
@@ -324,6 +739,12 @@ impl Field {
             guard,
             post_guard,
             features,
+            flatten,
+            visible,
+            directives,
+            with,
+            ty,
+            getter,
         }))
     }
 }
@@ -332,6 +753,11 @@ pub struct Enum {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub remote: Option<syn::Path>,
+    pub rename_items: RenameRule,
+    pub visible: Option<syn::Path>,
+    pub directives: Vec<AppliedDirective>,
+    pub doc: DocOptions,
 }
 
 impl Enum {
@@ -339,8 +765,17 @@ impl Enum {
         let mut internal = false;
         let mut name = None;
         let mut desc = None;
+        let mut remote = None;
+        let mut rename_items = RenameRule::ScreamingSnake;
+        let mut visible = None;
+        let mut directives = Vec::new();
+        let mut doc = DocOptions::default();
 
         for arg in args {
+            if let Some(directive) = AppliedDirective::parse_federation(&arg)? {
+                directives.push(directive);
+                continue;
+            }
             match arg {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
                     internal = true;
@@ -364,8 +799,34 @@ impl Enum {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("remote") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            remote = Some(lit.parse::<syn::Path>()?);
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'remote' should be a string.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("rename_items") {
+                        rename_items = RenameRule::parse(&nv.lit)?;
+                    } else if nv.path.is_ident("visible") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            visible = Some(lit.parse::<syn::Path>()?);
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'visible' should be a string.",
+                            ));
+                        }
                     }
                 }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("directive") => {
+                    directives.extend(AppliedDirective::parse(&ls)?);
+                }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("doc") => {
+                    doc = DocOptions::parse(&ls)?;
+                }
                 _ => {}
             }
         }
@@ -374,6 +835,11 @@ impl Enum {
             internal,
             name,
             desc,
+            remote,
+            rename_items,
+            visible,
+            directives,
+            doc,
         })
     }
 }
@@ -382,13 +848,17 @@ pub struct EnumItem {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub deprecation: Option<String>,
+    pub visible: Option<syn::Path>,
+    pub value: Option<String>,
 }
 
 impl EnumItem {
-    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+    pub fn parse(attrs: &[Attribute], doc: &DocOptions) -> Result<Self> {
         let mut name = None;
         let mut desc = None;
         let mut deprecation = None;
+        let mut visible = None;
+        let mut value = None;
 
         for attr in attrs {
             if attr.path.is_ident("item") {
@@ -422,6 +892,24 @@ impl EnumItem {
                                         "Attribute 'deprecation' should be a string.",
                                     ));
                                 }
+                            } else if nv.path.is_ident("visible") {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    visible = Some(lit.parse::<syn::Path>()?);
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &nv.lit,
+                                        "Attribute 'visible' should be a string.",
+                                    ));
+                                }
+                            } else if nv.path.is_ident("value") {
+                                if let syn::Lit::Str(lit) = nv.lit {
+                                    value = Some(lit.value());
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &nv.lit,
+                                        "Attribute 'value' should be a string.",
+                                    ));
+                                }
                             }
                         }
                     }
@@ -430,13 +918,15 @@ impl EnumItem {
         }
 
         if desc.is_none() {
-            desc = get_rustdoc(attrs)?;
+            desc = doc.rustdoc(attrs)?;
         }
 
         Ok(Self {
             name,
             desc,
             deprecation,
+            visible,
+            value,
         })
     }
 }
@@ -446,19 +936,27 @@ pub struct InputField {
     pub desc: Option<String>,
     pub default: Option<TokenStream>,
     pub validator: TokenStream,
+    pub visible: Option<syn::Path>,
+    pub directives: Vec<AppliedDirective>,
 }
 
 impl InputField {
-    pub fn parse(crate_name: &TokenStream, attrs: &[Attribute]) -> Result<Self> {
+    pub fn parse(crate_name: &TokenStream, attrs: &[Attribute], doc: &DocOptions) -> Result<Self> {
         let mut name = None;
         let mut desc = None;
         let mut default = None;
         let mut validator = quote! { None };
+        let mut visible = None;
+        let mut directives = Vec::new();
 
         for attr in attrs {
             if attr.path.is_ident("field") {
                 if let Meta::List(args) = &attr.parse_meta()? {
                     for meta in &args.nested {
+                        if let Some(directive) = AppliedDirective::parse_federation(meta)? {
+                            directives.push(directive);
+                            continue;
+                        }
                         match meta {
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("skip") => {
                                 return Err(Error::new_spanned(
@@ -492,8 +990,20 @@ impl InputField {
                                     default = Some(parse_default(&nv.lit)?);
                                 } else if nv.path.is_ident("default_with") {
                                     default = Some(parse_default_with(&nv.lit)?);
+                                } else if nv.path.is_ident("visible") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        visible = Some(lit.parse::<syn::Path>()?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'visible' should be a string.",
+                                        ));
+                                    }
                                 }
                             }
+                            NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("directive") => {
+                                directives.extend(AppliedDirective::parse(ls)?);
+                            }
                             _ => {}
                         }
                     }
@@ -504,7 +1014,7 @@ impl InputField {
         }
 
         if desc.is_none() {
-            desc = get_rustdoc(attrs)?;
+            desc = doc.rustdoc(attrs)?;
         }
 
         Ok(Self {
@@ -512,6 +1022,8 @@ impl InputField {
             desc,
             default,
             validator,
+            visible,
+            directives,
         })
     }
 }
@@ -520,6 +1032,10 @@ pub struct InputObject {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub rename_fields: RenameRule,
+    pub visible: Option<syn::Path>,
+    pub directives: Vec<AppliedDirective>,
+    pub doc: DocOptions,
 }
 
 impl InputObject {
@@ -527,8 +1043,16 @@ impl InputObject {
         let mut internal = false;
         let mut name = None;
         let mut desc = None;
+        let mut rename_fields = RenameRule::Camel;
+        let mut visible = None;
+        let mut directives = Vec::new();
+        let mut doc = DocOptions::default();
 
         for arg in args {
+            if let Some(directive) = AppliedDirective::parse_federation(&arg)? {
+                directives.push(directive);
+                continue;
+            }
             match arg {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
                     internal = true;
@@ -552,8 +1076,25 @@ impl InputObject {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("rename_fields") {
+                        rename_fields = RenameRule::parse(&nv.lit)?;
+                    } else if nv.path.is_ident("visible") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            visible = Some(lit.parse::<syn::Path>()?);
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'visible' should be a string.",
+                            ));
+                        }
                     }
                 }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("directive") => {
+                    directives.extend(AppliedDirective::parse(&ls)?);
+                }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("doc") => {
+                    doc = DocOptions::parse(&ls)?;
+                }
                 _ => {}
             }
         }
@@ -562,6 +1103,10 @@ impl InputObject {
             internal,
             name,
             desc,
+            rename_fields,
+            visible,
+            directives,
+            doc,
         })
     }
 }
@@ -571,6 +1116,7 @@ pub struct InterfaceFieldArgument {
     pub desc: Option<String>,
     pub ty: Type,
     pub default: Option<TokenStream>,
+    pub secret: bool,
 }
 
 impl InterfaceFieldArgument {
@@ -579,11 +1125,14 @@ impl InterfaceFieldArgument {
         let mut desc = None;
         let mut ty = None;
         let mut default = None;
+        let mut secret = false;
 
         for meta in &ls.nested {
             if let NestedMeta::Meta(Meta::Path(p)) = meta {
                 if p.is_ident("default") {
                     default = Some(quote! { Default::default() });
+                } else if p.is_ident("secret") {
+                    secret = true;
                 }
             } else if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
                 if nv.path.is_ident("name") {
@@ -638,6 +1187,7 @@ impl InterfaceFieldArgument {
             desc,
             ty: ty.unwrap(),
             default,
+            secret,
         })
     }
 }
@@ -652,6 +1202,7 @@ pub struct InterfaceField {
     pub external: bool,
     pub provides: Option<String>,
     pub requires: Option<String>,
+    pub default_impl: bool,
 }
 
 impl InterfaceField {
@@ -665,12 +1216,16 @@ impl InterfaceField {
         let mut external = false;
         let mut provides = None;
         let mut requires = None;
+        let mut default_impl = false;
 
         for meta in &ls.nested {
             match meta {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("external") => {
                     external = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("default_impl") => {
+                    default_impl = true;
+                }
                 NestedMeta::Meta(Meta::NameValue(nv)) => {
                     if nv.path.is_ident("name") {
                         if let syn::Lit::Str(lit) = &nv.lit {
@@ -766,6 +1321,7 @@ impl InterfaceField {
             external,
             requires,
             provides,
+            default_impl,
         })
     }
 }
@@ -776,6 +1332,8 @@ pub struct Interface {
     pub desc: Option<String>,
     pub fields: Vec<InterfaceField>,
     pub extends: bool,
+    pub implements: Vec<Type>,
+    pub concretes: Vec<ConcreteType>,
 }
 
 impl Interface {
@@ -785,6 +1343,8 @@ impl Interface {
         let mut desc = None;
         let mut fields = Vec::new();
         let mut extends = false;
+        let mut implements = Vec::new();
+        let mut concretes = Vec::new();
 
         for arg in args {
             match arg {
@@ -813,11 +1373,27 @@ impl Interface {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("implements") {
+                        if let syn::Lit::Str(lit) = &nv.lit {
+                            if let Ok(ty) = syn::parse_str::<syn::Type>(&lit.value()) {
+                                implements.push(ty);
+                            } else {
+                                return Err(Error::new_spanned(lit, "Expect type"));
+                            }
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'implements' should be a string.",
+                            ));
+                        }
                     }
                 }
                 NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("field") => {
                     fields.push(InterfaceField::parse(&ls)?);
                 }
+                NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("concrete") => {
+                    concretes.push(ConcreteType::parse(&ls)?);
+                }
                 _ => {}
             }
         }
@@ -828,6 +1404,8 @@ impl Interface {
             desc,
             fields,
             extends,
+            implements,
+            concretes,
         })
     }
 }
@@ -905,6 +1483,108 @@ impl Scalar {
     }
 }
 
+pub struct NewType {
+    pub internal: bool,
+    pub name: Option<String>,
+    pub desc: Option<String>,
+    pub validator: Option<syn::Path>,
+}
+
+impl NewType {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut internal = false;
+        let mut name = None;
+        let mut desc = None;
+        let mut validator = None;
+
+        for attr in attrs {
+            if !attr.path.is_ident("graphql") {
+                continue;
+            }
+            if let Meta::List(ls) = attr.parse_meta()? {
+                for meta in &ls.nested {
+                    match meta {
+                        NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
+                            internal = true;
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) => {
+                            if nv.path.is_ident("name") {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    name = Some(lit.value());
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &nv.lit,
+                                        "Attribute 'name' should be a string.",
+                                    ));
+                                }
+                            } else if nv.path.is_ident("desc") {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    desc = Some(lit.value());
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &nv.lit,
+                                        "Attribute 'desc' should be a string.",
+                                    ));
+                                }
+                            } else if nv.path.is_ident("validator") {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    validator = Some(lit.parse::<syn::Path>()?);
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &nv.lit,
+                                        "Attribute 'validator' should be a string.",
+                                    ));
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            internal,
+            name,
+            desc,
+            validator,
+        })
+    }
+}
+
+pub struct Description {
+    pub internal: bool,
+    pub doc: DocOptions,
+}
+
+impl Description {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut internal = false;
+        let mut doc = DocOptions::default();
+
+        for attr in attrs {
+            if !attr.path.is_ident("graphql") {
+                continue;
+            }
+            if let Meta::List(ls) = attr.parse_meta()? {
+                for meta in &ls.nested {
+                    match meta {
+                        NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
+                            internal = true;
+                        }
+                        NestedMeta::Meta(Meta::List(ls)) if ls.path.is_ident("doc") => {
+                            doc = DocOptions::parse(ls)?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(Self { internal, doc })
+    }
+}
+
 pub struct Entity {}
 
 impl Entity {
@@ -0,0 +1,29 @@
+use crate::args;
+use crate::utils::get_crate_name;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Result};
+
+pub fn generate(description_args: &args::Description, input: &DeriveInput) -> Result<TokenStream> {
+    let crate_name = get_crate_name(description_args.internal);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let desc = match description_args.doc.rustdoc(&input.attrs)? {
+        Some(desc) => desc,
+        None => {
+            return Err(Error::new_spanned(
+                ident,
+                "Description can only be derived for a type with a doc comment.",
+            ))
+        }
+    };
+
+    let expanded = quote! {
+        impl #impl_generics #crate_name::Description for #ident #ty_generics #where_clause {
+            fn description() -> &'static str {
+                #desc
+            }
+        }
+    };
+    Ok(expanded.into())
+}
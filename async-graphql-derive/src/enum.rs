@@ -1,6 +1,5 @@
 use crate::args;
-use crate::utils::{get_crate_name, get_rustdoc};
-use inflector::Inflector;
+use crate::utils::{applied_directives, get_crate_name, visible_fn};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Error, Result};
@@ -18,13 +17,16 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
     let desc = enum_args
         .desc
         .clone()
-        .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
+        .or_else(|| enum_args.doc.rustdoc(&input.attrs).ok().flatten())
         .map(|s| quote! { Some(#s) })
         .unwrap_or_else(|| quote! {None});
+    let visible = visible_fn(&crate_name, &enum_args.visible);
+    let directives = applied_directives(&crate_name, &enum_args.directives)?;
 
     let mut enum_items = Vec::new();
     let mut items = Vec::new();
     let mut schema_enum_items = Vec::new();
+    let mut item_idents = Vec::new();
 
     for variant in &e.variants {
         if !variant.fields.is_empty() {
@@ -38,16 +40,17 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
         }
 
         let item_ident = &variant.ident;
+        item_idents.push(item_ident);
         let item_attrs = variant
             .attrs
             .iter()
             .filter(|attr| !attr.path.is_ident("item"))
             .collect::<Vec<_>>();
-        let mut item_args = args::EnumItem::parse(&variant.attrs)?;
+        let mut item_args = args::EnumItem::parse(&variant.attrs, &enum_args.doc)?;
         let gql_item_name = item_args
             .name
             .take()
-            .unwrap_or_else(|| variant.ident.to_string().to_screaming_snake_case());
+            .unwrap_or_else(|| enum_args.rename_items.rename(&variant.ident.to_string()));
         let item_deprecation = item_args
             .deprecation
             .as_ref()
@@ -58,11 +61,18 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
             .as_ref()
             .map(|s| quote! { Some(#s) })
             .unwrap_or_else(|| quote! {None});
+        let item_visible = visible_fn(&crate_name, &item_args.visible);
+        let custom_value = item_args
+            .value
+            .as_ref()
+            .map(|s| quote! { Some(#s) })
+            .unwrap_or_else(|| quote! {None});
         enum_items.push(quote! { #(#item_attrs)* #item_ident});
         items.push(quote! {
             #crate_name::EnumItem {
                 name: #gql_item_name,
                 value: #ident::#item_ident,
+                custom_value: #custom_value,
             }
         });
         schema_enum_items.push(quote! {
@@ -70,11 +80,34 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
                 name: #gql_item_name,
                 description: #item_desc,
                 deprecation: #item_deprecation,
+                visible: #item_visible,
             });
         });
     }
 
+    let remote_conversions = enum_args.remote.as_ref().map(|remote| {
+        quote! {
+            impl From<#ident> for #remote {
+                fn from(value: #ident) -> Self {
+                    match value {
+                        #(#ident::#item_idents => #remote::#item_idents,)*
+                    }
+                }
+            }
+
+            impl From<#remote> for #ident {
+                fn from(value: #remote) -> Self {
+                    match value {
+                        #(#remote::#item_idents => #ident::#item_idents,)*
+                    }
+                }
+            }
+        }
+    });
+
     let expanded = quote! {
+        #remote_conversions
+
         impl #crate_name::EnumType for #ident {
             fn items() -> &'static [#crate_name::EnumItem<#ident>] {
                 &[#(#items),*]
@@ -96,6 +129,8 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
                             #(#schema_enum_items)*
                             enum_items
                         },
+                        visible: #visible,
+                        directives: #directives,
                     }
                 })
             }
@@ -114,7 +149,7 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
         #[#crate_name::async_trait::async_trait]
         impl #crate_name::OutputValueType for #ident {
             async fn resolve(&self, _: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::query::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
-                Ok(#crate_name::EnumType::to_value(self).into())
+                Ok(#crate_name::EnumType::to_output_value(self).into())
             }
         }
     };
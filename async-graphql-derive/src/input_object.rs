@@ -1,11 +1,29 @@
 use crate::args;
-use crate::utils::{get_crate_name, get_rustdoc};
-use inflector::Inflector;
+use crate::utils::{applied_directives, get_crate_name, visible_fn};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Error, Result};
 
 pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<TokenStream> {
+    Ok(expand(object_args, input, true)?.into())
+}
+
+/// Generate the input object of a `SimpleObject` with an `input_name`.
+///
+/// The `Type` implementation belongs to the output object, so only the input value
+/// implementations are generated.
+pub fn generate_for_output(
+    object_args: &args::InputObject,
+    input: &DeriveInput,
+) -> Result<proc_macro2::TokenStream> {
+    expand(object_args, input, false)
+}
+
+fn expand(
+    object_args: &args::InputObject,
+    input: &DeriveInput,
+    impl_type: bool,
+) -> Result<proc_macro2::TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
     let ident = &input.ident;
     let s = match &input.data {
@@ -37,9 +55,11 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
     let desc = object_args
         .desc
         .clone()
-        .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
+        .or_else(|| object_args.doc.rustdoc(&input.attrs).ok().flatten())
         .map(|s| quote! { Some(#s) })
         .unwrap_or_else(|| quote! {None});
+    let visible = visible_fn(&crate_name, &object_args.visible);
+    let directives = applied_directives(&crate_name, &object_args.directives)?;
 
     let mut get_fields = Vec::new();
     let mut put_fields = Vec::new();
@@ -47,17 +67,17 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
     let mut schema_fields = Vec::new();
 
     for field in &s.fields {
-        let field_args = args::InputField::parse(&crate_name, &field.attrs)?;
+        let field_args = args::InputField::parse(&crate_name, &field.attrs, &object_args.doc)?;
         let ident = field.ident.as_ref().unwrap();
         let ty = &field.ty;
         let validator = &field_args.validator;
         let name = field_args
             .name
-            .unwrap_or_else(|| ident.to_string().to_camel_case());
+            .unwrap_or_else(|| object_args.rename_fields.rename(&ident.to_string()));
         let desc = field_args
             .desc
             .as_ref()
-            .map(|s| quote! {Some(#s)})
+            .map(|s| quote! {Some(#s.to_string())})
             .unwrap_or_else(|| quote! {None});
         let schema_default = field_args
             .default
@@ -66,6 +86,8 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
                 quote! {Some( <#ty as #crate_name::InputValueType>::to_value(&#value).to_string() )}
             })
             .unwrap_or_else(|| quote! {None});
+        let field_visible = visible_fn(&crate_name, &field_args.visible);
+        let field_directives = applied_directives(&crate_name, &field_args.directives)?;
 
         if let Some(default) = &field_args.default {
             get_fields.push(quote! {
@@ -89,35 +111,61 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
         fields.push(ident);
         schema_fields.push(quote! {
             fields.insert(#name.to_string(), #crate_name::registry::MetaInputValue {
-                name: #name,
+                name: #name.to_string(),
                 description: #desc,
-                ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                ty: <#ty as #crate_name::InputValueType>::create_input_type_info(registry),
                 default_value: #schema_default,
                 validator: #validator,
+                visible: #field_visible,
+                directives: #field_directives,
+                is_secret: false,
             });
         })
     }
 
+    let type_impl = if impl_type {
+        quote! {
+            impl #crate_name::Type for #ident {
+                fn type_name() -> std::borrow::Cow<'static, str> {
+                    std::borrow::Cow::Borrowed(#gql_typename)
+                }
+
+                fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                    <Self as #crate_name::InputValueType>::create_input_type_info(registry)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
-        impl #crate_name::Type for #ident {
-            fn type_name() -> std::borrow::Cow<'static, str> {
+        #type_impl
+
+        impl #crate_name::InputValueType for #ident {
+            fn input_type_name() -> std::borrow::Cow<'static, str> {
                 std::borrow::Cow::Borrowed(#gql_typename)
             }
 
-            fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
-                registry.create_type::<Self, _>(|registry| #crate_name::registry::MetaType::InputObject {
+            fn qualified_input_type_name() -> String {
+                format!("{}!", #gql_typename)
+            }
+
+            fn create_input_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                registry.create_input_type::<Self, _>(|registry| #crate_name::registry::MetaType::InputObject {
                     name: #gql_typename.to_string(),
                     description: #desc,
                     input_fields: {
                         let mut fields = #crate_name::indexmap::IndexMap::new();
                         #(#schema_fields)*
                         fields
-                    }
+                    },
+                    visible: #visible,
+                    directives: #directives,
+                    oneof: false,
                 })
             }
-        }
 
-        impl #crate_name::InputValueType for #ident {
             fn parse(value: Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
                 use #crate_name::Type;
 
@@ -138,5 +186,5 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
 
         impl #crate_name::InputObjectType for #ident {}
     };
-    Ok(expanded.into())
+    Ok(expanded)
 }
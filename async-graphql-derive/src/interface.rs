@@ -7,12 +7,13 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
 use std::collections::HashSet;
-use syn::{Data, DeriveInput, Error, Fields, Result, Type};
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Result, Type};
 
 pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result<TokenStream> {
     let crate_name = get_crate_name(interface_args.internal);
     let ident = &input.ident;
     let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let s = match &input.data {
         Data::Enum(s) => s,
         _ => {
@@ -30,18 +31,24 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         .name
         .clone()
         .unwrap_or_else(|| ident.to_string());
+    let typename = if interface_args.concretes.is_empty() {
+        quote! { #gql_typename }
+    } else {
+        quote! { name }
+    };
 
     let desc = interface_args
         .desc
         .clone()
         .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
-        .map(|s| quote! { Some(#s) })
+        .map(|s| quote! { Some(#s.to_string()) })
         .unwrap_or_else(|| quote! {None});
 
     let mut registry_types = Vec::new();
     let mut possible_types = Vec::new();
     let mut collect_inline_fields = Vec::new();
     let mut get_introspection_typename = Vec::new();
+    let mut variant_types = Vec::new();
 
     for variant in s.variants.iter() {
         let enum_name = &variant.ident;
@@ -76,7 +83,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
             }
 
             type_into_impls.push(quote! {
-                impl #generics From<#p> for #ident #generics {
+                impl #impl_generics From<#p> for #ident #ty_generics #where_clause {
                     fn from(obj: #p) -> Self {
                         #ident::#enum_name(obj)
                     }
@@ -86,7 +93,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
 
             registry_types.push(quote! {
                 <#p as #crate_name::Type>::create_type_info(registry);
-                registry.add_implements(&<#p as #crate_name::Type>::type_name(), #gql_typename);
+                registry.add_implements(&<#p as #crate_name::Type>::type_name(), #typename);
             });
 
             possible_types.push(quote! {
@@ -101,12 +108,20 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
 
             get_introspection_typename.push(quote! {
                 #ident::#enum_name(obj) => <#p as #crate_name::Type>::type_name()
-            })
+            });
+            variant_types.push(p);
         } else {
             return Err(Error::new_spanned(field, "Invalid type"));
         }
     }
 
+    for ty in &interface_args.implements {
+        registry_types.push(quote! {
+            <#ty as #crate_name::Type>::create_type_info(registry);
+            registry.add_implements(#typename, &<#ty as #crate_name::Type>::type_name());
+        });
+    }
+
     let mut methods = Vec::new();
     let mut schema_fields = Vec::new();
     let mut resolvers = Vec::new();
@@ -121,6 +136,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         external,
         provides,
         requires,
+        default_impl,
     } in &interface_args.fields
     {
         let (name, method_name) = if let Some(method) = method {
@@ -151,6 +167,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
             desc,
             ty,
             default,
+            secret,
         } in args
         {
             let ident = Ident::new(name, Span::call_site());
@@ -168,7 +185,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
 
             let desc = desc
                 .as_ref()
-                .map(|s| quote! {Some(#s)})
+                .map(|s| quote! {Some(#s.to_string())})
                 .unwrap_or_else(|| quote! {None});
             let schema_default = default
                 .as_ref()
@@ -177,25 +194,28 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 })
                 .unwrap_or_else(|| quote! {None});
             schema_args.push(quote! {
-                args.insert(#name, #crate_name::registry::MetaInputValue {
-                    name: #name,
+                args.insert(#name.to_string(), #crate_name::registry::MetaInputValue {
+                    name: #name.to_string(),
                     description: #desc,
-                    ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                    ty: <#ty as #crate_name::InputValueType>::create_input_type_info(registry),
                     default_value: #schema_default,
                     validator: None,
+                    visible: None,
+                    directives: Default::default(),
+                    is_secret: #secret,
                 });
             });
         }
 
         for enum_name in &enum_names {
             calls.push(quote! {
-                #ident::#enum_name(obj) => obj.#method_name(#(#use_params),*).await
+                #ident::#enum_name(ref obj) => obj.#method_name(#(#use_params),*).await
             });
         }
 
         let desc = desc
             .as_ref()
-            .map(|s| quote! {Some(#s)})
+            .map(|s| quote! {Some(#s.to_string())})
             .unwrap_or_else(|| quote! {None});
         let deprecation = deprecation
             .as_ref()
@@ -209,14 +229,20 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         };
         let schema_ty = oty.value_type();
 
-        methods.push(quote! {
-            #[inline]
-            async fn #method_name <'ctx>(&self, #(#decl_params),*) -> #crate_name::FieldResult<#ty> {
-                match self {
-                    #(#calls,)*
+        if *default_impl {
+            registry_types.push(quote! {
+                registry.add_default_field(#typename, #name);
+            });
+        } else {
+            methods.push(quote! {
+                #[inline]
+                async fn #method_name <'ctx>(&self, #(#decl_params),*) -> #crate_name::FieldResult<#ty> {
+                    match *self {
+                        #(#calls,)*
+                    }
                 }
-            }
-        });
+            });
+        }
 
         schema_fields.push(quote! {
             fields.insert(#name.to_string(), #crate_name::registry::MetaField {
@@ -233,6 +259,8 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 external: #external,
                 provides: #provides,
                 requires: #requires,
+                visible: None,
+                directives: Default::default(),
             });
         });
 
@@ -241,8 +269,26 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 map_err(|err| err.into_error_with_path(ctx.position(), ctx.path_node.as_ref().unwrap().to_json()))?
         };
 
+        // A field with a default implementation is resolved by the possible type if it defines
+        // the field, otherwise by the method of the interface.
+        let resolve_override = if *default_impl && !enum_names.is_empty() {
+            quote! {
+                let type_name = #crate_name::Type::introspection_type_name(self);
+                if let Some(#crate_name::registry::MetaType::Object { fields, .. }) = ctx.registry().types.get(type_name.as_ref()) {
+                    if fields.contains_key(#name) {
+                        match self {
+                            #(#ident::#enum_names(obj) => return #crate_name::ObjectType::resolve_field(obj, ctx).await,)*
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         resolvers.push(quote! {
             if ctx.name.node == #name {
+                #resolve_override
                 #(#get_params)*
                 let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
                 return #crate_name::OutputValueType::resolve(&#resolve_obj, &ctx_obj, ctx.item).await;
@@ -260,6 +306,125 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         }
     };
 
+    if !interface_args.concretes.is_empty() {
+        let concrete_impls = interface_args.concretes.iter().map(|concrete| {
+            let gql_typename = &concrete.name;
+            let params = &concrete.params;
+            let concrete_ty: Type = parse_quote!(#ident<#(#params),*>);
+            quote! {
+                impl #crate_name::Type for #concrete_ty {
+                    fn type_name() -> std::borrow::Cow<'static, str> {
+                        std::borrow::Cow::Borrowed(#gql_typename)
+                    }
+
+                    fn introspection_type_name(&self) -> std::borrow::Cow<'static, str> {
+                        self.__internal_introspection_type_name()
+                    }
+
+                    fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                        Self::__internal_create_type_info(registry, #gql_typename)
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::ObjectType for #concrete_ty {
+                    async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        self.__internal_resolve_field(ctx).await
+                    }
+
+                    fn collect_inline_fields<'a>(
+                        &'a self,
+                        name: &str,
+                        ctx: &#crate_name::ContextSelectionSet<'a>,
+                        futures: &mut Vec<#crate_name::BoxFieldFuture<'a>>,
+                    ) -> #crate_name::Result<()> {
+                        self.__internal_collect_inline_fields(name, ctx, futures)
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::OutputValueType for #concrete_ty {
+                    async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::query::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        #crate_name::do_resolve(ctx, self).await
+                    }
+                }
+            }
+        });
+
+        let expanded = quote! {
+            #(#type_into_impls)*
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#methods)*
+
+                #[doc(hidden)]
+                fn __internal_introspection_type_name(&self) -> std::borrow::Cow<'static, str>
+                where
+                    #(#variant_types: #crate_name::Type),*
+                {
+                    #introspection_type_name
+                }
+
+                #[doc(hidden)]
+                fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> String
+                where
+                    Self: #crate_name::Type,
+                    #(#variant_types: #crate_name::Type),*
+                {
+                    registry.create_type::<Self, _>(|registry| {
+                        #(#registry_types)*
+
+                        #crate_name::registry::MetaType::Interface {
+                            name: name.to_string(),
+                            description: #desc,
+                            fields: {
+                                let mut fields = #crate_name::indexmap::IndexMap::new();
+                                #(#schema_fields)*
+                                fields
+                            },
+                            possible_types: {
+                                let mut possible_types = #crate_name::indexmap::IndexSet::new();
+                                #(#possible_types)*
+                                possible_types
+                            },
+                            extends: #extends,
+                            keys: None,
+                        }
+                    })
+                }
+
+                #[doc(hidden)]
+                async fn __internal_resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value>
+                where
+                    Self: #crate_name::Type,
+                {
+                    #(#resolvers)*
+                    Err(#crate_name::QueryError::FieldNotFound {
+                        field_name: ctx.name.to_string(),
+                        object: <Self as #crate_name::Type>::type_name().to_string(),
+                    }.into_error(ctx.position()))
+                }
+
+                #[doc(hidden)]
+                fn __internal_collect_inline_fields<'a>(
+                    &'a self,
+                    name: &str,
+                    ctx: &#crate_name::ContextSelectionSet<'a>,
+                    futures: &mut Vec<#crate_name::BoxFieldFuture<'a>>,
+                ) -> #crate_name::Result<()>
+                where
+                    #(#variant_types: #crate_name::ObjectType + Send + Sync),*
+                {
+                    #(#collect_inline_fields)*
+                    Ok(())
+                }
+            }
+
+            #(#concrete_impls)*
+        };
+        return Ok(expanded.into());
+    }
+
     let expanded = quote! {
         #(#type_into_impls)*
 
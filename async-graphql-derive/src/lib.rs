@@ -4,10 +4,15 @@
 extern crate proc_macro;
 
 mod args;
+mod description;
 mod r#enum;
 mod input_object;
 mod interface;
+mod merged_object;
+mod merged_subscription;
+mod newtype;
 mod object;
+mod oneof_object;
 mod output_type;
 mod scalar;
 mod simple_object;
@@ -19,7 +24,7 @@ use crate::utils::{add_container_attrs, get_crate_name, parse_derive};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse_macro_input;
-use syn::{AttributeArgs, ItemImpl};
+use syn::{AttributeArgs, DeriveInput, ItemImpl};
 
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
@@ -29,7 +34,21 @@ pub fn Object(args: TokenStream, input: TokenStream) -> TokenStream {
         Err(err) => return err.to_compile_error().into(),
     };
     let mut item_impl = parse_macro_input!(input as ItemImpl);
-    match object::generate(&object_args, &mut item_impl) {
+    match object::generate(&object_args, &mut item_impl, false) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ComplexObject(args: TokenStream, input: TokenStream) -> TokenStream {
+    let object_args = match args::Object::parse(parse_macro_input!(args as AttributeArgs)) {
+        Ok(object_args) => object_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let mut item_impl = parse_macro_input!(input as ItemImpl);
+    match object::generate(&object_args, &mut item_impl, true) {
         Ok(expanded) => expanded,
         Err(err) => err.to_compile_error().into(),
     }
@@ -63,6 +82,62 @@ pub fn derive_simple_object(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn MergedObject(args: TokenStream, input: TokenStream) -> TokenStream {
+    add_container_attrs(
+        quote!(GQLMergedObject),
+        parse_macro_input!(args as AttributeArgs),
+        input.into(),
+    )
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
+
+#[proc_macro_derive(GQLMergedObject, attributes(graphql))]
+pub fn derive_merged_object(input: TokenStream) -> TokenStream {
+    let (args, input) = match parse_derive(input.into()) {
+        Ok(r) => r,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let object_args = match args::Object::parse(parse_macro_input!(args as AttributeArgs)) {
+        Ok(object_args) => object_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    match merged_object::generate(&object_args, &input) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn MergedSubscription(args: TokenStream, input: TokenStream) -> TokenStream {
+    add_container_attrs(
+        quote!(GQLMergedSubscription),
+        parse_macro_input!(args as AttributeArgs),
+        input.into(),
+    )
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
+
+#[proc_macro_derive(GQLMergedSubscription, attributes(graphql))]
+pub fn derive_merged_subscription(input: TokenStream) -> TokenStream {
+    let (args, input) = match parse_derive(input.into()) {
+        Ok(r) => r,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let object_args = match args::Object::parse(parse_macro_input!(args as AttributeArgs)) {
+        Ok(object_args) => object_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    match merged_subscription::generate(&object_args, &input) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn Enum(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -119,6 +194,34 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn OneofObject(args: TokenStream, input: TokenStream) -> TokenStream {
+    add_container_attrs(
+        quote!(GQLOneofObject),
+        parse_macro_input!(args as AttributeArgs),
+        input.into(),
+    )
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
+
+#[proc_macro_derive(GQLOneofObject, attributes(field, graphql))]
+pub fn derive_oneof_object(input: TokenStream) -> TokenStream {
+    let (args, input) = match parse_derive(input.into()) {
+        Ok(r) => r,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let object_args = match args::InputObject::parse(parse_macro_input!(args as AttributeArgs)) {
+        Ok(object_args) => object_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    match oneof_object::generate(&object_args, &input) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn Interface(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -206,6 +309,32 @@ pub fn DataSource(args: TokenStream, input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+#[proc_macro_derive(NewType, attributes(graphql))]
+pub fn derive_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let newtype_args = match args::NewType::parse(&input.attrs) {
+        Ok(newtype_args) => newtype_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    match newtype::generate(&newtype_args, &input) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Description, attributes(graphql))]
+pub fn derive_description(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let description_args = match args::Description::parse(&input.attrs) {
+        Ok(description_args) => description_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    match description::generate(&description_args, &input) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn Scalar(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -0,0 +1,95 @@
+use crate::args;
+use crate::utils::{get_crate_name, get_rustdoc};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Index, Member, Result};
+
+pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<TokenStream> {
+    let crate_name = get_crate_name(object_args.internal);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let gql_typename = object_args
+        .name
+        .clone()
+        .unwrap_or_else(|| ident.to_string());
+
+    let desc = object_args
+        .desc
+        .clone()
+        .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
+        .map(|s| quote! { Some(#s.to_string()) })
+        .unwrap_or_else(|| quote! {None});
+
+    let s = match &input.data {
+        Data::Struct(e) => e,
+        _ => return Err(Error::new_spanned(input, "It should be a struct")),
+    };
+
+    let mut types = Vec::new();
+    let mut members = Vec::new();
+    for (idx, field) in s.fields.iter().enumerate() {
+        types.push(&field.ty);
+        members.push(match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(idx)),
+        });
+    }
+    if types.is_empty() {
+        return Err(Error::new_spanned(
+            input,
+            "A merged subscription must have at least one field.",
+        ));
+    }
+
+    let expanded = quote! {
+        impl #impl_generics #crate_name::Type for #ident #ty_generics #where_clause {
+            fn type_name() -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed(#gql_typename)
+            }
+
+            fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                registry.create_type::<Self, _>(|registry| {
+                    let mut fields = #crate_name::indexmap::IndexMap::new();
+                    let mut cache_control = Default::default();
+                    #(registry.merge_object_fields::<#types>(#gql_typename, &mut fields, &mut cache_control);)*
+                    #crate_name::registry::MetaType::Object {
+                        name: #gql_typename.to_string(),
+                        description: #desc,
+                        fields,
+                        cache_control: Default::default(),
+                        extends: false,
+                        keys: None,
+                        visible: None,
+                        directives: Default::default(),
+                    }
+                })
+            }
+        }
+
+        #[#crate_name::async_trait::async_trait]
+        impl #impl_generics #crate_name::SubscriptionType for #ident #ty_generics #where_clause {
+            async fn create_field_stream(
+                &self,
+                idx: usize,
+                ctx: &#crate_name::Context<'_>,
+                schema_env: #crate_name::SchemaEnv,
+                query_env: #crate_name::QueryEnv,
+            ) -> #crate_name::Result<std::pin::Pin<Box<dyn #crate_name::futures::Stream<Item = #crate_name::Result<#crate_name::serde_json::Value>> + Send>>>
+            where
+                Self: Send + Sync + 'static + Sized,
+            {
+                #(
+                    match #crate_name::SubscriptionType::create_field_stream(&self.#members, idx, ctx, schema_env.clone(), query_env.clone()).await {
+                        Err(#crate_name::Error::Query { err: #crate_name::QueryError::FieldNotFound { .. }, .. }) => {}
+                        res => return res,
+                    }
+                )*
+                Err(#crate_name::QueryError::FieldNotFound {
+                    field_name: ctx.name.to_string(),
+                    object: #gql_typename.to_string(),
+                }.into_error(ctx.position()))
+            }
+        }
+    };
+    Ok(expanded.into())
+}
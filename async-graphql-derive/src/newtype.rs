@@ -0,0 +1,116 @@
+use crate::args;
+use crate::utils::{get_crate_name, get_rustdoc};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn generate(newtype_args: &args::NewType, input: &DeriveInput) -> Result<TokenStream> {
+    let crate_name = get_crate_name(newtype_args.internal);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let inner_ty = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(Error::new_spanned(
+                    input,
+                    "NewType can only be derived for a tuple struct with one field.",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "NewType can only be derived for a tuple struct with one field.",
+            ))
+        }
+    };
+
+    let validate = match &newtype_args.validator {
+        Some(validator) => quote! {
+            if let Err(err) = #validator(&value) {
+                return Err(#crate_name::InputValueError::Custom(err.to_string()));
+            }
+        },
+        None => quote! {},
+    };
+
+    let type_impl = match &newtype_args.name {
+        Some(name) => {
+            let desc = newtype_args
+                .desc
+                .clone()
+                .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
+                .map(|s| quote! { Some(#s) })
+                .unwrap_or_else(|| quote! {None});
+            quote! {
+                impl #impl_generics #crate_name::Type for #ident #ty_generics #where_clause {
+                    fn type_name() -> std::borrow::Cow<'static, str> {
+                        std::borrow::Cow::Borrowed(#name)
+                    }
+
+                    fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                        registry.create_type::<Self, _>(|_| #crate_name::registry::MetaType::Scalar {
+                            name: #name.to_string(),
+                            description: #desc,
+                            is_valid: |value| <#ident #ty_generics as #crate_name::ScalarType>::is_valid(value),
+                        })
+                    }
+                }
+            }
+        }
+        None => quote! {
+            impl #impl_generics #crate_name::Type for #ident #ty_generics #where_clause {
+                fn type_name() -> std::borrow::Cow<'static, str> {
+                    <#inner_ty as #crate_name::Type>::type_name()
+                }
+
+                fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                    <#inner_ty as #crate_name::Type>::create_type_info(registry)
+                }
+            }
+        },
+    };
+
+    let expanded = quote! {
+        impl #impl_generics #crate_name::ScalarType for #ident #ty_generics #where_clause {
+            fn parse(value: #crate_name::Value) -> #crate_name::InputValueResult<Self> {
+                let value = <#inner_ty as #crate_name::ScalarType>::parse(value)?;
+                #validate
+                Ok(#ident(value))
+            }
+
+            fn is_valid(value: &#crate_name::Value) -> bool {
+                <#inner_ty as #crate_name::ScalarType>::is_valid(value)
+            }
+
+            fn to_value(&self) -> #crate_name::Value {
+                <#inner_ty as #crate_name::ScalarType>::to_value(&self.0)
+            }
+        }
+
+        #type_impl
+
+        impl #impl_generics #crate_name::InputValueType for #ident #ty_generics #where_clause {
+            fn parse(value: Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
+                <Self as #crate_name::ScalarType>::parse(value.unwrap_or_default())
+            }
+
+            fn to_value(&self) -> #crate_name::Value {
+                <Self as #crate_name::ScalarType>::to_value(self)
+            }
+        }
+
+        #[#crate_name::async_trait::async_trait]
+        impl #impl_generics #crate_name::OutputValueType for #ident #ty_generics #where_clause {
+            async fn resolve(
+                &self,
+                _: &#crate_name::ContextSelectionSet<'_>,
+                _field: &#crate_name::Positioned<#crate_name::parser::query::Field>
+            ) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                Ok(#crate_name::ScalarType::to_value(self).into())
+            }
+        }
+    };
+    Ok(expanded.into())
+}
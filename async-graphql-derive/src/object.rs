@@ -1,12 +1,21 @@
 use crate::args;
 use crate::output_type::OutputType;
-use crate::utils::{feature_block, get_crate_name, get_param_getter_ident, get_rustdoc};
-use inflector::Inflector;
+use crate::utils::{
+    applied_directives, feature_block, get_crate_name, get_param_getter_ident, has_lifetime,
+    visible_fn,
+};
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Block, Error, FnArg, ImplItem, ItemImpl, Pat, Result, ReturnType, Type, TypeReference};
-
-pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<TokenStream> {
+use syn::{
+    parse_quote, Block, Error, FnArg, ImplItem, ItemImpl, Pat, PathArguments, Result, ReturnType,
+    Type, TypeReference,
+};
+
+pub fn generate(
+    object_args: &args::Object,
+    item_impl: &mut ItemImpl,
+    complex: bool,
+) -> Result<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
     let (self_ty, self_name) = match item_impl.self_ty.as_ref() {
         Type::Path(path) => (
@@ -22,18 +31,24 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
     let generics = &item_impl.generics;
     let where_clause = &item_impl.generics.where_clause;
     let extends = object_args.extends;
+    let visible = visible_fn(&crate_name, &object_args.visible);
+    let directives = applied_directives(&crate_name, &object_args.directives)?;
 
     let gql_typename = object_args
         .name
         .clone()
         .unwrap_or_else(|| self_name.clone());
 
-    let desc = object_args
-        .desc
-        .clone()
-        .or_else(|| get_rustdoc(&item_impl.attrs).ok().flatten())
-        .map(|s| quote! { Some(#s) })
-        .unwrap_or_else(|| quote! {None});
+    let desc = if object_args.use_type_description {
+        quote! { Some(<Self as #crate_name::Description>::description().to_string()) }
+    } else {
+        object_args
+            .desc
+            .clone()
+            .or_else(|| object_args.doc.rustdoc(&item_impl.attrs).ok().flatten())
+            .map(|s| quote! { Some(#s.to_string()) })
+            .unwrap_or_else(|| quote! {None})
+    };
 
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
@@ -115,9 +130,9 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 let mut keys_str = String::new();
 
                 for (ident, ty, args::Argument { name, .. }) in &args {
-                    let name = name
-                        .clone()
-                        .unwrap_or_else(|| ident.ident.to_string().to_camel_case());
+                    let name = name.clone().unwrap_or_else(|| {
+                        object_args.rename_args.rename(&ident.ident.to_string())
+                    });
 
                     if !keys_str.is_empty() {
                         keys_str.push(' ');
@@ -179,19 +194,28 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         .map(|(idx, _)| idx)
                         .unwrap(),
                 );
-            } else if let Some(field) = args::Field::parse(&crate_name, &method.attrs)? {
+            } else if let Some(field) =
+                args::Field::parse(&crate_name, &method.attrs, &object_args.doc)?
+            {
                 if method.sig.asyncness.is_none() {
                     return Err(Error::new_spanned(&method, "Must be asynchronous"));
                 }
+                if field.flatten {
+                    return Err(Error::new_spanned(
+                        &method,
+                        "Only fields of SimpleObject can be flattened",
+                    ));
+                }
 
-                let field_name = field
-                    .name
-                    .clone()
-                    .unwrap_or_else(|| method.sig.ident.to_string().to_camel_case());
+                let field_name = field.name.clone().unwrap_or_else(|| {
+                    object_args
+                        .rename_fields
+                        .rename(&method.sig.ident.to_string())
+                });
                 let field_desc = field
                     .desc
                     .as_ref()
-                    .map(|s| quote! {Some(#s)})
+                    .map(|s| quote! {Some(#s.to_string())})
                     .unwrap_or_else(|| quote! {None});
                 let field_deprecation = field
                     .deprecation
@@ -208,6 +232,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     Some(provides) => quote! { Some(#provides) },
                     None => quote! { None },
                 };
+                let field_visible = visible_fn(&crate_name, &field.visible);
+                let field_directives = applied_directives(&crate_name, &field.directives)?;
                 let ty = match &method.sig.output {
                     ReturnType::Type(_, ty) => OutputType::parse(ty)?,
                     ReturnType::Default => {
@@ -290,15 +316,16 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         desc,
                         default,
                         validator,
+                        secret,
                     },
                 ) in args
                 {
-                    let name = name
-                        .clone()
-                        .unwrap_or_else(|| ident.ident.to_string().to_camel_case());
+                    let name = name.clone().unwrap_or_else(|| {
+                        object_args.rename_args.rename(&ident.ident.to_string())
+                    });
                     let desc = desc
                         .as_ref()
-                        .map(|s| quote! {Some(#s)})
+                        .map(|s| quote! {Some(#s.to_string())})
                         .unwrap_or_else(|| quote! {None});
                     let schema_default = default
                         .as_ref()
@@ -308,12 +335,15 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         .unwrap_or_else(|| quote! {None});
 
                     schema_args.push(quote! {
-                        args.insert(#name, #crate_name::registry::MetaInputValue {
-                            name: #name,
+                        args.insert(#name.to_string(), #crate_name::registry::MetaInputValue {
+                            name: #name.to_string(),
                             description: #desc,
-                            ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                            ty: <#ty as #crate_name::InputValueType>::create_input_type_info(registry),
                             default_value: #schema_default,
                             validator: #validator,
+                            visible: None,
+                            directives: Default::default(),
+                            is_secret: #secret,
                         });
                     });
 
@@ -347,6 +377,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         external: #external,
                         provides: #provides,
                         requires: #requires,
+                        visible: #field_visible,
+                        directives: #field_directives,
                     });
                 });
 
@@ -438,6 +470,146 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
     find_entities.sort_by(|(a, _), (b, _)| b.cmp(a));
     let find_entities_iter = find_entities.iter().map(|(_, code)| code);
 
+    if complex {
+        if !find_entities.is_empty() {
+            return Err(Error::new_spanned(
+                &item_impl.self_ty,
+                "Entities are not supported in ComplexObject",
+            ));
+        }
+        let expanded = quote! {
+            #item_impl
+
+            #[#crate_name::async_trait::async_trait]
+            impl #generics #crate_name::ComplexObject for #self_ty #where_clause {
+                fn fields(registry: &mut #crate_name::registry::Registry, fields: &mut #crate_name::indexmap::IndexMap<String, #crate_name::registry::MetaField>) {
+                    #(#schema_fields)*
+                }
+
+                async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                    #(#resolvers)*
+                    Err(#crate_name::QueryError::FieldNotFound {
+                        field_name: ctx.name.to_string(),
+                        object: #gql_typename.to_string(),
+                    }.into_error(ctx.position()))
+                }
+            }
+        };
+        return Ok(expanded.into());
+    }
+
+    if !object_args.concretes.is_empty() {
+        let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+        let concrete_impls = object_args.concretes.iter().map(|concrete| {
+            let gql_typename = &concrete.name;
+            let params = &concrete.params;
+            let mut concrete_ty = self_ty.clone();
+            if let Some(segment) = concrete_ty.path.segments.last_mut() {
+                segment.arguments = PathArguments::AngleBracketed(parse_quote!(<#(#params),*>));
+            }
+            quote! {
+                impl #crate_name::Type for #concrete_ty {
+                    fn type_name() -> std::borrow::Cow<'static, str> {
+                        std::borrow::Cow::Borrowed(#gql_typename)
+                    }
+
+                    fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                        Self::__internal_create_type_info(registry, #gql_typename)
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::ObjectType for #concrete_ty {
+                    async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        self.__internal_resolve_field(ctx).await
+                    }
+
+                    async fn find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        self.__internal_find_entity(ctx, params).await
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::OutputValueType for #concrete_ty {
+                    async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::query::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        #crate_name::do_resolve(ctx, self).await
+                    }
+                }
+            }
+        });
+
+        let expanded = quote! {
+            #item_impl
+
+            impl #impl_generics #self_ty #where_clause {
+                #[doc(hidden)]
+                fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> String
+                where
+                    Self: #crate_name::Type,
+                {
+                    let ty = registry.create_type::<Self, _>(|registry| #crate_name::registry::MetaType::Object {
+                        name: name.to_string(),
+                        description: #desc,
+                        fields: {
+                            let mut fields = #crate_name::indexmap::IndexMap::new();
+                            #(#schema_fields)*
+                            fields
+                        },
+                        cache_control: #cache_control,
+                        extends: #extends,
+                        keys: None,
+                        visible: #visible,
+                        directives: #directives,
+                    });
+                    #(#create_entity_types)*
+                    #(#add_keys)*
+                    ty
+                }
+
+                #[doc(hidden)]
+                async fn __internal_resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value>
+                where
+                    Self: #crate_name::Type,
+                {
+                    #(#resolvers)*
+                    Err(#crate_name::QueryError::FieldNotFound {
+                        field_name: ctx.name.to_string(),
+                        object: <Self as #crate_name::Type>::type_name().to_string(),
+                    }.into_error(ctx.position()))
+                }
+
+                #[doc(hidden)]
+                #[allow(unused_variables)]
+                async fn __internal_find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                    let params = match params {
+                        #crate_name::Value::Object(params) => params,
+                        _ => return Err(#crate_name::QueryError::EntityNotFound.into_error(ctx.position())),
+                    };
+                    let typename = if let Some(#crate_name::Value::String(typename)) = params.get("__typename") {
+                        typename
+                    } else {
+                        return Err(#crate_name::QueryError::TypeNameNotExists.into_error(ctx.position()));
+                    };
+                    #(#find_entities_iter)*
+                    Err(#crate_name::QueryError::EntityNotFound.into_error(ctx.position()))
+                }
+            }
+
+            #(#concrete_impls)*
+        };
+        return Ok(expanded.into());
+    }
+
+    let as_any = if generics.params.is_empty() && !has_lifetime(&item_impl.self_ty) {
+        quote! {
+            fn as_any(&self) -> Option<&(dyn std::any::Any + Sync)> {
+                Some(self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         #item_impl
 
@@ -458,6 +630,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     cache_control: #cache_control,
                     extends: #extends,
                     keys: None,
+                    visible: #visible,
+                    directives: #directives,
                 });
                 #(#create_entity_types)*
                 #(#add_keys)*
@@ -489,6 +663,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 #(#find_entities_iter)*
                 Err(#crate_name::QueryError::EntityNotFound.into_error(ctx.position()))
             }
+
+            #as_any
         }
 
         #[#crate_name::async_trait::async_trait]
@@ -0,0 +1,139 @@
+use crate::args;
+use crate::utils::{applied_directives, get_crate_name, visible_fn};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<TokenStream> {
+    let crate_name = get_crate_name(object_args.internal);
+    let ident = &input.ident;
+    let e = match &input.data {
+        Data::Enum(e) => e,
+        _ => return Err(Error::new_spanned(input, "It should be a enum.")),
+    };
+
+    let gql_typename = object_args
+        .name
+        .clone()
+        .unwrap_or_else(|| ident.to_string());
+
+    let desc = object_args
+        .desc
+        .clone()
+        .or_else(|| object_args.doc.rustdoc(&input.attrs).ok().flatten())
+        .map(|s| quote! { Some(#s) })
+        .unwrap_or_else(|| quote! {None});
+    let visible = visible_fn(&crate_name, &object_args.visible);
+    let directives = applied_directives(&crate_name, &object_args.directives)?;
+
+    let mut get_fields = Vec::new();
+    let mut put_fields = Vec::new();
+    let mut schema_fields = Vec::new();
+
+    for variant in &e.variants {
+        let ty = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "All variants of a oneOf input object must have exactly one unnamed field.",
+                ))
+            }
+        };
+        let field_args = args::InputField::parse(&crate_name, &variant.attrs, &object_args.doc)?;
+        if field_args.default.is_some() {
+            return Err(Error::new_spanned(
+                variant,
+                "Fields of a oneOf input object can't have a default value.",
+            ));
+        }
+        let variant_ident = &variant.ident;
+        let validator = &field_args.validator;
+        let name = field_args
+            .name
+            .clone()
+            .unwrap_or_else(|| object_args.rename_fields.rename(&variant_ident.to_string()));
+        let desc = field_args
+            .desc
+            .as_ref()
+            .map(|s| quote! {Some(#s.to_string())})
+            .unwrap_or_else(|| quote! {None});
+        let field_visible = visible_fn(&crate_name, &field_args.visible);
+        let field_directives = applied_directives(&crate_name, &field_args.directives)?;
+
+        get_fields.push(quote! {
+            #name => Ok(Self::#variant_ident(#crate_name::InputValueType::parse(Some(value))?)),
+        });
+
+        put_fields.push(quote! {
+            Self::#variant_ident(value) => {
+                map.insert(#name.to_string(), #crate_name::InputValueType::to_value(value));
+            }
+        });
+
+        schema_fields.push(quote! {
+            fields.insert(#name.to_string(), #crate_name::registry::MetaInputValue {
+                name: #name.to_string(),
+                description: #desc,
+                ty: <#ty as #crate_name::InputValueType>::create_input_type_info(registry).trim_end_matches('!').to_string(),
+                default_value: None,
+                validator: #validator,
+                visible: #field_visible,
+                directives: #field_directives,
+                is_secret: false,
+            });
+        })
+    }
+
+    let expanded = quote! {
+        impl #crate_name::Type for #ident {
+            fn type_name() -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed(#gql_typename)
+            }
+
+            fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                registry.create_type::<Self, _>(|registry| #crate_name::registry::MetaType::InputObject {
+                    name: #gql_typename.to_string(),
+                    description: #desc,
+                    input_fields: {
+                        let mut fields = #crate_name::indexmap::IndexMap::new();
+                        #(#schema_fields)*
+                        fields
+                    },
+                    visible: #visible,
+                    directives: #directives,
+                    oneof: true,
+                })
+            }
+        }
+
+        impl #crate_name::InputValueType for #ident {
+            fn parse(value: Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
+                match value {
+                    Some(#crate_name::Value::Object(obj)) if obj.len() == 1 => {
+                        let (name, value) = obj.into_iter().next().unwrap();
+                        match name.as_str() {
+                            #(#get_fields)*
+                            _ => Err(#crate_name::InputValueError::Custom(format!("Unknown field \"{}\".", name))),
+                        }
+                    }
+                    Some(#crate_name::Value::Object(_)) => {
+                        Err(#crate_name::InputValueError::Custom("Exactly one field must be provided.".to_string()))
+                    }
+                    value => Err(#crate_name::InputValueError::ExpectedType(value.unwrap_or_default())),
+                }
+            }
+
+            fn to_value(&self) -> #crate_name::Value {
+                let mut map = std::collections::BTreeMap::new();
+                match self {
+                    #(#put_fields)*
+                }
+                #crate_name::Value::Object(map)
+            }
+        }
+
+        impl #crate_name::InputObjectType for #ident {}
+    };
+    Ok(expanded.into())
+}
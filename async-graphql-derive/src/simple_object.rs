@@ -1,16 +1,17 @@
 use crate::args;
-use crate::utils::{feature_block, get_crate_name, get_rustdoc};
-use inflector::Inflector;
+use crate::input_object;
+use crate::utils::{applied_directives, feature_block, get_crate_name, visible_fn};
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Error, Fields, Result};
+use syn::{Data, DeriveInput, Error, Fields, Result, Type};
 
 pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
     let ident = &input.ident;
-    let generics = &input.generics;
-    let where_clause = &generics.where_clause;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let extends = object_args.extends;
+    let visible = visible_fn(&crate_name, &object_args.visible);
+    let directives = applied_directives(&crate_name, &object_args.directives)?;
     let gql_typename = object_args
         .name
         .clone()
@@ -19,14 +20,46 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
     let desc = object_args
         .desc
         .clone()
-        .or_else(|| get_rustdoc(&input.attrs).ok().flatten())
-        .map(|s| quote! { Some(#s) })
+        .or_else(|| object_args.doc.rustdoc(&input.attrs).ok().flatten())
+        .map(|s| quote! { Some(#s.to_string()) })
         .unwrap_or_else(|| quote! {None});
 
     let s = match &input.data {
         Data::Struct(e) => e,
         _ => return Err(Error::new_spanned(input, "It should be a struct")),
     };
+
+    let input_object = match &object_args.input_name {
+        Some(_) if !input.generics.params.is_empty() => {
+            return Err(Error::new_spanned(
+                &input.generics,
+                "The 'input_name' attribute is not supported on generic objects.",
+            ))
+        }
+        Some(input_name) => input_object::generate_for_output(
+            &args::InputObject {
+                internal: object_args.internal,
+                name: Some(input_name.clone()),
+                desc: object_args.desc.clone(),
+                rename_fields: object_args.rename_fields,
+                visible: None,
+                directives: Vec::new(),
+                doc: object_args.doc.clone(),
+            },
+            input,
+        )?,
+        None => quote! {},
+    };
+    let remote_impl = match &object_args.remote {
+        Some(_) if !input.generics.params.is_empty() => {
+            return Err(Error::new_spanned(
+                &input.generics,
+                "The 'remote' attribute is not supported on generic objects.",
+            ))
+        }
+        Some(remote) => generate_remote(&crate_name, object_args, remote, input, &s.fields)?,
+        None => quote! {},
+    };
     let mut getters = Vec::new();
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
@@ -38,15 +71,31 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
 
     if let Some(fields) = fields {
         for item in &fields.named {
-            if let Some(field) = args::Field::parse(&crate_name, &item.attrs)? {
-                let field_name = field
-                    .name
-                    .clone()
-                    .unwrap_or_else(|| item.ident.as_ref().unwrap().to_string().to_camel_case());
+            if let Some(field) = args::Field::parse(&crate_name, &item.attrs, &object_args.doc)? {
+                if field.flatten {
+                    let ident = &item.ident;
+                    let ty = &item.ty;
+                    schema_fields.push(quote! {
+                        registry.merge_object_fields::<#ty>(&<Self as #crate_name::Type>::type_name(), &mut fields, &mut cache_control);
+                    });
+                    resolvers.push(quote! {
+                        match #crate_name::ObjectType::resolve_field(&self.#ident, ctx).await {
+                            Err(#crate_name::Error::Query { err: #crate_name::QueryError::FieldNotFound { .. }, .. }) => {}
+                            res => return res,
+                        }
+                    });
+                    continue;
+                }
+
+                let field_name = field.name.clone().unwrap_or_else(|| {
+                    object_args
+                        .rename_fields
+                        .rename(&item.ident.as_ref().unwrap().to_string())
+                });
                 let field_desc = field
                     .desc
                     .as_ref()
-                    .map(|s| quote! {Some(#s)})
+                    .map(|s| quote! {Some(#s.to_string())})
                     .unwrap_or_else(|| quote! {None});
                 let field_deprecation = field
                     .deprecation
@@ -62,8 +111,25 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                     Some(provides) => quote! { Some(#provides) },
                     None => quote! { None },
                 };
+                let field_visible = visible_fn(&crate_name, &field.visible);
+                let field_directives = applied_directives(&crate_name, &field.directives)?;
                 let vis = &item.vis;
-                let ty = &item.ty;
+                let ty = match (&field.with, &field.ty) {
+                    (Some(_), Some(ty)) => ty,
+                    (None, None) => &item.ty,
+                    (Some(_), None) => {
+                        return Err(Error::new_spanned(
+                            item,
+                            "Attribute 'with' requires the attribute 'type'.",
+                        ))
+                    }
+                    (None, Some(_)) => {
+                        return Err(Error::new_spanned(
+                            item,
+                            "Attribute 'type' requires the attribute 'with'.",
+                        ))
+                    }
+                };
 
                 let cache_control = {
                     let public = field.cache_control.public;
@@ -87,6 +153,8 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                         external: #external,
                         provides: #provides,
                         requires: #requires,
+                        visible: #field_visible,
+                        directives: #field_directives,
                     });
                 });
 
@@ -99,7 +167,21 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                     .map(|guard| quote! { #guard.check(ctx, &res).await.map_err(|err| err.into_error_with_path(ctx.position(), ctx.path_node.as_ref().unwrap().to_json()))?; });
 
                 let features = &field.features;
-                getters.push(if field.is_ref {
+                getters.push(if let Some(with) = &field.with {
+                    let block = feature_block(
+                        &crate_name,
+                        features,
+                        &field_name,
+                        quote! { #with(&self.#ident).map_err(::std::convert::Into::into) },
+                    );
+                    quote! {
+                        #[inline]
+                        #[allow(missing_docs)]
+                        #vis async fn #ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::FieldResult<#ty> {
+                            #block
+                        }
+                    }
+                } else if field.is_ref.unwrap_or(object_args.by_ref) {
                     let block = feature_block(
                         &crate_name,
                         &features,
@@ -142,6 +224,18 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
         }
     }
 
+    if object_args.complex {
+        schema_fields.push(quote! {
+            <Self as #crate_name::ComplexObject>::fields(registry, &mut fields);
+        });
+        resolvers.push(quote! {
+            match #crate_name::ComplexObject::resolve_field(self, ctx).await {
+                Err(#crate_name::Error::Query { err: #crate_name::QueryError::FieldNotFound { .. }, .. }) => {}
+                res => return res,
+            }
+        });
+    }
+
     let cache_control = {
         let public = object_args.cache_control.public;
         let max_age = object_args.cache_control.max_age;
@@ -153,34 +247,124 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
         }
     };
 
+    if !object_args.concretes.is_empty() {
+        let concrete_impls = object_args.concretes.iter().map(|concrete| {
+            let gql_typename = &concrete.name;
+            let params = &concrete.params;
+            quote! {
+                impl #crate_name::Type for #ident<#(#params),*> {
+                    fn type_name() -> std::borrow::Cow<'static, str> {
+                        std::borrow::Cow::Borrowed(#gql_typename)
+                    }
+
+                    fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                        Self::__internal_create_type_info(registry, #gql_typename)
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::ObjectType for #ident<#(#params),*> {
+                    async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        self.__internal_resolve_field(ctx).await
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::OutputValueType for #ident<#(#params),*> {
+                    async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::query::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        #crate_name::do_resolve(ctx, self).await
+                    }
+                }
+            }
+        });
+
+        let expanded = quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#getters)*
+
+                #[doc(hidden)]
+                fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> String
+                where
+                    Self: #crate_name::Type,
+                {
+                    registry.create_type::<Self, _>(|registry| {
+                        let mut fields = #crate_name::indexmap::IndexMap::new();
+                        #[allow(unused_mut)]
+                    let mut cache_control = #cache_control;
+                        #(#schema_fields)*
+                        #crate_name::registry::MetaType::Object {
+                            name: name.to_string(),
+                            description: #desc,
+                            fields,
+                            cache_control,
+                            extends: #extends,
+                            keys: None,
+                            visible: #visible,
+                            directives: #directives,
+                        }
+                    })
+                }
+
+                #[doc(hidden)]
+                async fn __internal_resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value>
+                where
+                    Self: #crate_name::Type,
+                {
+                    #(#resolvers)*
+                    Err(#crate_name::QueryError::FieldNotFound {
+                        field_name: ctx.name.to_string(),
+                        object: <Self as #crate_name::Type>::type_name().to_string(),
+                    }.into_error(ctx.position()))
+                }
+            }
+
+            #(#concrete_impls)*
+        };
+        return Ok(expanded.into());
+    }
+
+    let as_any = if input.generics.params.is_empty() {
+        quote! {
+            fn as_any(&self) -> Option<&(dyn std::any::Any + Sync)> {
+                Some(self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
-        impl #generics #ident #where_clause {
+        impl #impl_generics #ident #ty_generics #where_clause {
             #(#getters)*
         }
 
-        impl #generics #crate_name::Type for #ident #generics #where_clause {
+        impl #impl_generics #crate_name::Type for #ident #ty_generics #where_clause {
             fn type_name() -> std::borrow::Cow<'static, str> {
                 std::borrow::Cow::Borrowed(#gql_typename)
             }
 
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
-                registry.create_type::<Self, _>(|registry| #crate_name::registry::MetaType::Object {
-                    name: #gql_typename.to_string(),
-                    description: #desc,
-                    fields: {
-                        let mut fields = #crate_name::indexmap::IndexMap::new();
-                        #(#schema_fields)*
-                        fields
-                    },
-                    cache_control: #cache_control,
-                    extends: #extends,
-                    keys: None,
+                registry.create_type::<Self, _>(|registry| {
+                    let mut fields = #crate_name::indexmap::IndexMap::new();
+                    #[allow(unused_mut)]
+                    let mut cache_control = #cache_control;
+                    #(#schema_fields)*
+                    #crate_name::registry::MetaType::Object {
+                        name: #gql_typename.to_string(),
+                        description: #desc,
+                        fields,
+                        cache_control,
+                        extends: #extends,
+                        keys: None,
+                        visible: #visible,
+                        directives: #directives,
+                    }
                 })
             }
         }
 
         #[#crate_name::async_trait::async_trait]
-        impl #generics #crate_name::ObjectType for #ident #generics #where_clause {
+        impl #impl_generics #crate_name::ObjectType for #ident #ty_generics #where_clause {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
                 #(#resolvers)*
                 Err(#crate_name::QueryError::FieldNotFound {
@@ -188,14 +372,71 @@ pub fn generate(object_args: &args::Object, input: &DeriveInput) -> Result<Token
                     object: #gql_typename.to_string(),
                 }.into_error(ctx.position()))
             }
+
+            #as_any
         }
 
         #[#crate_name::async_trait::async_trait]
-        impl #generics #crate_name::OutputValueType for #ident #generics #where_clause {
+        impl #impl_generics #crate_name::OutputValueType for #ident #ty_generics #where_clause {
             async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::query::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
                 #crate_name::do_resolve(ctx, self).await
             }
         }
+
+        #input_object
+        #remote_impl
     };
     Ok(expanded.into())
 }
+
+fn generate_remote(
+    crate_name: &proc_macro2::TokenStream,
+    object_args: &args::Object,
+    remote: &Type,
+    input: &DeriveInput,
+    fields: &Fields,
+) -> Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let fields = match fields {
+        Fields::Named(fields) => fields,
+        _ => {
+            return Ok(quote! {
+                impl ::std::convert::From<#remote> for #ident {
+                    fn from(_: #remote) -> Self {
+                        #ident
+                    }
+                }
+            })
+        }
+    };
+
+    // The getters borrow the remote value, so they are called before the other fields are moved out.
+    let mut getters = Vec::new();
+    let mut values = Vec::new();
+    for item in &fields.named {
+        let field_ident = item.ident.as_ref().unwrap();
+        match args::Field::parse(crate_name, &item.attrs, &object_args.doc)? {
+            Some(field) => match &field.getter {
+                Some(getter) => {
+                    getters.push(quote! { let #field_ident = #getter(&value); });
+                    values.push(quote! { #field_ident: ::std::convert::Into::into(#field_ident) });
+                }
+                None => values
+                    .push(quote! { #field_ident: ::std::convert::Into::into(value.#field_ident) }),
+            },
+            None => values.push(quote! { #field_ident: ::std::default::Default::default() }),
+        }
+    }
+
+    Ok(quote! {
+        impl ::std::convert::From<#remote> for #ident {
+            #[allow(unused_variables)]
+            fn from(value: #remote) -> Self {
+                #(#getters)*
+                Self {
+                    #(#values),*
+                }
+            }
+        }
+    })
+}
@@ -1,7 +1,8 @@
 use crate::args;
 use crate::output_type::OutputType;
-use crate::utils::{feature_block, get_crate_name, get_param_getter_ident, get_rustdoc};
-use inflector::Inflector;
+use crate::utils::{
+    applied_directives, feature_block, get_crate_name, get_param_getter_ident, visible_fn,
+};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
@@ -24,6 +25,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
     };
     let generics = &item_impl.generics;
     let where_clause = &generics.where_clause;
+    let visible = visible_fn(&crate_name, &object_args.visible);
+    let directives = applied_directives(&crate_name, &object_args.directives)?;
 
     let gql_typename = object_args
         .name
@@ -33,8 +36,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
     let desc = object_args
         .desc
         .clone()
-        .or_else(|| get_rustdoc(&item_impl.attrs).ok().flatten())
-        .map(|s| quote! { Some(#s) })
+        .or_else(|| object_args.doc.rustdoc(&item_impl.attrs).ok().flatten())
+        .map(|s| quote! { Some(#s.to_string()) })
         .unwrap_or_else(|| quote! {None});
 
     let mut create_stream = Vec::new();
@@ -42,16 +45,23 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
 
     for item in &mut item_impl.items {
         if let ImplItem::Method(method) = item {
-            if let Some(field) = args::Field::parse(&crate_name, &method.attrs)? {
+            if let Some(field) = args::Field::parse(&crate_name, &method.attrs, &object_args.doc)? {
+                if field.flatten {
+                    return Err(Error::new_spanned(
+                        &method,
+                        "Only fields of SimpleObject can be flattened",
+                    ));
+                }
                 let ident = &method.sig.ident;
-                let field_name = field
-                    .name
-                    .clone()
-                    .unwrap_or_else(|| method.sig.ident.to_string().to_camel_case());
+                let field_name = field.name.clone().unwrap_or_else(|| {
+                    object_args
+                        .rename_fields
+                        .rename(&method.sig.ident.to_string())
+                });
                 let field_desc = field
                     .desc
                     .as_ref()
-                    .map(|s| quote! {Some(#s)})
+                    .map(|s| quote! {Some(#s.to_string())})
                     .unwrap_or_else(|| quote! {None});
                 let field_deprecation = field
                     .deprecation
@@ -59,6 +69,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     .map(|s| quote! {Some(#s)})
                     .unwrap_or_else(|| quote! {None});
                 let features = field.features;
+                let field_visible = visible_fn(&crate_name, &field.visible);
+                let field_directives = applied_directives(&crate_name, &field.directives)?;
 
                 if method.sig.asyncness.is_none() {
                     return Err(Error::new_spanned(
@@ -143,15 +155,16 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         desc,
                         default,
                         validator,
+                        secret,
                     },
                 ) in args
                 {
-                    let name = name
-                        .clone()
-                        .unwrap_or_else(|| ident.ident.to_string().to_camel_case());
+                    let name = name.clone().unwrap_or_else(|| {
+                        object_args.rename_args.rename(&ident.ident.to_string())
+                    });
                     let desc = desc
                         .as_ref()
-                        .map(|s| quote! {Some(#s)})
+                        .map(|s| quote! {Some(#s.to_string())})
                         .unwrap_or_else(|| quote! {None});
                     let schema_default = default
                         .as_ref()
@@ -161,12 +174,15 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         .unwrap_or_else(|| quote! {None});
 
                     schema_args.push(quote! {
-                        args.insert(#name, #crate_name::registry::MetaInputValue {
-                            name: #name,
+                        args.insert(#name.to_string(), #crate_name::registry::MetaInputValue {
+                            name: #name.to_string(),
                             description: #desc,
-                            ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                            ty: <#ty as #crate_name::InputValueType>::create_input_type_info(registry),
                             default_value: #schema_default,
                             validator: #validator,
+                            visible: None,
+                            directives: Default::default(),
+                            is_secret: #secret,
                         });
                     });
 
@@ -227,6 +243,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         external: false,
                         requires: None,
                         provides: None,
+                        visible: #field_visible,
+                        directives: #field_directives,
                     });
                 });
 
@@ -266,6 +284,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                                 let field_name = field_name.clone();
                                 async move {
                                     let resolve_id = std::sync::atomic::AtomicUsize::default();
+                                    let errors = Default::default();
                                     let ctx_selection_set = query_env.create_context(
                                         &schema_env,
                                         Some(#crate_name::QueryPathNode {
@@ -275,8 +294,17 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                                         &field.selection_set,
                                         &resolve_id,
                                         None,
+                                        &errors,
                                     );
-                                    #crate_name::OutputValueType::resolve(&msg, &ctx_selection_set, &*field).await
+                                    let value = #crate_name::OutputValueType::resolve(&msg, &ctx_selection_set, &*field).await?;
+                                    // A subscription event has no errors besides its data, so the
+                                    // errors of the fields resolved with partial data fail the event.
+                                    let errors = errors.take();
+                                    if errors.is_empty() {
+                                        Ok(value)
+                                    } else {
+                                        Err(#crate_name::Error::Remote { errors })
+                                    }
                                 }
                             }
                         })
@@ -327,6 +355,8 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     cache_control: Default::default(),
                     extends: false,
                     keys: None,
+                    visible: #visible,
+                    directives: #directives,
                 })
             }
         }
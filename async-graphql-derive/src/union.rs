@@ -3,12 +3,13 @@ use crate::utils::{get_crate_name, get_rustdoc};
 use proc_macro::TokenStream;
 use quote::quote;
 use std::collections::HashSet;
-use syn::{Data, DeriveInput, Error, Fields, Result, Type};
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Result, Type};
 
 pub fn generate(union_args: &args::Interface, input: &DeriveInput) -> Result<TokenStream> {
     let crate_name = get_crate_name(union_args.internal);
     let ident = &input.ident;
     let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let s = match &input.data {
         Data::Enum(s) => s,
         _ => {
@@ -34,6 +35,7 @@ pub fn generate(union_args: &args::Interface, input: &DeriveInput) -> Result<Tok
     let mut possible_types = Vec::new();
     let mut collect_inline_fields = Vec::new();
     let mut get_introspection_typename = Vec::new();
+    let mut variant_types = Vec::new();
 
     for variant in s.variants.iter() {
         let enum_name = &variant.ident;
@@ -69,7 +71,7 @@ pub fn generate(union_args: &args::Interface, input: &DeriveInput) -> Result<Tok
 
             enum_names.push(enum_name);
             type_into_impls.push(quote! {
-                impl #generics From<#p> for #ident #generics {
+                impl #impl_generics From<#p> for #ident #ty_generics #where_clause {
                     fn from(obj: #p) -> Self {
                         #ident::#enum_name(obj)
                     }
@@ -88,12 +90,116 @@ pub fn generate(union_args: &args::Interface, input: &DeriveInput) -> Result<Tok
             });
             get_introspection_typename.push(quote! {
                 #ident::#enum_name(obj) => <#p as #crate_name::Type>::type_name()
-            })
+            });
+            variant_types.push(p);
         } else {
             return Err(Error::new_spanned(field, "Invalid type"));
         }
     }
 
+    if !union_args.concretes.is_empty() {
+        let concrete_impls = union_args.concretes.iter().map(|concrete| {
+            let gql_typename = &concrete.name;
+            let params = &concrete.params;
+            let concrete_ty: Type = parse_quote!(#ident<#(#params),*>);
+            quote! {
+                impl #crate_name::Type for #concrete_ty {
+                    fn type_name() -> std::borrow::Cow<'static, str> {
+                        std::borrow::Cow::Borrowed(#gql_typename)
+                    }
+
+                    fn introspection_type_name(&self) -> std::borrow::Cow<'static, str> {
+                        self.__internal_introspection_type_name()
+                    }
+
+                    fn create_type_info(registry: &mut #crate_name::registry::Registry) -> String {
+                        Self::__internal_create_type_info(registry, #gql_typename)
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::ObjectType for #concrete_ty {
+                    async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        Err(#crate_name::QueryError::FieldNotFound {
+                            field_name: ctx.name.to_string(),
+                            object: #gql_typename.to_string(),
+                        }.into_error(ctx.position()))
+                    }
+
+                    fn collect_inline_fields<'a>(
+                        &'a self,
+                        name: &str,
+                        ctx: &#crate_name::ContextSelectionSet<'a>,
+                        futures: &mut Vec<#crate_name::BoxFieldFuture<'a>>,
+                    ) -> #crate_name::Result<()> {
+                        self.__internal_collect_inline_fields(name, ctx, futures)
+                    }
+                }
+
+                #[#crate_name::async_trait::async_trait]
+                impl #crate_name::OutputValueType for #concrete_ty {
+                    async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::query::Field>) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                        #crate_name::do_resolve(ctx, self).await
+                    }
+                }
+            }
+        });
+
+        let expanded = quote! {
+            #(#type_into_impls)*
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc(hidden)]
+                fn __internal_introspection_type_name(&self) -> std::borrow::Cow<'static, str>
+                where
+                    #(#variant_types: #crate_name::Type),*
+                {
+                    match self {
+                        #(#get_introspection_typename),*
+                    }
+                }
+
+                #[doc(hidden)]
+                fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> String
+                where
+                    Self: #crate_name::Type,
+                    #(#variant_types: #crate_name::Type),*
+                {
+                    registry.create_type::<Self, _>(|registry| {
+                        #(#registry_types)*
+
+                        #crate_name::registry::MetaType::Union {
+                            name: name.to_string(),
+                            description: #desc,
+                            possible_types: {
+                                let mut possible_types = #crate_name::indexmap::IndexSet::new();
+                                #(#possible_types)*
+                                possible_types
+                            }
+                        }
+                    })
+                }
+
+                #[doc(hidden)]
+                fn __internal_collect_inline_fields<'a>(
+                    &'a self,
+                    name: &str,
+                    ctx: &#crate_name::ContextSelectionSet<'a>,
+                    futures: &mut Vec<#crate_name::BoxFieldFuture<'a>>,
+                ) -> #crate_name::Result<()>
+                where
+                    #(#variant_types: #crate_name::ObjectType + Send + Sync),*
+                {
+                    #(#collect_inline_fields)*
+                    Ok(())
+                }
+            }
+
+            #(#concrete_impls)*
+        };
+        return Ok(expanded.into());
+    }
+
     let expanded = quote! {
         #(#type_into_impls)*
 
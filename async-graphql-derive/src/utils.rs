@@ -1,3 +1,4 @@
+use crate::args;
 use itertools::Itertools;
 use proc_macro2::{Span, TokenStream};
 use proc_macro_crate::crate_name;
@@ -264,14 +265,8 @@ pub fn parse_default(lit: &Lit) -> Result<TokenStream> {
             let value = value.value();
             Ok(quote!({ #value.to_string() }))
         }
-        Lit::Int(value) => {
-            let value = value.base10_parse::<i32>()?;
-            Ok(quote!({ #value as i32 }))
-        }
-        Lit::Float(value) => {
-            let value = value.base10_parse::<f64>()?;
-            Ok(quote!({ #value as f64 }))
-        }
+        Lit::Int(value) => Ok(quote!({ #value })),
+        Lit::Float(value) => Ok(quote!({ #value })),
         Lit::Bool(value) => {
             let value = value.value;
             Ok(quote!({ #value }))
@@ -326,3 +321,59 @@ pub fn feature_block(
         block
     }
 }
+
+pub fn visible_fn(crate_name: &TokenStream, visible: &Option<syn::Path>) -> TokenStream {
+    match visible {
+        Some(path) => quote! { Some(#path as #crate_name::registry::MetaVisibleFn) },
+        None => quote! { None },
+    }
+}
+
+pub fn applied_directives(
+    crate_name: &TokenStream,
+    directives: &[args::AppliedDirective],
+) -> Result<TokenStream> {
+    let mut items = Vec::new();
+    for directive in directives {
+        let name = &directive.name;
+        let mut args = Vec::new();
+        for (arg_name, lit) in &directive.args {
+            let value =
+                match lit {
+                    Lit::Str(value) => quote! { #crate_name::Value::String(#value.to_string()) },
+                    Lit::Int(value) => quote! { #crate_name::Value::Int(#value) },
+                    Lit::Float(value) => quote! { #crate_name::Value::Float(#value) },
+                    Lit::Bool(value) => quote! { #crate_name::Value::Boolean(#value) },
+                    _ => return Err(Error::new_spanned(
+                        lit,
+                        "The directive argument type only be string, integer, float and boolean",
+                    )),
+                };
+            args.push(quote! { args.insert(#arg_name, #value); });
+        }
+        items.push(quote! {
+            #crate_name::registry::MetaAppliedDirective {
+                name: #name,
+                args: {
+                    #[allow(unused_mut)]
+                    let mut args = #crate_name::indexmap::IndexMap::new();
+                    #(#args)*
+                    args
+                },
+            }
+        });
+    }
+    Ok(quote! { vec![#(#items),*] })
+}
+
+/// Returns true if the type has a lifetime, e.g. `Foo<'_>`.
+pub fn has_lifetime(ty: &syn::Type) -> bool {
+    fn visit(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '\'',
+            proc_macro2::TokenTree::Group(group) => visit(group.stream()),
+            _ => false,
+        })
+    }
+    visit(quote! { #ty })
+}
@@ -1,10 +1,47 @@
 //! Async-graphql integration with AWS lambda
 //!
+//! The request body is read as decoded by `lambda_http`, so base64 encoded bodies from API Gateway,
+//! such as multipart uploads, are supported. `GET` requests are read from the query string.
+//!
+//! The response is built with the `http::Response` conversions of `async_graphql::http`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use async_graphql::http::GQLResponse;
+//! use async_graphql::*;
+//! use async_graphql_lambda::GQLRequestExt;
+//! use lambda_http::{handler, lambda, Body, Request, Response};
+//!
+//! struct QueryRoot;
+//!
+//! #[Object]
+//! impl QueryRoot {
+//!     #[field(desc = "Returns the sum of a and b")]
+//!     async fn add(&self, a: i32, b: i32) -> i32 {
+//!         a + b
+//!     }
+//! }
+//!
+//! async fn index(req: Request, _: lambda::Context) -> Result<Response<Body>, lambda::Error> {
+//!     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+//!     let builder = req.graphql().await?;
+//!     Ok(GQLResponse(builder.execute(&schema).await).into())
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), lambda::Error> {
+//!     lambda::run(handler(index)).await
+//! }
+//! ```
 #![forbid(unsafe_code)]
 
-use async_graphql::{IntoQueryBuilder, IntoQueryBuilderOpts, ParseRequestError, QueryBuilder};
-use futures::io::AllowStdIo;
-use lambda_http::Request;
+use async_graphql::{
+    BatchQueryBuilder, IntoBatchQueryBuilder, IntoQueryBuilder, IntoQueryBuilderOpts,
+    ParseRequestError, QueryBuilder,
+};
+use futures::stream;
+use lambda_http::{http, Request};
 use std::io::Cursor;
 
 /// Lambda request extension
@@ -21,6 +58,17 @@ pub trait GQLRequestExt {
         &self,
         opts: IntoQueryBuilderOpts,
     ) -> Result<QueryBuilder, ParseRequestError>;
+
+    /// Convert a query or a batch of queries to `async_graphql::BatchQueryBuilder`.
+    async fn graphql_batch(&self) -> Result<BatchQueryBuilder, ParseRequestError> {
+        self.graphql_batch_opts(Default::default()).await
+    }
+
+    /// Similar to graphql_batch, but you can set the options `IntoQueryBuilderOpts`.
+    async fn graphql_batch_opts(
+        &self,
+        opts: IntoQueryBuilderOpts,
+    ) -> Result<BatchQueryBuilder, ParseRequestError>;
 }
 
 #[async_trait::async_trait]
@@ -29,12 +77,28 @@ impl GQLRequestExt for Request {
         &self,
         opts: IntoQueryBuilderOpts,
     ) -> Result<QueryBuilder, ParseRequestError> {
-        let ct = self
-            .headers()
-            .get("content-type")
-            .and_then(|value| value.to_str().ok());
-        (ct, AllowStdIo::new(Cursor::new(self.body().to_vec())))
-            .into_query_builder_opts(&opts)
+        stream_request(self).into_query_builder_opts(&opts).await
+    }
+
+    async fn graphql_batch_opts(
+        &self,
+        opts: IntoQueryBuilderOpts,
+    ) -> Result<BatchQueryBuilder, ParseRequestError> {
+        stream_request(self)
+            .into_batch_query_builder_opts(&opts)
             .await
     }
 }
+
+type BodyStream = stream::Iter<std::vec::IntoIter<std::io::Result<Cursor<Vec<u8>>>>>;
+
+/// Copy the request with a body stream, to use the `http::Request` implementation of
+/// `IntoQueryBuilder`.
+fn stream_request(req: &Request) -> http::Request<BodyStream> {
+    let body = stream::iter(vec![Ok(Cursor::new(req.body().to_vec()))]);
+    let mut stream_req = http::Request::new(body);
+    *stream_req.method_mut() = req.method().clone();
+    *stream_req.uri_mut() = req.uri().clone();
+    *stream_req.headers_mut() = req.headers().clone();
+    stream_req
+}
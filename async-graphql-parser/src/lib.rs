@@ -19,4 +19,4 @@ pub use error::{Error, Result};
 pub use pos::{Pos, Positioned};
 pub use query_parser::parse_query;
 pub use schema_parser::parse_schema;
-pub use value::{UploadValue, Value};
+pub use value::{UploadContent, UploadValue, Value};
@@ -2,11 +2,30 @@ use std::collections::BTreeMap;
 use std::fmt;
 use std::fmt::Formatter;
 use std::fs::File;
+use std::sync::Arc;
 
 pub struct UploadValue {
     pub filename: String,
     pub content_type: Option<String>,
-    pub content: File,
+    pub content: UploadContent,
+}
+
+/// The content of an uploaded file
+pub enum UploadContent {
+    /// A small file that is kept in memory
+    Memory(Arc<[u8]>),
+
+    /// A file that is stored in a temporary file
+    File(File),
+}
+
+impl Clone for UploadContent {
+    fn clone(&self) -> Self {
+        match self {
+            UploadContent::Memory(data) => UploadContent::Memory(data.clone()),
+            UploadContent::File(file) => UploadContent::File(file.try_clone().unwrap()),
+        }
+    }
 }
 
 impl fmt::Debug for UploadValue {
@@ -20,7 +39,7 @@ impl Clone for UploadValue {
         Self {
             filename: self.filename.clone(),
             content_type: self.content_type.clone(),
-            content: self.content.try_clone().unwrap(),
+            content: self.content.clone(),
         }
     }
 }
@@ -5,19 +5,23 @@
 #![allow(clippy::needless_doctest_main)]
 #![forbid(unsafe_code)]
 
-use async_graphql::http::{multipart_stream, GQLRequest, GQLResponse, StreamBody};
+use async_graphql::http::{
+    multipart_stream, parse_query_string, playground_source, receive_request, GQLResponse,
+    GraphQLPlaygroundConfig, StreamBody,
+};
 use async_graphql::{
     IntoQueryBuilder, IntoQueryBuilderOpts, ObjectType, QueryBuilder, QueryResponse, Schema,
     StreamResponse, SubscriptionType,
 };
 use async_trait::async_trait;
 use futures::channel::mpsc;
+use futures::future::BoxFuture;
 use futures::io::BufReader;
-use futures::{SinkExt, StreamExt};
+use futures::{Future, SinkExt, StreamExt};
 use std::str::FromStr;
 use tide::{
     http::{headers, Method},
-    Body, Request, Response, Status, StatusCode,
+    Body, Endpoint, Request, Response, Status, StatusCode,
 };
 
 /// GraphQL request handler
@@ -90,6 +94,175 @@ where
     )
 }
 
+/// GraphQL request handler with an async configuration function
+///
+/// Similar to graphql, but `query_builder_configuration` is an async function, so you can do
+/// asynchronous work such as loading a session and attach the results to the query with
+/// `QueryBuilder::data`. It returns a `tide::Result<QueryBuilder>`. If it returns an error, the
+/// query is not executed and the error is returned as the HTTP response, e.g.
+/// `401 Unauthorized` for a request without a valid session.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_std::task;
+/// use tide::{Request, StatusCode};
+///
+/// struct QueryRoot;
+/// #[Object]
+/// impl QueryRoot {
+///     #[field(desc = "Returns the sum of a and b")]
+///     async fn add(&self, a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// fn main() -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+///     task::block_on(async {
+///         let mut app = tide::new();
+///         app.at("/").post(|req: Request<()>| async move {
+///             let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish();
+///             let authorized = req.header("token").is_some();
+///             async_graphql_tide::graphql_async(req, schema, |query_builder| async move {
+///                 if authorized {
+///                     Ok(query_builder)
+///                 } else {
+///                     Err(tide::Error::from_str(StatusCode::Unauthorized, "Unauthorized"))
+///                 }
+///             })
+///             .await
+///         });
+///         app.listen("0.0.0.0:8000").await?;
+///
+///         Ok(())
+///     })
+/// }
+/// ```
+pub async fn graphql_async<Query, Mutation, Subscription, TideState, F, Fut>(
+    req: Request<TideState>,
+    schema: Schema<Query, Mutation, Subscription>,
+    query_builder_configuration: F,
+) -> tide::Result<Response>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    TideState: Send + Sync + 'static,
+    F: Fn(QueryBuilder) -> Fut + Send,
+    Fut: Future<Output = tide::Result<QueryBuilder>> + Send,
+{
+    graphql_async_opts(req, schema, query_builder_configuration, Default::default()).await
+}
+
+/// Similar to graphql_async, but you can set the options `IntoQueryBuilderOpts`.
+pub async fn graphql_async_opts<Query, Mutation, Subscription, TideState, F, Fut>(
+    req: Request<TideState>,
+    schema: Schema<Query, Mutation, Subscription>,
+    query_builder_configuration: F,
+    opts: IntoQueryBuilderOpts,
+) -> tide::Result<Response>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    TideState: Send + Sync + 'static,
+    F: Fn(QueryBuilder) -> Fut + Send,
+    Fut: Future<Output = tide::Result<QueryBuilder>> + Send,
+{
+    let query_builder = req.body_graphql_opts(opts).await?;
+    Response::new(StatusCode::Ok).body_graphql(
+        query_builder_configuration(query_builder)
+            .await?
+            .execute(&schema)
+            .await,
+    )
+}
+
+/// Create a tide endpoint for GraphQL queries and mutations
+///
+/// It handles both `GET` and `POST` requests.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_std::task;
+///
+/// struct QueryRoot;
+/// #[Object]
+/// impl QueryRoot {
+///     #[field(desc = "Returns the sum of a and b")]
+///     async fn add(&self, a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// fn main() -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+///     task::block_on(async {
+///         let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish();
+///         let mut app = tide::new();
+///         app.at("/graphql").all(async_graphql_tide::endpoint(schema));
+///         app.at("/").get(async_graphql_tide::playground_endpoint("/graphql"));
+///         app.listen("0.0.0.0:8000").await?;
+///
+///         Ok(())
+///     })
+/// }
+/// ```
+pub fn endpoint<Query, Mutation, Subscription>(
+    schema: Schema<Query, Mutation, Subscription>,
+) -> GraphQLEndpoint<Query, Mutation, Subscription> {
+    GraphQLEndpoint {
+        schema,
+        opts: Default::default(),
+    }
+}
+
+/// Tide endpoint for GraphQL queries and mutations, created with `endpoint`.
+pub struct GraphQLEndpoint<Query, Mutation, Subscription> {
+    schema: Schema<Query, Mutation, Subscription>,
+    opts: IntoQueryBuilderOpts,
+}
+
+impl<Query, Mutation, Subscription> GraphQLEndpoint<Query, Mutation, Subscription> {
+    /// Set the options `IntoQueryBuilderOpts`.
+    pub fn opts(self, opts: IntoQueryBuilderOpts) -> Self {
+        GraphQLEndpoint { opts, ..self }
+    }
+}
+
+impl<Query, Mutation, Subscription, TideState> Endpoint<TideState>
+    for GraphQLEndpoint<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    TideState: Send + Sync + 'static,
+{
+    fn call<'a>(&'a self, req: Request<TideState>) -> BoxFuture<'a, tide::Result> {
+        Box::pin(graphql_opts(
+            req,
+            self.schema.clone(),
+            |query_builder| query_builder,
+            self.opts.clone(),
+        ))
+    }
+}
+
+/// Create a tide endpoint that serves GraphQL Playground for the GraphQL endpoint at `url`.
+pub fn playground_endpoint<TideState: Send + Sync + 'static>(
+    url: &str,
+) -> impl Endpoint<TideState> {
+    let html = playground_source(GraphQLPlaygroundConfig::new(url));
+    move |_: Request<TideState>| {
+        let mut resp = Response::new(StatusCode::Ok);
+        resp.set_body(html.clone());
+        resp.set_content_type(tide::http::mime::HTML);
+        async move { Ok(resp) }
+    }
+}
+
 /// Tide request extension
 ///
 #[async_trait]
@@ -107,7 +280,8 @@ pub trait RequestExt<State: Send + Sync + 'static>: Sized {
 impl<State: Send + Sync + 'static> RequestExt<State> for Request<State> {
     async fn body_graphql_opts(self, opts: IntoQueryBuilderOpts) -> tide::Result<QueryBuilder> {
         if self.method() == Method::Get {
-            let gql_request: GQLRequest = self.query::<GQLRequest>()?;
+            let gql_request = parse_query_string(self.url().query().unwrap_or_default())
+                .status(StatusCode::BadRequest)?;
             let builder = gql_request
                 .into_query_builder_opts(&opts)
                 .await
@@ -117,7 +291,7 @@ impl<State: Send + Sync + 'static> RequestExt<State> for Request<State> {
             let content_type = self
                 .header(&headers::CONTENT_TYPE)
                 .and_then(|values| values.get(0).map(|value| value.to_string()));
-            Ok((content_type, self).into_query_builder_opts(&opts).await?)
+            Ok(receive_request(content_type, self, &opts).await?)
         }
     }
 }
@@ -169,12 +343,13 @@ fn add_cache_control(
     mut http_resp: Response,
     resp: &async_graphql::Result<QueryResponse>,
 ) -> Response {
-    if let Ok(QueryResponse { cache_control, .. }) = resp {
-        if let Some(cache_control) = cache_control.value() {
-            if let Ok(header) = tide::http::headers::HeaderName::from_str("cache-control") {
-                http_resp.insert_header(header, cache_control);
-                return http_resp;
-            }
+    let cache_control = match resp {
+        Ok(QueryResponse { cache_control, .. }) => cache_control.value(),
+        Err(_) => Some("no-store".to_string()),
+    };
+    if let Some(cache_control) = cache_control {
+        if let Ok(header) = tide::http::headers::HeaderName::from_str("cache-control") {
+            http_resp.insert_header(header, cache_control);
         }
     }
     http_resp
@@ -254,3 +254,137 @@ fn upload() -> Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn unauthorized() -> Result<()> {
+    smol::run(async {
+        let listen_addr = test_utils::find_listen_addr().await;
+
+        let server = Task::<Result<()>>::spawn(async move {
+            use tide::{http::StatusCode, Request};
+
+            struct QueryRoot;
+            #[Object]
+            impl QueryRoot {
+                async fn value(&self) -> i32 {
+                    10
+                }
+            }
+
+            let mut app = tide::new();
+            app.at("/").post(|req: Request<()>| async move {
+                let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish();
+                let authorized = req.header("token").is_some();
+                async_graphql_tide::graphql_async(req, schema, |query_builder| async move {
+                    if authorized {
+                        Ok(query_builder)
+                    } else {
+                        Err(tide::Error::from_str(
+                            StatusCode::Unauthorized,
+                            "Unauthorized",
+                        ))
+                    }
+                })
+                .await
+            });
+            app.listen(&listen_addr).await?;
+
+            Ok(())
+        });
+
+        let client = Task::<Result<()>>::spawn(async move {
+            Timer::after(Duration::from_millis(300)).await;
+
+            let resp = reqwest::Client::new()
+                .post(format!("http://{}", listen_addr).as_str())
+                .body(r#"{"query":"{ value }"}"#)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .send()
+                .await?;
+            assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+            let resp = reqwest::Client::new()
+                .post(format!("http://{}", listen_addr).as_str())
+                .body(r#"{"query":"{ value }"}"#)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header("Token", "123456")
+                .send()
+                .await?;
+            assert_eq!(resp.status(), reqwest::StatusCode::OK);
+            let string = resp.text().await?;
+            assert_eq!(string, json!({"data": {"value": 10}}).to_string());
+
+            Ok(())
+        });
+
+        client.await?;
+        server.cancel().await;
+
+        Ok(())
+    })
+}
+
+#[test]
+fn endpoint() -> Result<()> {
+    smol::run(async {
+        let listen_addr = test_utils::find_listen_addr().await;
+
+        let server = Task::<Result<()>>::spawn(async move {
+            struct QueryRoot;
+            #[Object]
+            impl QueryRoot {
+                async fn add(&self, a: i32, b: i32) -> i32 {
+                    a + b
+                }
+            }
+
+            let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish();
+            let mut app = tide::new();
+            app.at("/graphql").all(async_graphql_tide::endpoint(schema));
+            app.at("/")
+                .get(async_graphql_tide::playground_endpoint("/graphql"));
+            app.listen(&listen_addr).await?;
+
+            Ok(())
+        });
+
+        let client = Task::<Result<()>>::spawn(async move {
+            Timer::after(Duration::from_millis(300)).await;
+
+            let resp = reqwest::Client::new()
+                .post(format!("http://{}/graphql", listen_addr).as_str())
+                .body(r#"{"query":"{ add(a: 10, b: 20) }"}"#)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .send()
+                .await?;
+            assert_eq!(resp.status(), reqwest::StatusCode::OK);
+            let string = resp.text().await?;
+            assert_eq!(string, json!({"data": {"add": 30}}).to_string());
+
+            let resp = reqwest::Client::new()
+                .get(format!("http://{}/graphql", listen_addr).as_str())
+                .query(&[("query", "{ add(a: 1, b: 2) }")])
+                .send()
+                .await?;
+            assert_eq!(resp.status(), reqwest::StatusCode::OK);
+            let string = resp.text().await?;
+            assert_eq!(string, json!({"data": {"add": 3}}).to_string());
+
+            let resp = reqwest::Client::new()
+                .get(format!("http://{}", listen_addr).as_str())
+                .send()
+                .await?;
+            assert_eq!(resp.status(), reqwest::StatusCode::OK);
+            assert!(resp.headers()[reqwest::header::CONTENT_TYPE]
+                .to_str()?
+                .starts_with("text/html"));
+
+            Ok(())
+        });
+
+        client.await?;
+        server.cancel().await;
+
+        Ok(())
+    })
+}
@@ -5,19 +5,18 @@
 #![allow(clippy::needless_doctest_main)]
 #![forbid(unsafe_code)]
 
-use async_graphql::http::{multipart_stream, GQLRequest, StreamBody};
+use async_graphql::http::{multipart_stream, parse_query_string, receive_request, StreamBody};
 use async_graphql::{
     Data, FieldResult, IntoQueryBuilder, IntoQueryBuilderOpts, ObjectType, QueryBuilder,
-    QueryResponse, Schema, StreamResponse, SubscriptionType, WebSocketTransport,
+    QueryResponse, Schema, StreamResponse, SubscriptionServer, SubscriptionType,
+    WebSocketTransport,
 };
-use bytes::Bytes;
-use futures::select;
-use futures::{SinkExt, StreamExt};
+use futures::{future, SinkExt, StreamExt};
 use hyper::header::HeaderValue;
 use hyper::{Body, Method};
 use std::convert::Infallible;
 use std::sync::Arc;
-use warp::filters::ws::Message;
+use warp::filters::ws::{Message, WebSocket};
 use warp::filters::BoxedFilter;
 use warp::reject::Reject;
 use warp::reply::Response;
@@ -106,17 +105,15 @@ where
              opts: Arc<IntoQueryBuilderOpts>,
              schema| async move {
                 if method == Method::GET {
-                    let gql_request: GQLRequest =
-                        serde_urlencoded::from_str(&query)
-                            .map_err(|err| warp::reject::custom(BadRequest(err.into())))?;
+                    let gql_request = parse_query_string(&query)
+                        .map_err(|err| warp::reject::custom(BadRequest(err.into())))?;
                     let builder = gql_request
                         .into_query_builder_opts(&opts)
                         .await
                         .map_err(|err| warp::reject::custom(BadRequest(err.into())))?;
                     Ok::<_, Rejection>((schema, builder))
                 } else {
-                    let builder = (content_type, StreamBody::new(body))
-                        .into_query_builder_opts(&opts)
+                    let builder = receive_request(content_type, StreamBody::new(body), &opts)
                         .await
                         .map_err(|err| warp::reject::custom(BadRequest(err.into())))?;
                     Ok::<_, Rejection>((schema, builder))
@@ -173,45 +170,11 @@ where
         .map(
             |ws: warp::ws::Ws, schema: Schema<Query, Mutation, Subscription>| {
                 ws.on_upgrade(move |websocket| {
-                    let (mut tx, rx) = websocket.split();
-                    let (mut stx, srx) =
-                        schema.subscription_connection(WebSocketTransport::default());
-
-                    let mut rx = rx.fuse();
-                    let mut srx = srx.fuse();
-
-                    async move {
-                        loop {
-                            select! {
-                                bytes = srx.next() => {
-                                    if let Some(bytes) = bytes {
-                                        if let Ok(text) = String::from_utf8(bytes.to_vec()) {
-                                            if tx.send(Message::text(text)).await.is_err()
-                                            {
-                                                return;
-                                            }
-                                        }
-                                    } else {
-                                        return;
-                                    }
-                                }
-                                msg = rx.next() => {
-                                    if let Some(Ok(msg)) = msg {
-                                        if msg.is_text() {
-                                            if stx.send(Bytes::copy_from_slice(msg.as_bytes())).await.is_err() {
-                                                return;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    serve_websocket(websocket, SubscriptionServer::new(schema))
                 })
             },
-        ).map(|reply| {
-        warp::reply::with_header(reply, "Sec-WebSocket-Protocol", "graphql-ws")
-    })
+        )
+        .map(|reply| warp::reply::with_header(reply, "Sec-WebSocket-Protocol", "graphql-ws"))
         .boxed()
 }
 
@@ -233,49 +196,46 @@ where
         .and(warp::any().map(move || schema.clone()))
         .and(warp::any().map(move || init_context_data.clone()))
         .map(
-            |ws: warp::ws::Ws, schema: Schema<Query, Mutation, Subscription>, init_context_data: F| {
+            |ws: warp::ws::Ws,
+             schema: Schema<Query, Mutation, Subscription>,
+             init_context_data: F| {
                 ws.on_upgrade(move |websocket| {
-                    let (mut tx, rx) = websocket.split();
-                    let (mut stx, srx) =
-                        schema.subscription_connection(WebSocketTransport::new(init_context_data));
-
-                    let mut rx = rx.fuse();
-                    let mut srx = srx.fuse();
-
-                    async move {
-                        loop {
-                            select! {
-                                bytes = srx.next() => {
-                                    if let Some(bytes) = bytes {
-                                        if let Ok(text) = String::from_utf8(bytes.to_vec()) {
-                                            if tx.send(Message::text(text)).await.is_err() {
-                                                return;
-                                            }
-                                        }
-                                    } else {
-                                        return;
-                                    }
-                                }
-                                msg = rx.next() => {
-                                    if let Some(Ok(msg)) = msg {
-                                        if msg.is_text() {
-                                            if stx.send(Bytes::copy_from_slice(msg.as_bytes())).await.is_err() {
-                                                return;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    serve_websocket(
+                        websocket,
+                        SubscriptionServer::with_transport(
+                            schema,
+                            WebSocketTransport::new(init_context_data),
+                        ),
+                    )
                 })
             },
-        ).map(|reply| {
-        warp::reply::with_header(reply, "Sec-WebSocket-Protocol", "graphql-ws")
-    })
+        )
+        .map(|reply| warp::reply::with_header(reply, "Sec-WebSocket-Protocol", "graphql-ws"))
         .boxed()
 }
 
+async fn serve_websocket<Query, Mutation, Subscription>(
+    websocket: WebSocket,
+    server: SubscriptionServer<Query, Mutation, Subscription>,
+) where
+    Query: ObjectType + Sync + Send + 'static,
+    Mutation: ObjectType + Sync + Send + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    let (tx, rx) = websocket.split();
+    server
+        .serve(
+            tx.with(|text| future::ok::<_, warp::Error>(Message::text(text))),
+            rx.filter_map(|msg| {
+                future::ready(
+                    msg.ok()
+                        .and_then(|msg| msg.to_str().ok().map(ToString::to_string)),
+                )
+            }),
+        )
+        .await
+}
+
 /// GraphQL reply
 pub struct GQLResponse(async_graphql::Result<QueryResponse>);
 
@@ -286,12 +246,19 @@ impl From<async_graphql::Result<QueryResponse>> for GQLResponse {
 }
 
 fn add_cache_control(http_resp: &mut Response, resp: &async_graphql::Result<QueryResponse>) {
-    if let Ok(QueryResponse { cache_control, .. }) = resp {
-        if let Some(cache_control) = cache_control.value() {
-            if let Ok(value) = cache_control.parse() {
-                http_resp.headers_mut().insert("cache-control", value);
+    match resp {
+        Ok(QueryResponse { cache_control, .. }) => {
+            if let Some(cache_control) = cache_control.value() {
+                if let Ok(value) = cache_control.parse() {
+                    http_resp.headers_mut().insert("cache-control", value);
+                }
             }
         }
+        Err(_) => {
+            http_resp
+                .headers_mut()
+                .insert("cache-control", HeaderValue::from_static("no-store"));
+        }
     }
 }
 
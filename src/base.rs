@@ -4,6 +4,8 @@ use crate::{
     Result, Value,
 };
 use async_graphql_parser::query::Field;
+use indexmap::IndexMap;
+use std::any::Any;
 use std::borrow::Cow;
 use std::future::Future;
 use std::pin::Pin;
@@ -34,6 +36,24 @@ pub trait Type {
 
 /// Represents a GraphQL input value
 pub trait InputValueType: Type + Sized {
+    /// The name of the type when it is used as an input value.
+    ///
+    /// It is the same as `Type::type_name`, unless the type is both an output object and an
+    /// input object.
+    fn input_type_name() -> Cow<'static, str> {
+        Self::type_name()
+    }
+
+    /// Qualified input typename.
+    fn qualified_input_type_name() -> String {
+        Self::qualified_type_name()
+    }
+
+    /// Create the type information of the input type in the registry and return qualified input typename.
+    fn create_input_type_info(registry: &mut registry::Registry) -> String {
+        Self::create_type_info(registry)
+    }
+
     /// Parse from `Value`，None represent undefined.
     fn parse(value: Option<Value>) -> InputValueResult<Self>;
 
@@ -97,11 +117,213 @@ pub trait ObjectType: OutputValueType {
     async fn find_entity(&self, ctx: &Context<'_>, _params: &Value) -> Result<serde_json::Value> {
         Err(QueryError::EntityNotFound.into_error(ctx.position()))
     }
+
+    /// Returns the object as `Any`, it is used to resolve the fields added by an
+    /// [`ObjectExtension`](trait.ObjectExtension.html), and is `None` for generic objects.
+    #[doc(hidden)]
+    fn as_any(&self) -> Option<&(dyn Any + Sync)> {
+        None
+    }
+}
+
+/// An object that can be resolved through a trait object
+///
+/// It is implemented for all objects. A trait that has it as a supertrait can be bound to an
+/// interface with [`dyn_interface!`](macro.dyn_interface.html), and then resolvers can return
+/// `Box<dyn Trait>`, whose concrete type is only known at runtime.
+#[async_trait::async_trait]
+pub trait DynObjectType: Send + Sync {
+    /// The name of the concrete object type.
+    fn dyn_type_name(&self) -> Cow<'static, str>;
+
+    /// Resolve the concrete object.
+    async fn dyn_resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value>;
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType + Send + Sync> DynObjectType for T {
+    fn dyn_type_name(&self) -> Cow<'static, str> {
+        self.introspection_type_name()
+    }
+
+    async fn dyn_resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        OutputValueType::resolve(self, ctx, field).await
+    }
+}
+
+/// Add fields to an object type defined in another crate
+///
+/// The fields of the extension are merged into the fields of `Target` when the extension is
+/// registered with `SchemaBuilder::extend_type`. They are resolved on the extension value created
+/// from the `Target` value with `from_target`. `Target` must not be a generic object.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// /// Defined in a shared library.
+/// #[SimpleObject]
+/// struct User {
+///     id: i32,
+/// }
+///
+/// struct UserExtension {
+///     id: i32,
+/// }
+///
+/// #[Object]
+/// impl UserExtension {
+///     async fn avatar_url(&self) -> String {
+///         format!("https://example.com/avatars/{}.png", self.id)
+///     }
+/// }
+///
+/// impl ObjectExtension for UserExtension {
+///     type Target = User;
+///
+///     fn from_target(user: &User) -> Self {
+///         UserExtension { id: user.id }
+///     }
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn user(&self) -> User {
+///         User { id: 1 }
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .extend_type::<UserExtension>()
+///         .finish();
+///     let res = schema.execute("{ user { id avatarUrl } }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({
+///         "user": { "id": 1, "avatarUrl": "https://example.com/avatars/1.png" },
+///     }));
+/// });
+/// ```
+pub trait ObjectExtension: ObjectType + Send + Sync + Sized + 'static {
+    /// The extended object type.
+    type Target: ObjectType + Send + Sync + 'static;
+
+    /// Create the extension of a `Target` value.
+    fn from_target(target: &Self::Target) -> Self;
+}
+
+/// Bind a trait to an interface, so that `Box<dyn Trait>` can be returned by resolvers
+///
+/// The trait must have [`DynObjectType`](trait.DynObjectType.html) as a supertrait, and the
+/// interface defines the fields of the GraphQL type. The objects that implement the trait must
+/// be registered as possible types of the interface with `SchemaBuilder::register_implementor`,
+/// unless they are variants of the interface.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// trait Shape: DynObjectType {}
+///
+/// #[Interface(field(name = "area", type = "f64"))]
+/// enum ShapeInterface {}
+///
+/// dyn_interface!(Shape, ShapeInterface);
+///
+/// struct Square(f64);
+///
+/// #[Object]
+/// impl Square {
+///     async fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// impl Shape for Square {}
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn shape(&self) -> Box<dyn Shape> {
+///         Box::new(Square(2.0))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .register_implementor::<dyn Shape, Square>()
+///         .finish();
+///     let res = schema.execute("{ shape { __typename area } }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "shape": { "__typename": "Square", "area": 4.0 } }));
+/// });
+/// ```
+#[macro_export]
+macro_rules! dyn_interface {
+    ($trait:path, $interface:ty) => {
+        impl $crate::Type for dyn $trait {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                <$interface as $crate::Type>::type_name()
+            }
+
+            fn introspection_type_name(&self) -> ::std::borrow::Cow<'static, str> {
+                $crate::DynObjectType::dyn_type_name(self)
+            }
+
+            fn create_type_info(registry: &mut $crate::registry::Registry) -> String {
+                <$interface as $crate::Type>::create_type_info(registry)
+            }
+        }
+
+        #[$crate::async_trait::async_trait]
+        impl $crate::OutputValueType for dyn $trait {
+            async fn resolve(
+                &self,
+                ctx: &$crate::ContextSelectionSet<'_>,
+                field: &$crate::Positioned<$crate::parser::query::Field>,
+            ) -> $crate::Result<$crate::serde_json::Value> {
+                $crate::DynObjectType::dyn_resolve(self, ctx, field).await
+            }
+        }
+    };
+}
+
+/// Resolvers of the computed fields of a `SimpleObject`
+///
+/// It is implemented by the `ComplexObject` macro, for a `SimpleObject` that has the `complex`
+/// attribute.
+#[async_trait::async_trait]
+pub trait ComplexObject {
+    #[doc(hidden)]
+    fn fields(registry: &mut Registry, fields: &mut IndexMap<String, registry::MetaField>);
+
+    #[doc(hidden)]
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value>;
 }
 
 /// Represents a GraphQL input object
 pub trait InputObjectType: InputValueType {}
 
+/// The description of a type, taken from its doc comment
+///
+/// It is implemented with `#[derive(Description)]`, and used by an `Object` with the
+/// `use_type_description` attribute, whose doc comment is on the type instead of the impl block.
+pub trait Description {
+    /// The description of the type.
+    fn description() -> &'static str;
+}
+
 /// Represents a GraphQL scalar
 ///
 /// You can implement the trait to create a custom scalar.
@@ -165,7 +387,7 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for &T {
     }
 }
 
-impl<T: Type + Send + Sync> Type for Box<T> {
+impl<T: Type + Send + Sync + ?Sized> Type for Box<T> {
     fn type_name() -> Cow<'static, str> {
         T::type_name()
     }
@@ -176,7 +398,7 @@ impl<T: Type + Send + Sync> Type for Box<T> {
 }
 
 #[async_trait::async_trait]
-impl<T: OutputValueType + Send + Sync> OutputValueType for Box<T> {
+impl<T: OutputValueType + Send + Sync + ?Sized> OutputValueType for Box<T> {
     #[allow(clippy::trivially_copy_pass_by_ref)]
     #[allow(clippy::borrowed_box)]
     async fn resolve(
@@ -184,11 +406,33 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for Box<T> {
         ctx: &ContextSelectionSet<'_>,
         field: &Positioned<Field>,
     ) -> Result<serde_json::Value> {
-        T::resolve(&*self, ctx, field).await
+        T::resolve(&**self, ctx, field).await
+    }
+}
+
+impl<T: InputValueType + Send + Sync> InputValueType for Box<T> {
+    fn input_type_name() -> Cow<'static, str> {
+        T::input_type_name()
+    }
+
+    fn qualified_input_type_name() -> String {
+        T::qualified_input_type_name()
+    }
+
+    fn create_input_type_info(registry: &mut Registry) -> String {
+        T::create_input_type_info(registry)
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        T::parse(value).map(Box::new)
+    }
+
+    fn to_value(&self) -> Value {
+        T::to_value(self)
     }
 }
 
-impl<T: Type + Send + Sync> Type for Arc<T> {
+impl<T: Type + Send + Sync + ?Sized> Type for Arc<T> {
     fn type_name() -> Cow<'static, str> {
         T::type_name()
     }
@@ -199,14 +443,14 @@ impl<T: Type + Send + Sync> Type for Arc<T> {
 }
 
 #[async_trait::async_trait]
-impl<T: OutputValueType + Send + Sync> OutputValueType for Arc<T> {
+impl<T: OutputValueType + Send + Sync + ?Sized> OutputValueType for Arc<T> {
     #[allow(clippy::trivially_copy_pass_by_ref)]
     async fn resolve(
         &self,
         ctx: &ContextSelectionSet<'_>,
         field: &Positioned<Field>,
     ) -> Result<serde_json::Value> {
-        T::resolve(&*self, ctx, field).await
+        T::resolve(&**self, ctx, field).await
     }
 }
 
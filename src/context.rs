@@ -2,16 +2,17 @@ use crate::extensions::Extensions;
 use crate::parser::query::{Directive, Field, SelectionSet};
 use crate::schema::SchemaEnv;
 use crate::{
-    InputValueType, Lookahead, Pos, Positioned, QueryError, QueryResponse, Result, Type, Value,
+    InputValueType, Lookahead, Pos, Positioned, QueryError, QueryResponse, Result, ServerError,
+    Type, Value,
 };
 use async_graphql_parser::query::Document;
-use async_graphql_parser::UploadValue;
+use async_graphql_parser::{UploadContent, UploadValue};
 use fnv::FnvHashMap;
 use futures::Future;
 use parking_lot::Mutex;
 use std::any::{Any, TypeId};
-use std::collections::BTreeMap;
-use std::fs::File;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Display, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
 use std::sync::atomic::AtomicUsize;
@@ -27,6 +28,12 @@ impl Default for Variables {
     }
 }
 
+impl Display for Variables {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 impl Deref for Variables {
     type Target = BTreeMap<String, Value>;
 
@@ -59,12 +66,23 @@ impl Variables {
         }
     }
 
+    /// Returns a copy of the variables with the values of `names` replaced with `***`.
+    pub(crate) fn mask_secrets(&self, names: &HashSet<String>) -> Self {
+        let mut variables = self.clone();
+        for (name, value) in variables.iter_mut() {
+            if names.contains(name) {
+                *value = Value::String("***".to_string());
+            }
+        }
+        variables
+    }
+
     pub(crate) fn set_upload(
         &mut self,
         var_path: &str,
         filename: String,
         content_type: Option<String>,
-        content: File,
+        content: UploadContent,
     ) {
         let mut it = var_path.split('.').peekable();
 
@@ -124,6 +142,53 @@ impl Data {
     pub fn insert<D: Any + Send + Sync>(&mut self, data: D) {
         self.0.insert(TypeId::of::<D>(), Box::new(data));
     }
+
+    pub(crate) fn merge(&mut self, other: Data) {
+        self.0.extend(other.0);
+    }
+}
+
+/// Context of the `visible` functions
+///
+/// The visibility of types, fields and enum values is checked when the query is validated, before
+/// any field is resolved. A `Context` belongs to a field being resolved, with its path, arguments
+/// and selection set, so none exists yet at that time. The visible functions get the data of the
+/// request and of the schema instead, with the same `data` and `data_opt` methods as `Context`,
+/// and introspection calls them with the same data so both always agree.
+#[derive(Copy, Clone)]
+pub struct VisibleContext<'a> {
+    query_data: &'a Data,
+    schema_data: &'a Data,
+}
+
+impl<'a> VisibleContext<'a> {
+    pub(crate) fn new(query_data: &'a Data, schema_data: &'a Data) -> Self {
+        VisibleContext {
+            query_data,
+            schema_data,
+        }
+    }
+
+    /// Gets the global data defined in the `Context` or `Schema`.
+    ///
+    /// If both `Schema` and `Query` have the same data type, the data in the `Query` is obtained.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the specified data type does not exist.
+    pub fn data<D: Any + Send + Sync>(&self) -> &'a D {
+        self.data_opt::<D>()
+            .expect("The specified data type does not exist.")
+    }
+
+    /// Gets the global data defined in the `Context` or `Schema`, returns `None` if the specified type data does not exist.
+    pub fn data_opt<D: Any + Send + Sync>(&self) -> Option<&'a D> {
+        self.query_data
+            .0
+            .get(&TypeId::of::<D>())
+            .or_else(|| self.schema_data.0.get(&TypeId::of::<D>()))
+            .and_then(|d| d.downcast_ref::<D>())
+    }
 }
 
 /// Context for `SelectionSet`
@@ -256,6 +321,21 @@ impl DeferList {
     }
 }
 
+#[doc(hidden)]
+#[derive(Default)]
+pub struct ResponseErrors(Mutex<Vec<ServerError>>);
+
+impl ResponseErrors {
+    pub(crate) fn add(&self, errors: impl IntoIterator<Item = ServerError>) {
+        self.0.lock().extend(errors);
+    }
+
+    #[doc(hidden)]
+    pub fn take(&self) -> Vec<ServerError> {
+        std::mem::take(&mut *self.0.lock())
+    }
+}
+
 /// Query context
 #[derive(Clone)]
 pub struct ContextBase<'a, T> {
@@ -268,6 +348,7 @@ pub struct ContextBase<'a, T> {
     pub(crate) schema_env: &'a SchemaEnv,
     pub(crate) query_env: &'a QueryEnv,
     pub(crate) defer_list: Option<&'a DeferList>,
+    pub(crate) errors: &'a ResponseErrors,
 }
 
 impl<'a, T> Deref for ContextBase<'a, T> {
@@ -322,6 +403,7 @@ impl QueryEnv {
         item: T,
         inc_resolve_id: &'a AtomicUsize,
         defer_list: Option<&'a DeferList>,
+        errors: &'a ResponseErrors,
     ) -> ContextBase<'a, T> {
         ContextBase {
             path_node,
@@ -331,6 +413,7 @@ impl QueryEnv {
             schema_env,
             query_env: self,
             defer_list,
+            errors,
         }
     }
 }
@@ -369,6 +452,7 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             defer_list: self.defer_list,
+            errors: self.errors,
         }
     }
 
@@ -385,6 +469,7 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             defer_list: self.defer_list,
+            errors: self.errors,
         }
     }
 
@@ -402,12 +487,11 @@ impl<'a, T> ContextBase<'a, T> {
 
     /// Gets the global data defined in the `Context` or `Schema`, returns `None` if the specified type data does not exist.
     pub fn data_opt<D: Any + Send + Sync>(&self) -> Option<&D> {
-        self.query_env
-            .ctx_data
-            .0
-            .get(&TypeId::of::<D>())
-            .or_else(|| self.schema_env.data.0.get(&TypeId::of::<D>()))
-            .and_then(|d| d.downcast_ref::<D>())
+        self.visible_context().data_opt::<D>()
+    }
+
+    pub(crate) fn visible_context(&self) -> VisibleContext<'_> {
+        VisibleContext::new(&self.query_env.ctx_data, &self.schema_env.data)
     }
 
     fn var_value(&self, name: &str, pos: Pos) -> Result<Value> {
@@ -431,7 +515,7 @@ impl<'a, T> ContextBase<'a, T> {
         .into_error(pos))
     }
 
-    fn resolve_input_value(&self, value: &mut Value, pos: Pos) -> Result<()> {
+    pub(crate) fn resolve_input_value(&self, value: &mut Value, pos: Pos) -> Result<()> {
         match value {
             Value::Variable(var_name) => {
                 *value = self.var_value(&var_name, pos)?;
@@ -525,6 +609,7 @@ impl<'a> ContextBase<'a, &'a Positioned<SelectionSet>> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             defer_list: self.defer_list,
+            errors: self.errors,
         }
     }
 }
@@ -557,7 +642,7 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
 
         match InputValueType::parse(value) {
             Ok(res) => Ok(res),
-            Err(err) => Err(err.into_error(pos, T::qualified_type_name())),
+            Err(err) => Err(err.into_error(pos, T::qualified_input_type_name())),
         }
     }
 
@@ -570,6 +655,11 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
             .unwrap_or_else(|| self.item.name.as_str())
     }
 
+    #[doc(hidden)]
+    pub fn registry(&self) -> &crate::registry::Registry {
+        &self.schema_env.registry
+    }
+
     /// Get the position of the current field in the query code.
     pub fn position(&self) -> Pos {
         self.pos
@@ -0,0 +1,165 @@
+//! Custom executable directives
+
+use crate::parser::query::Directive;
+use crate::registry::{MetaInputValue, Registry};
+use crate::{Context, FieldResult, InputValueType, Positioned, Result};
+use indexmap::IndexMap;
+use std::ops::Deref;
+
+/// Custom executable directive
+///
+/// A custom directive is registered with `SchemaBuilder::directive`, and can then be used on the
+/// fields of queries and mutations, e.g. `{ name @uppercase }`. The directive and its arguments
+/// are validated like `@skip` and `@include`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::directive::{CustomDirective, DirectiveArgs, DirectiveContext};
+///
+/// struct Truncate;
+///
+/// #[async_trait::async_trait]
+/// impl CustomDirective for Truncate {
+///     fn name(&self) -> &'static str {
+///         "truncate"
+///     }
+///
+///     fn args(&self, args: &mut DirectiveArgs<'_>) {
+///         args.arg::<i32>("length", Some("Maximum length of the string"));
+///     }
+///
+///     async fn after_resolve(
+///         &self,
+///         ctx: &DirectiveContext<'_>,
+///         value: serde_json::Value,
+///     ) -> FieldResult<serde_json::Value> {
+///         let length = ctx.directive_param_value::<i32>("length")? as usize;
+///         Ok(match value {
+///             serde_json::Value::String(s) => s.chars().take(length).collect::<String>().into(),
+///             value => value,
+///         })
+///     }
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn value(&self) -> &str {
+///         "abcdef"
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///         .directive(Truncate)
+///         .finish();
+///     let res = schema.execute("{ value @truncate(length: 3) }").await.unwrap();
+///     assert_eq!(res.data, serde_json::json!({ "value": "abc" }));
+/// });
+/// ```
+#[async_trait::async_trait]
+pub trait CustomDirective: Send + Sync + 'static {
+    /// The name of the directive, without the `@`.
+    fn name(&self) -> &'static str;
+
+    /// The description of the directive.
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Define the arguments of the directive.
+    fn args(&self, _args: &mut DirectiveArgs<'_>) {}
+
+    /// Called before the field is resolved.
+    ///
+    /// If it returns a value, the field resolver is not called and the value is used instead.
+    async fn before_resolve(
+        &self,
+        _ctx: &DirectiveContext<'_>,
+    ) -> FieldResult<Option<serde_json::Value>> {
+        Ok(None)
+    }
+
+    /// Called with the resolved value of the field, returns the value of the field.
+    async fn after_resolve(
+        &self,
+        _ctx: &DirectiveContext<'_>,
+        value: serde_json::Value,
+    ) -> FieldResult<serde_json::Value> {
+        Ok(value)
+    }
+}
+
+/// The arguments of a custom directive
+pub struct DirectiveArgs<'a> {
+    pub(crate) registry: &'a mut Registry,
+    pub(crate) args: IndexMap<String, MetaInputValue>,
+}
+
+impl<'a> DirectiveArgs<'a> {
+    /// Add an argument of type `T`.
+    pub fn arg<T: InputValueType>(
+        &mut self,
+        name: &'static str,
+        description: Option<&'static str>,
+    ) -> &mut Self {
+        let ty = T::create_input_type_info(self.registry);
+        self.args.insert(
+            name.to_string(),
+            MetaInputValue {
+                name: name.to_string(),
+                description: description.map(ToString::to_string),
+                ty,
+                default_value: None,
+                validator: None,
+                visible: None,
+                directives: Default::default(),
+                is_secret: false,
+            },
+        );
+        self
+    }
+}
+
+/// Context of a custom directive
+///
+/// It dereferences to the context of the field that the directive is applied to.
+pub struct DirectiveContext<'a> {
+    pub(crate) ctx: &'a Context<'a>,
+    pub(crate) directive: &'a Positioned<Directive>,
+}
+
+impl<'a> Deref for DirectiveContext<'a> {
+    type Target = Context<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.ctx
+    }
+}
+
+impl<'a> DirectiveContext<'a> {
+    /// Get the value of an argument of the directive.
+    pub fn directive_param_value<T: InputValueType>(&self, name: &str) -> Result<T> {
+        let value = self.directive.get_argument(name).cloned();
+        let pos = value
+            .as_ref()
+            .map(|value| value.position())
+            .unwrap_or_else(|| self.directive.position());
+        let value = match value {
+            Some(value) => {
+                let mut new_value = value.into_inner();
+                self.ctx.resolve_input_value(&mut new_value, pos)?;
+                Some(new_value)
+            }
+            None => None,
+        };
+
+        match InputValueType::parse(value) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(err.into_error(pos, T::qualified_input_type_name())),
+        }
+    }
+}
@@ -0,0 +1,801 @@
+//! Dynamic schema
+//!
+//! The types, fields and resolvers of a dynamic schema are registered at runtime, e.g. loaded
+//! from a configuration file or a database, instead of being defined with the procedural macros.
+//! Fields are resolved by boxed functions that return a [`FieldValue`](enum.FieldValue.html),
+//! the value returned for an object is passed to the resolvers of its fields as the parent.
+//!
+//! Only objects are supported, the arguments and the scalar fields use the built-in scalars
+//! (`Int`, `Float`, `String`, `Boolean` and `ID`). The values returned for scalar fields are
+//! checked against their types, an integer `ID` is returned as a string.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::dynamic::*;
+//! use async_graphql::Value;
+//! use futures::FutureExt;
+//!
+//! let user = Object::new("User")
+//!     .field(Field::new("name", "String!", |ctx| {
+//!         async move { Ok(FieldValue::value(ctx.parent.as_value().unwrap()["name"].clone())) }
+//!             .boxed()
+//!     }));
+//!
+//! let query = Object::new("Query").field(
+//!     Field::new("user", "User", |ctx| {
+//!         async move {
+//!             let name: String = ctx.arg("name")?;
+//!             Ok(FieldValue::value(serde_json::json!({ "name": name })))
+//!         }
+//!         .boxed()
+//!     })
+//!     .argument(InputValue::new("name", "String").default_value(Value::String("jack".into()))),
+//! );
+//!
+//! let schema = Schema::build("Query", None)
+//!     .register(user)
+//!     .register(query)
+//!     .finish()
+//!     .unwrap();
+//!
+//! async_std::task::block_on(async move {
+//!     let res = schema.execute(r#"{ a: user { name } b: user(name: "tom") { name } }"#).await.unwrap();
+//!     assert_eq!(res.data, serde_json::json!({
+//!         "a": { "name": "jack" },
+//!         "b": { "name": "tom" },
+//!     }));
+//! });
+//! ```
+
+use crate::context::{Data, ResolveId, ResponseErrors};
+use crate::extensions::{BoxExtension, Extension, Extensions};
+use crate::parser::parse_query;
+use crate::parser::query::{Selection, TypeCondition};
+use crate::registry::{MetaField, MetaInputValue, MetaType, MetaTypeName};
+use crate::schema::create_registry;
+use crate::types::{add_introspection_fields, resolve_introspection_field};
+use crate::validation::{check_rules, CheckResult, ValidationMode};
+use crate::{
+    Context, ContextBase, ContextSelectionSet, Error, FieldResult, InputValueType, Pos,
+    QueryBuilder, QueryEnv, QueryError, QueryResponse, Result, SchemaEnv, Value,
+};
+use async_graphql_parser::query::OperationType;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use indexmap::IndexMap;
+use itertools::Itertools;
+use std::any::Any;
+use std::ops::Deref;
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+
+/// A value returned by the resolver of a dynamic field
+pub enum FieldValue {
+    /// `null`
+    Null,
+
+    /// A JSON value, e.g. a scalar or an object whose fields read it from `ResolverContext::parent`.
+    Value(serde_json::Value),
+
+    /// Any Rust value, usually an object whose fields downcast it from `ResolverContext::parent`.
+    Owned(Box<dyn Any + Send + Sync>),
+
+    /// A list of values
+    List(Vec<FieldValue>),
+}
+
+impl FieldValue {
+    /// Create a JSON value.
+    pub fn value(value: impl Into<serde_json::Value>) -> Self {
+        FieldValue::Value(value.into())
+    }
+
+    /// Create a value from any Rust value.
+    pub fn owned_any<T: Any + Send + Sync>(value: T) -> Self {
+        FieldValue::Owned(Box::new(value))
+    }
+
+    /// Create a list of values.
+    pub fn list(values: impl IntoIterator<Item = FieldValue>) -> Self {
+        FieldValue::List(values.into_iter().collect())
+    }
+
+    /// Returns the JSON value, or `None` if it is not a JSON value.
+    pub fn as_value(&self) -> Option<&serde_json::Value> {
+        match self {
+            FieldValue::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the Rust value, or `None` if it is not a value of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            FieldValue::Owned(value) => value.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(
+            self,
+            FieldValue::Null | FieldValue::Value(serde_json::Value::Null)
+        )
+    }
+}
+
+/// Context of the resolver of a dynamic field
+///
+/// It dereferences to the context of the field.
+pub struct ResolverContext<'a> {
+    /// The context of the field.
+    pub ctx: &'a Context<'a>,
+
+    /// The value of the object that the field belongs to, `FieldValue::Null` for the root objects.
+    pub parent: &'a FieldValue,
+
+    field: &'a Field,
+}
+
+impl<'a> Deref for ResolverContext<'a> {
+    type Target = Context<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.ctx
+    }
+}
+
+impl<'a> ResolverContext<'a> {
+    /// Get the value of an argument of the field.
+    ///
+    /// The default value of the argument is used if it is not specified in the query.
+    pub fn arg<T: InputValueType>(&self, name: &str) -> Result<T> {
+        if self.ctx.get_argument(name).is_none() {
+            if let Some(default_value) = self
+                .field
+                .args
+                .get(name)
+                .and_then(|arg| arg.default_value.clone())
+            {
+                return T::parse(Some(default_value)).map_err(|err| {
+                    err.into_error(self.ctx.position(), T::qualified_input_type_name())
+                });
+            }
+        }
+        self.ctx.param_value(name, None)
+    }
+}
+
+type BoxResolverFn = Box<
+    dyn for<'a> Fn(ResolverContext<'a>) -> BoxFuture<'a, FieldResult<FieldValue>> + Send + Sync,
+>;
+
+/// An argument of a dynamic field
+pub struct InputValue {
+    name: String,
+    description: Option<String>,
+    ty: String,
+    default_value: Option<Value>,
+}
+
+impl InputValue {
+    /// Create an argument with the name and the GraphQL type, e.g. `String!` or `[Int!]`.
+    pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            ty: ty.into(),
+            default_value: None,
+        }
+    }
+
+    /// Set the description of the argument.
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Set the default value of the argument.
+    pub fn default_value(self, value: Value) -> Self {
+        Self {
+            default_value: Some(value),
+            ..self
+        }
+    }
+}
+
+/// A field of a dynamic object
+pub struct Field {
+    name: String,
+    description: Option<String>,
+    ty: String,
+    args: IndexMap<String, InputValue>,
+    resolver: BoxResolverFn,
+}
+
+impl Field {
+    /// Create a field with the name, the GraphQL type, e.g. `String!` or `[User!]!`, and the resolver.
+    pub fn new<F>(name: impl Into<String>, ty: impl Into<String>, resolver: F) -> Self
+    where
+        F: for<'a> Fn(ResolverContext<'a>) -> BoxFuture<'a, FieldResult<FieldValue>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            name: name.into(),
+            description: None,
+            ty: ty.into(),
+            args: Default::default(),
+            resolver: Box::new(resolver),
+        }
+    }
+
+    /// Set the description of the field.
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Add an argument to the field.
+    pub fn argument(mut self, input_value: InputValue) -> Self {
+        self.args.insert(input_value.name.clone(), input_value);
+        self
+    }
+}
+
+/// A dynamic object
+pub struct Object {
+    name: String,
+    description: Option<String>,
+    fields: IndexMap<String, Field>,
+}
+
+impl Object {
+    /// Create an object with the name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            fields: Default::default(),
+        }
+    }
+
+    /// Set the description of the object.
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Add a field to the object.
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.insert(field.name.clone(), field);
+        self
+    }
+}
+
+/// An error of building a dynamic schema
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct SchemaError(pub String);
+
+/// Dynamic schema builder
+pub struct SchemaBuilder {
+    query: String,
+    mutation: Option<String>,
+    objects: IndexMap<String, Object>,
+    data: Data,
+    extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+}
+
+impl SchemaBuilder {
+    /// Register an object.
+    pub fn register(mut self, object: Object) -> Self {
+        self.objects.insert(object.name.clone(), object);
+        self
+    }
+
+    /// Add an extension
+    pub fn extension<F: Fn() -> E + Send + Sync + 'static, E: Extension>(
+        mut self,
+        extension_factory: F,
+    ) -> Self {
+        self.extensions
+            .push(Box::new(move || Box::new(extension_factory())));
+        self
+    }
+
+    /// Add a global data that can be accessed in the `Schema`, you access it with `Context::data`.
+    pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
+        self.data.insert(data);
+        self
+    }
+
+    /// Build the schema, returns an error if a root object is not registered or a type is not defined.
+    pub fn finish(self) -> std::result::Result<Schema, SchemaError> {
+        for name in std::iter::once(&self.query).chain(&self.mutation) {
+            if !self.objects.contains_key(name) {
+                return Err(SchemaError(format!(
+                    "The root object \"{}\" is not registered.",
+                    name
+                )));
+            }
+        }
+
+        let mut registry = create_registry(self.query.clone(), self.mutation.clone(), None);
+
+        for object in self.objects.values() {
+            if registry.types.contains_key(&object.name) {
+                return Err(SchemaError(format!(
+                    "The type \"{}\" is already defined.",
+                    object.name
+                )));
+            }
+
+            let mut fields = IndexMap::new();
+            for field in object.fields.values() {
+                let mut args = IndexMap::new();
+                for arg in field.args.values() {
+                    args.insert(
+                        arg.name.clone(),
+                        MetaInputValue {
+                            name: arg.name.clone(),
+                            description: arg.description.clone(),
+                            ty: arg.ty.clone(),
+                            default_value: arg.default_value.as_ref().map(ToString::to_string),
+                            validator: None,
+                            visible: None,
+                            directives: Default::default(),
+                            is_secret: false,
+                        },
+                    );
+                }
+                fields.insert(
+                    field.name.clone(),
+                    MetaField {
+                        name: field.name.clone(),
+                        description: field.description.clone(),
+                        args,
+                        ty: field.ty.clone(),
+                        deprecation: None,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        directives: Default::default(),
+                    },
+                );
+            }
+            registry.types.insert(
+                object.name.clone(),
+                MetaType::Object {
+                    name: object.name.clone(),
+                    description: object.description.clone(),
+                    fields,
+                    cache_control: Default::default(),
+                    extends: false,
+                    keys: None,
+                    visible: None,
+                    directives: Default::default(),
+                },
+            );
+        }
+
+        for object in self.objects.values() {
+            for field in object.fields.values() {
+                let type_name = MetaTypeName::concrete_typename(&field.ty);
+                if !registry.types.contains_key(type_name) {
+                    return Err(SchemaError(format!(
+                        "The type \"{}\" of the field \"{}.{}\" is not defined.",
+                        type_name, object.name, field.name
+                    )));
+                }
+                for arg in field.args.values() {
+                    let type_name = MetaTypeName::concrete_typename(&arg.ty);
+                    if !matches!(registry.types.get(type_name), Some(MetaType::Scalar { .. })) {
+                        return Err(SchemaError(format!(
+                            "The type \"{}\" of the argument \"{}.{}({})\" is not an input type.",
+                            type_name, object.name, field.name, arg.name
+                        )));
+                    }
+                }
+            }
+        }
+
+        add_introspection_fields(&mut registry, &self.query);
+
+        Ok(Schema(Arc::new(SchemaInner {
+            env: SchemaEnv(Arc::new(crate::schema::SchemaEnvInner {
+                registry,
+                data: self.data,
+                custom_directives: Default::default(),
+                type_extensions: Default::default(),
+            })),
+            query: self.query,
+            mutation: self.mutation,
+            objects: self.objects,
+            extensions: self.extensions,
+        })))
+    }
+}
+
+struct SchemaInner {
+    env: SchemaEnv,
+    query: String,
+    mutation: Option<String>,
+    objects: IndexMap<String, Object>,
+    extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+}
+
+/// Dynamic schema
+///
+/// Cloning a schema is cheap, so it can be shared between threads.
+#[derive(Clone)]
+pub struct Schema(Arc<SchemaInner>);
+
+impl Schema {
+    /// Create a schema builder with the names of the query object and the mutation object.
+    pub fn build(query: impl Into<String>, mutation: Option<&str>) -> SchemaBuilder {
+        SchemaBuilder {
+            query: query.into(),
+            mutation: mutation.map(ToString::to_string),
+            objects: Default::default(),
+            data: Default::default(),
+            extensions: Default::default(),
+        }
+    }
+
+    /// Returns SDL(Schema Definition Language) of this schema.
+    pub fn sdl(&self) -> String {
+        self.0.env.registry.export_sdl()
+    }
+
+    /// Execute query without create the `QueryBuilder`.
+    pub async fn execute(&self, query_source: &str) -> Result<QueryResponse> {
+        self.execute_query(QueryBuilder::new(query_source)).await
+    }
+
+    /// Execute the query built with a `QueryBuilder`, subscriptions are not supported.
+    pub async fn execute_query(&self, query: QueryBuilder) -> Result<QueryResponse> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(timeout) = query.timeout {
+                return crate::query::execute_with_timeout(self.execute_complete(query), timeout)
+                    .await;
+            }
+        }
+        self.execute_complete(query).await
+    }
+
+    async fn execute_complete(&self, query: QueryBuilder) -> Result<QueryResponse> {
+        let extensions = Extensions(
+            self.0
+                .extensions
+                .iter()
+                .chain(&query.extensions)
+                .map(|factory| factory())
+                .collect_vec(),
+        );
+
+        extensions.parse_start(&query.query_source);
+        let mut document =
+            extensions.log_error(parse_query(&query.query_source).map_err(Into::<Error>::into))?;
+        extensions.parse_end(&query.query_source, &document);
+
+        extensions.validation_start();
+        let CheckResult {
+            cache_control,
+            secret_variables,
+            ..
+        } = extensions.log_error(check_rules(
+            &self.0.env.registry,
+            &document,
+            None,
+            None,
+            ValidationMode::Strict,
+        ))?;
+        extensions.validation_end();
+        extensions.variables(&query.variables.mask_secrets(&secret_variables));
+
+        if !document.retain_operation(query.operation_name.as_deref()) {
+            return extensions.log_error(if let Some(name) = query.operation_name {
+                Err(QueryError::UnknownOperationNamed { name }.into_error(Pos::default()))
+            } else {
+                Err(QueryError::MissingOperation.into_error(Pos::default()))
+            });
+        }
+
+        let env = QueryEnv::new(
+            extensions,
+            query.variables,
+            document,
+            Arc::new(query.ctx_data.unwrap_or_default()),
+        );
+        let inc_resolve_id = AtomicUsize::default();
+        let errors = ResponseErrors::default();
+        let ctx = ContextBase {
+            path_node: None,
+            resolve_id: ResolveId::root(),
+            inc_resolve_id: &inc_resolve_id,
+            item: &env.document.current_operation().selection_set,
+            schema_env: &self.0.env,
+            query_env: &env,
+            defer_list: None,
+            errors: &errors,
+        };
+
+        env.extensions.execution_start();
+
+        let (root, serial) = match (&env.document.current_operation().ty, &self.0.mutation) {
+            (OperationType::Query, _) => (&self.0.objects[&self.0.query], false),
+            (OperationType::Mutation, Some(mutation)) => (&self.0.objects[mutation], true),
+            _ => {
+                return env.extensions.log_error(Err(Error::Query {
+                    pos: Pos::default(),
+                    path: None,
+                    err: QueryError::NotSupported,
+                }))
+            }
+        };
+        let data = env
+            .extensions
+            .log_error(resolve_object(&self.0, &ctx, root, &FieldValue::Null, serial).await)?;
+
+        env.extensions.execution_end();
+
+        Ok(QueryResponse {
+            label: None,
+            path: None,
+            data,
+            errors: errors.take(),
+            extensions: env.extensions.result(),
+            cache_control,
+        })
+    }
+}
+
+type BoxFieldFuture<'a> = BoxFuture<'a, Result<(String, serde_json::Value)>>;
+
+fn resolve_object<'a>(
+    schema: &'a SchemaInner,
+    ctx: &'a ContextSelectionSet<'a>,
+    object: &'a Object,
+    parent: &'a FieldValue,
+    serial: bool,
+) -> BoxFuture<'a, Result<serde_json::Value>> {
+    async move {
+        let mut futures = Vec::new();
+        collect_fields(schema, ctx, object, parent, &mut futures)?;
+
+        let res = if serial {
+            let mut res = Vec::new();
+            for fut in futures {
+                res.push(fut.await?);
+            }
+            res
+        } else {
+            futures::future::try_join_all(futures).await?
+        };
+
+        let mut map = serde_json::Map::new();
+        for (name, value) in res {
+            if let serde_json::Value::Object(b) = value {
+                if let Some(serde_json::Value::Object(a)) = map.get_mut(&name) {
+                    a.extend(b);
+                } else {
+                    map.insert(name, b.into());
+                }
+            } else {
+                map.insert(name, value);
+            }
+        }
+        Ok(map.into())
+    }
+    .boxed()
+}
+
+fn collect_fields<'a>(
+    schema: &'a SchemaInner,
+    ctx: &ContextSelectionSet<'a>,
+    object: &'a Object,
+    parent: &'a FieldValue,
+    futures: &mut Vec<BoxFieldFuture<'a>>,
+) -> Result<()> {
+    for selection in &ctx.item.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                if ctx.is_skip(&field.directives)? {
+                    continue;
+                }
+
+                let ctx = ctx.clone();
+                futures.push(
+                    async move {
+                        let ctx_field = ctx.with_field(field);
+                        let field_name = ctx_field.result_name().to_string();
+                        let value = resolve_field(schema, &ctx_field, object, parent).await?;
+                        Ok((field_name, value))
+                    }
+                    .boxed(),
+                );
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                if ctx.is_skip(&fragment_spread.directives)? {
+                    continue;
+                }
+
+                match ctx
+                    .query_env
+                    .document
+                    .fragments()
+                    .get(fragment_spread.fragment_name.as_str())
+                {
+                    Some(fragment) => {
+                        let TypeCondition::On(name) = &fragment.type_condition.node;
+                        if name.as_str() == object.name {
+                            collect_fields(
+                                schema,
+                                &ctx.with_selection_set(&fragment.selection_set),
+                                object,
+                                parent,
+                                futures,
+                            )?;
+                        }
+                    }
+                    None => {
+                        return Err(Error::Query {
+                            pos: fragment_spread.position(),
+                            path: None,
+                            err: QueryError::UnknownFragment {
+                                name: fragment_spread.fragment_name.to_string(),
+                            },
+                        });
+                    }
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                if ctx.is_skip(&inline_fragment.directives)? {
+                    continue;
+                }
+
+                let matches = match inline_fragment.type_condition.as_deref() {
+                    Some(TypeCondition::On(name)) => name.as_str() == object.name,
+                    None => true,
+                };
+                if matches {
+                    collect_fields(
+                        schema,
+                        &ctx.with_selection_set(&inline_fragment.selection_set),
+                        object,
+                        parent,
+                        futures,
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn resolve_field(
+    schema: &SchemaInner,
+    ctx: &Context<'_>,
+    object: &Object,
+    parent: &FieldValue,
+) -> Result<serde_json::Value> {
+    if ctx.name.node == "__typename" {
+        return Ok(object.name.clone().into());
+    }
+
+    if object.name == schema.query {
+        if let Some(res) = resolve_introspection_field(ctx).await {
+            return res;
+        }
+    }
+
+    let field = match object.fields.get(ctx.name.as_str()) {
+        Some(field) => field,
+        None => {
+            return Err(QueryError::FieldNotFound {
+                field_name: ctx.name.to_string(),
+                object: object.name.clone(),
+            }
+            .into_error(ctx.position()))
+        }
+    };
+
+    let value = (field.resolver)(ResolverContext { ctx, parent, field })
+        .await
+        .map_err(|err| {
+            err.into_error_with_path(ctx.position(), ctx.path_node.as_ref().unwrap().to_json())
+        })?;
+    let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
+    resolve_value(schema, &ctx_obj, ctx.position(), &field.ty, &value).await
+}
+
+fn resolve_value<'a>(
+    schema: &'a SchemaInner,
+    ctx: &'a ContextSelectionSet<'a>,
+    pos: Pos,
+    ty: &'a str,
+    value: &'a FieldValue,
+) -> BoxFuture<'a, Result<serde_json::Value>> {
+    async move {
+        let type_error = |message: &str| Error::Query {
+            pos,
+            path: ctx.path_node.as_ref().map(|path| path.to_json().into()),
+            err: QueryError::FieldError {
+                err: message.to_string(),
+                extended_error: None,
+            },
+        };
+
+        match MetaTypeName::create(ty) {
+            MetaTypeName::NonNull(ty) => {
+                if value.is_null() {
+                    return Err(type_error("The value of a non-null field is null."));
+                }
+                resolve_value(schema, ctx, pos, ty, value).await
+            }
+            _ if value.is_null() => Ok(serde_json::Value::Null),
+            MetaTypeName::List(ty) => {
+                let values;
+                let items = match value {
+                    FieldValue::List(items) => items.iter().collect_vec(),
+                    FieldValue::Value(serde_json::Value::Array(items)) => {
+                        values = items.iter().cloned().map(FieldValue::Value).collect_vec();
+                        values.iter().collect_vec()
+                    }
+                    _ => return Err(type_error("Expected a list value.")),
+                };
+                let mut futures = Vec::new();
+                for (idx, item) in items.into_iter().enumerate() {
+                    futures.push(async move {
+                        let ctx_idx = ctx.with_index(idx);
+                        resolve_value(schema, &ctx_idx, pos, ty, item).await
+                    });
+                }
+                Ok(futures::future::try_join_all(futures).await?.into())
+            }
+            MetaTypeName::Named(ty) => match schema.objects.get(ty) {
+                Some(object) => resolve_object(schema, ctx, object, value, false).await,
+                None => match value {
+                    FieldValue::Value(value) => resolve_scalar(ty, value).ok_or_else(|| {
+                        type_error(&format!("Expected a value of type \"{}\".", ty))
+                    }),
+                    _ => Err(type_error("Expected a scalar value.")),
+                },
+            },
+        }
+    }
+    .boxed()
+}
+
+/// Checks the value returned for a built-in scalar, an integer `ID` is converted to a string.
+fn resolve_scalar(ty: &str, value: &serde_json::Value) -> Option<serde_json::Value> {
+    let is_valid = match ty {
+        "Int" => matches!(
+            value.as_i64(),
+            Some(n) if (i32::MIN as i64..=i32::MAX as i64).contains(&n)
+        ),
+        "Float" => value.is_number(),
+        "String" => value.is_string(),
+        "Boolean" => value.is_boolean(),
+        "ID" if value.is_i64() || value.is_u64() => return Some(value.to_string().into()),
+        "ID" => value.is_string(),
+        _ => true,
+    };
+    if is_valid {
+        Some(value.clone())
+    } else {
+        None
+    }
+}
@@ -303,6 +303,13 @@ pub enum ParseRequestError {
     #[error("Invalid request: {0}")]
     InvalidRequest(serde_json::Error),
 
+    #[error("Invalid query string: {0}")]
+    InvalidQueryString(serde_urlencoded::de::Error),
+
+    #[cfg(feature = "cbor")]
+    #[error("Invalid CBOR request: {0}")]
+    InvalidCborRequest(serde_cbor::Error),
+
     #[error("Invalid files map: {0}")]
     InvalidFilesMap(serde_json::Error),
 
@@ -323,6 +330,12 @@ pub enum ParseRequestError {
 
     #[error("Payload too large")]
     PayloadTooLarge,
+
+    #[error("Too many files")]
+    TooManyFiles,
+
+    #[error("Too many operations in the batch")]
+    BatchTooLarge,
 }
 
 #[allow(missing_docs)]
@@ -332,6 +345,16 @@ pub struct RuleError {
     pub message: String,
 }
 
+/// An error of a field that was resolved with partial data, e.g. a field of a remote service
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerError {
+    pub message: String,
+    pub locations: Vec<Pos>,
+    pub path: Vec<serde_json::Value>,
+    pub extensions: Option<serde_json::Value>,
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Error, PartialEq)]
 pub enum Error {
@@ -347,4 +370,12 @@ pub enum Error {
 
     #[error("Rule error")]
     Rule { errors: Vec<RuleError> },
+
+    /// The errors of a delegated field.
+    #[error("Remote error")]
+    Remote { errors: Vec<ServerError> },
+
+    /// The query took longer than the timeout of the `QueryBuilder`.
+    #[error("Query timeout")]
+    Timeout,
 }
@@ -1,21 +1,57 @@
 use crate::extensions::{Extension, ResolveInfo};
-use crate::Error;
+use crate::{Error, Variables};
 use async_graphql_parser::query::{Definition, Document, OperationDefinition, Selection};
 use itertools::Itertools;
-use std::sync::atomic::{AtomicBool, Ordering};
-use uuid::Uuid;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
 
 /// Logger extension
+///
+/// The variables of the queries are not logged unless enabled with `Logger::with_variables`.
 pub struct Logger {
-    id: Uuid,
+    id: usize,
     enabled: AtomicBool,
+    log_variables: bool,
 }
 
 impl Default for Logger {
     fn default() -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             enabled: AtomicBool::new(true),
+            log_variables: false,
+        }
+    }
+}
+
+impl Logger {
+    /// Also log the variables of the queries.
+    ///
+    /// The values of the variables used by secret arguments are masked, but other variables may
+    /// still contain sensitive data.
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use async_graphql::extensions::Logger;
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[Object]
+    /// impl QueryRoot {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+    ///     .extension(|| Logger::default().with_variables())
+    ///     .finish();
+    /// ```
+    pub fn with_variables(self) -> Self {
+        Self {
+            log_variables: true,
+            ..self
         }
     }
 }
@@ -53,6 +89,13 @@ impl Extension for Logger {
         info!(target: "async-graphql", "query, id: {}, source: \"{}\"", self.id, query_source);
     }
 
+    fn variables(&self, variables: &Variables) {
+        if !self.log_variables || !self.enabled.load(Ordering::Relaxed) || variables.is_empty() {
+            return;
+        }
+        info!(target: "async-graphql", "query variables, id: {}, variables: {}", self.id, variables);
+    }
+
     fn resolve_start(&self, info: &ResolveInfo<'_>) {
         if !self.enabled.load(Ordering::Relaxed) {
             return;
@@ -89,6 +132,14 @@ impl Extension for Logger {
                     error!(target: "async-graphql", "validation error, id: {}, [{}] {}", self.id, locations, error.message)
                 }
             }
+            Error::Remote { errors } => {
+                for error in errors {
+                    error!(target: "async-graphql", "remote error, id: {}, path: {}, {}", self.id, serde_json::Value::from(error.path.clone()), error.message)
+                }
+            }
+            Error::Timeout => {
+                error!(target: "async-graphql", "query timeout, id: {}", self.id)
+            }
         }
     }
 }
@@ -1,12 +1,14 @@
 //! Extensions for schema
 
+#[cfg(feature = "chrono")]
 mod apollo_tracing;
 mod logger;
 mod tracing;
 
 use crate::context::{QueryPathNode, ResolveId};
-use crate::Result;
+use crate::{Result, Variables};
 
+#[cfg(feature = "chrono")]
 pub use self::apollo_tracing::ApolloTracing;
 pub use self::logger::Logger;
 pub use self::tracing::Tracing;
@@ -55,6 +57,11 @@ pub trait Extension: Sync + Send + 'static {
     /// Called at the end of the validation.
     fn validation_end(&self) {}
 
+    /// Called with the variables of the query after the validation.
+    ///
+    /// The values of the variables used by secret arguments (`#[arg(secret)]`) are replaced with `***`.
+    fn variables(&self, variables: &Variables) {}
+
     /// Called at the begin of the execution.
     fn execution_start(&self) {}
 
@@ -104,6 +111,10 @@ impl Extension for Extensions {
         self.0.iter().for_each(|e| e.validation_end());
     }
 
+    fn variables(&self, variables: &Variables) {
+        self.0.iter().for_each(|e| e.variables(variables));
+    }
+
     fn execution_start(&self) {
         self.0.iter().for_each(|e| e.execution_start());
     }
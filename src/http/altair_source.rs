@@ -0,0 +1,64 @@
+/// Generate the page for Altair GraphQL Client
+///
+/// # Example
+///
+/// ```rust
+/// use async_graphql::http::*;
+///
+/// altair_source("http://localhost:8000", Some("ws://localhost:8000"));
+/// ```
+pub fn altair_source(graphql_endpoint_url: &str, subscription_endpoint: Option<&str>) -> String {
+    r#"
+<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset="utf-8">
+    <title>Altair</title>
+    <base href="https://cdn.jsdelivr.net/npm/altair-static@2.4.11/build/dist/">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <link rel="icon" type="image/x-icon" href="favicon.ico">
+    <link href="styles.css" rel="stylesheet" />
+  </head>
+  <body>
+    <app-root>
+      <style>
+        .loading-screen {
+          display: none;
+        }
+      </style>
+      <div class="loading-screen styled">
+        <div class="loading-screen-inner">
+          <div class="loading-screen-logo-container">
+            <img src="assets/img/logo_350.svg" alt="Altair">
+          </div>
+          <div class="loading-screen-loading-indicator">
+            <span class="loading-indicator-dot"></span>
+            <span class="loading-indicator-dot"></span>
+            <span class="loading-indicator-dot"></span>
+          </div>
+        </div>
+      </div>
+    </app-root>
+    <script type="text/javascript" src="polyfills.js"></script>
+    <script type="text/javascript" src="main.js"></script>
+    <script>
+      AltairGraphQL.init({
+        endpointURL: GRAPHQL_URL,
+        subscriptionsEndpoint: GRAPHQL_SUBSCRIPTION_URL,
+      });
+    </script>
+  </body>
+</html>
+    "#
+    .replace(
+        "GRAPHQL_URL",
+        &serde_json::to_string(graphql_endpoint_url).unwrap(),
+    )
+    .replace(
+        "GRAPHQL_SUBSCRIPTION_URL",
+        &match subscription_endpoint {
+            Some(url) => serde_json::to_string(url).unwrap(),
+            None => "undefined".to_string(),
+        },
+    )
+}
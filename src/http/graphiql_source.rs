@@ -1,9 +1,17 @@
 /// Generate the page for GraphIQL
+///
+/// # Example
+///
+/// ```rust
+/// use async_graphql::http::*;
+///
+/// graphiql_source("http://localhost:8000", Some("ws://localhost:8000"));
+/// ```
 pub fn graphiql_source(graphql_endpoint_url: &str, subscription_endpoint: Option<&str>) -> String {
     r#"
     <html>
   <head>
-    <title>Simple GraphiQL Example</title>
+    <title>GraphiQL</title>
     <link href="https://unpkg.com/graphiql/graphiql.min.css" rel="stylesheet" />
   </head>
   <body style="margin: 0;">
@@ -31,8 +39,14 @@ pub fn graphiql_source(graphql_endpoint_url: &str, subscription_endpoint: Option
           headers: { 'Content-Type': 'application/json' },
           body: JSON.stringify(graphQLParams),
         })
-          .then(response => response.json())
-          .catch(() => response.text());
+          .then(response => response.text())
+          .then(body => {
+            try {
+              return JSON.parse(body);
+            } catch (error) {
+              return body;
+            }
+          });
 
       var subscription_url = GRAPHQL_SUBSCRIPTION_URL;
 
@@ -0,0 +1,154 @@
+use crate::http::{
+    parse_query_string, receive_batch_request, receive_request, GQLBatchResponse, GQLRequest,
+    GQLResponse, StreamBody,
+};
+use crate::query::{IntoBatchQueryBuilder, IntoQueryBuilder, IntoQueryBuilderOpts};
+use crate::{
+    BatchQueryBuilder, BatchQueryResponse, ObjectType, ParseRequestError, QueryBuilder,
+    QueryResponse, Schema, SubscriptionType,
+};
+use bytes::{Buf, Bytes};
+use futures::Stream;
+use http::header::{HeaderValue, CACHE_CONTROL, CONTENT_TYPE};
+use http::{Method, Request, Response, StatusCode};
+use std::convert::Infallible;
+
+#[async_trait::async_trait]
+impl<B, D, E> IntoQueryBuilder for Request<B>
+where
+    B: Stream<Item = std::result::Result<D, E>> + Send + Unpin + 'static,
+    D: Buf + Send + 'static,
+    E: Send + 'static,
+{
+    async fn into_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<QueryBuilder, ParseRequestError> {
+        if self.method() == Method::GET {
+            query_string_request(&self)?
+                .into_query_builder_opts(opts)
+                .await
+        } else {
+            receive_request(content_type(&self), StreamBody::new(self.into_body()), opts).await
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<B, D, E> IntoBatchQueryBuilder for Request<B>
+where
+    B: Stream<Item = std::result::Result<D, E>> + Send + Unpin + 'static,
+    D: Buf + Send + 'static,
+    E: Send + 'static,
+{
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        if self.method() == Method::GET {
+            Ok(BatchQueryBuilder::Single(
+                query_string_request(&self)?
+                    .into_query_builder_opts(opts)
+                    .await?,
+            ))
+        } else {
+            receive_batch_request(content_type(&self), StreamBody::new(self.into_body()), opts)
+                .await
+        }
+    }
+}
+
+fn content_type<B>(req: &Request<B>) -> Option<String> {
+    req.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
+
+fn query_string_request<B>(req: &Request<B>) -> std::result::Result<GQLRequest, ParseRequestError> {
+    parse_query_string(req.uri().query().unwrap_or_default())
+}
+
+impl<B: From<String>> From<GQLResponse> for Response<B> {
+    fn from(resp: GQLResponse) -> Self {
+        let cache_control = match &resp.0 {
+            Ok(QueryResponse { cache_control, .. }) => cache_control.value(),
+            Err(_) => Some("no-store".to_string()),
+        };
+        let mut http_resp = Response::new(B::from(serde_json::to_string(&resp).unwrap()));
+        http_resp
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Some(value) = cache_control.and_then(|value| HeaderValue::from_str(&value).ok()) {
+            http_resp.headers_mut().insert(CACHE_CONTROL, value);
+        }
+        http_resp
+    }
+}
+
+impl<B: From<String>> From<GQLBatchResponse> for Response<B> {
+    fn from(resp: GQLBatchResponse) -> Self {
+        match resp.0 {
+            BatchQueryResponse::Single(resp) => GQLResponse(resp).into(),
+            resp => {
+                let mut http_resp = Response::new(B::from(
+                    serde_json::to_string(&GQLBatchResponse(resp)).unwrap(),
+                ));
+                http_resp
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                http_resp
+            }
+        }
+    }
+}
+
+/// Execute a GraphQL request in an `http::Request` with a complete body, such as `Request<Bytes>`
+/// or `Request<Vec<u8>>`.
+///
+/// It is meant for platforms that pass requests and responses as `http` types, such as serverless
+/// runtimes. Batch requests are supported. Requests that cannot be parsed get a plain text
+/// `400 Bad Request` (or `413 Payload Too Large`) response.
+pub async fn execute_http_request<Query, Mutation, Subscription, B>(
+    schema: &Schema<Query, Mutation, Subscription>,
+    req: Request<B>,
+) -> Response<Bytes>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    B: Into<Bytes>,
+{
+    execute_http_request_opts(schema, req, &Default::default()).await
+}
+
+/// Similar to `execute_http_request`, but you can set the options `IntoQueryBuilderOpts`.
+pub async fn execute_http_request_opts<Query, Mutation, Subscription, B>(
+    schema: &Schema<Query, Mutation, Subscription>,
+    req: Request<B>,
+    opts: &IntoQueryBuilderOpts,
+) -> Response<Bytes>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    B: Into<Bytes>,
+{
+    let req = req.map(|body| futures::stream::iter(Some(Ok::<_, Infallible>(body.into()))));
+    match req.into_batch_query_builder_opts(opts).await {
+        Ok(builder) => GQLBatchResponse(builder.execute(schema).await).into(),
+        Err(err) => parse_request_error_response(err),
+    }
+}
+
+pub(crate) fn parse_request_error_response<B: From<String>>(err: ParseRequestError) -> Response<B> {
+    let status = match err {
+        ParseRequestError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        _ => StatusCode::BAD_REQUEST,
+    };
+    let mut resp = Response::new(B::from(err.to_string()));
+    *resp.status_mut() = status;
+    resp.headers_mut()
+        .insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    resp
+}
@@ -1,19 +1,31 @@
-use crate::http::GQLRequest;
-use crate::query::{IntoQueryBuilder, IntoQueryBuilderOpts};
-use crate::{ParseRequestError, QueryBuilder};
+use crate::http::{GQLBatchRequest, GQLRequest};
+use crate::query::{IntoBatchQueryBuilder, IntoQueryBuilder, IntoQueryBuilderOpts};
+use crate::{BatchQueryBuilder, ParseRequestError, QueryBuilder};
+use async_graphql_parser::UploadContent;
 use bytes::Bytes;
 use futures::{AsyncRead, AsyncReadExt, Stream};
 use mime::Mime;
 use multer::{Constraints, Multipart, SizeLimit};
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::io::{Seek, SeekFrom, Write};
 
+/// Room for the part headers of a multipart request, on top of the limits of the parts.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
 impl From<multer::Error> for ParseRequestError {
     fn from(err: multer::Error) -> Self {
         match err {
             multer::Error::FieldSizeExceeded { .. } | multer::Error::StreamSizeExceeded { .. } => {
                 ParseRequestError::PayloadTooLarge
             }
+            // The limit of the whole stream is reported as an error of reading the stream.
+            multer::Error::StreamReadFailed(err) => match err.downcast::<multer::Error>() {
+                Ok(err) => (*err).into(),
+                Err(err) => {
+                    ParseRequestError::InvalidMultipart(multer::Error::StreamReadFailed(err))
+                }
+            },
             _ => ParseRequestError::InvalidMultipart(err),
         }
     }
@@ -26,116 +38,272 @@ where
     Body: AsyncRead + Send + Unpin + 'static,
 {
     async fn into_query_builder_opts(
-        mut self,
+        self,
         opts: &IntoQueryBuilderOpts,
     ) -> std::result::Result<QueryBuilder, ParseRequestError> {
-        if let Some(boundary) = self
-            .0
-            .and_then(|value| value.as_ref().parse::<Mime>().ok())
-            .and_then(|ct| {
-                if ct.essence_str() == mime::MULTIPART_FORM_DATA {
-                    ct.get_param("boundary")
-                        .map(|boundary| boundary.to_string())
-                } else {
-                    None
-                }
-            })
-        {
-            // multipart
-            let stream = reader_stream(self.1);
-
-            let mut multipart = Multipart::new_with_constraints(
-                stream,
-                boundary,
-                Constraints::new().size_limit({
-                    let mut limit = SizeLimit::new();
-                    if let (Some(max_file_size), Some(max_num_files)) =
-                        (opts.max_file_size, opts.max_file_size)
-                    {
-                        limit = limit.whole_stream((max_file_size * max_num_files) as u64);
-                    }
-                    if let Some(max_file_size) = opts.max_file_size {
-                        limit = limit.per_field(max_file_size as u64);
-                    }
-                    limit
-                }),
-            );
-
-            let mut builder = None;
-            let mut map = None;
-            let mut files = Vec::new();
-
-            while let Some(mut field) = multipart.next_field().await? {
-                match field.name() {
-                    Some("operations") => {
-                        let request_str = field.text().await?;
-                        let request: GQLRequest = serde_json::from_str(&request_str)
-                            .map_err(ParseRequestError::InvalidRequest)?;
-                        builder = Some(request.into_query_builder().await?);
-                    }
-                    Some("map") => {
-                        let map_str = field.text().await?;
-                        map = Some(
-                            serde_json::from_str::<HashMap<String, Vec<String>>>(&map_str)
-                                .map_err(ParseRequestError::InvalidFilesMap)?,
-                        );
-                    }
-                    _ => {
-                        if let Some(name) = field.name().map(ToString::to_string) {
-                            if let Some(filename) = field.file_name().map(ToString::to_string) {
-                                let content_type =
-                                    field.content_type().map(|mime| mime.to_string());
-                                let mut file =
-                                    tempfile::tempfile().map_err(ParseRequestError::Io)?;
-                                while let Some(chunk) = field.chunk().await.unwrap() {
-                                    file.write(&chunk).map_err(ParseRequestError::Io)?;
+        receive_request(self.0, self.1, opts).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<CT, Body> IntoBatchQueryBuilder for (Option<CT>, Body)
+where
+    CT: AsRef<str> + Send,
+    Body: AsyncRead + Send + Unpin + 'static,
+{
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        receive_batch_request(self.0, self.1, opts).await
+    }
+}
+
+/// Receive a GraphQL request from a content type and a body.
+///
+/// The body is parsed as a multipart request if the content type is `multipart/form-data`, as CBOR
+/// if it is `application/cbor` and the `cbor` feature is enabled, otherwise as JSON. This is what
+/// the integrations for web frameworks use to read requests, so a new integration only needs to
+/// provide the body as `AsyncRead` (see `StreamBody` for byte streams).
+pub async fn receive_request(
+    content_type: Option<impl AsRef<str>>,
+    body: impl AsyncRead + Send + Unpin + 'static,
+    opts: &IntoQueryBuilderOpts,
+) -> std::result::Result<QueryBuilder, ParseRequestError> {
+    let content_type = parse_content_type(content_type);
+    if let Some(boundary) = multipart_boundary(content_type.as_ref()) {
+        receive_multipart(body, boundary, opts).await
+    } else {
+        let data = read_body(body, opts).await?;
+        let gql_request: GQLRequest = deserialize_body(content_type.as_ref(), &data)?;
+        gql_request.into_query_builder_opts(opts).await
+    }
+}
+
+/// Receive a GraphQL request or a batch of requests from a content type and a body.
+///
+/// Multipart requests are always single requests.
+pub async fn receive_batch_request(
+    content_type: Option<impl AsRef<str>>,
+    body: impl AsyncRead + Send + Unpin + 'static,
+    opts: &IntoQueryBuilderOpts,
+) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+    let content_type = parse_content_type(content_type);
+    if let Some(boundary) = multipart_boundary(content_type.as_ref()) {
+        Ok(BatchQueryBuilder::Single(
+            receive_multipart(body, boundary, opts).await?,
+        ))
+    } else {
+        let data = read_body(body, opts).await?;
+        let gql_request: GQLBatchRequest = deserialize_body(content_type.as_ref(), &data)?;
+        gql_request.into_batch_query_builder_opts(opts).await
+    }
+}
+
+/// Parse a GraphQL request from the query string of a `GET` request.
+///
+/// `variables` is a JSON encoded string.
+pub fn parse_query_string(query: &str) -> std::result::Result<GQLRequest, ParseRequestError> {
+    let params: HashMap<String, String> =
+        serde_urlencoded::from_str(query).map_err(ParseRequestError::InvalidQueryString)?;
+    serde_json::to_value(params)
+        .and_then(serde_json::from_value)
+        .map_err(ParseRequestError::InvalidRequest)
+}
+
+async fn read_body(
+    body: impl AsyncRead + Send + Unpin,
+    opts: &IntoQueryBuilderOpts,
+) -> std::result::Result<Vec<u8>, ParseRequestError> {
+    let mut data = Vec::new();
+    match opts.max_request_size {
+        Some(max_request_size) => {
+            body.take(max_request_size as u64 + 1)
+                .read_to_end(&mut data)
+                .await
+                .map_err(ParseRequestError::Io)?;
+            if data.len() > max_request_size {
+                return Err(ParseRequestError::PayloadTooLarge);
+            }
+        }
+        None => {
+            let mut body = body;
+            body.read_to_end(&mut data)
+                .await
+                .map_err(ParseRequestError::Io)?;
+        }
+    }
+    Ok(data)
+}
+
+fn parse_content_type(content_type: Option<impl AsRef<str>>) -> Option<Mime> {
+    content_type.and_then(|value| value.as_ref().parse::<Mime>().ok())
+}
+
+#[cfg_attr(not(feature = "cbor"), allow(unused_variables))]
+fn deserialize_body<T: DeserializeOwned>(
+    content_type: Option<&Mime>,
+    data: &[u8],
+) -> std::result::Result<T, ParseRequestError> {
+    #[cfg(feature = "cbor")]
+    {
+        if content_type.map(|ct| ct.essence_str()) == Some("application/cbor") {
+            return serde_cbor::from_slice(data).map_err(ParseRequestError::InvalidCborRequest);
+        }
+    }
+    serde_json::from_slice(data).map_err(ParseRequestError::InvalidRequest)
+}
+
+fn multipart_boundary(content_type: Option<&Mime>) -> Option<String> {
+    content_type.and_then(|ct| {
+        if ct.essence_str() == mime::MULTIPART_FORM_DATA {
+            ct.get_param("boundary")
+                .map(|boundary| boundary.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+async fn receive_multipart(
+    body: impl AsyncRead + Send + Unpin + 'static,
+    boundary: String,
+    opts: &IntoQueryBuilderOpts,
+) -> std::result::Result<QueryBuilder, ParseRequestError> {
+    let stream = reader_stream(body);
+
+    let mut multipart = Multipart::new_with_constraints(
+        stream,
+        boundary,
+        Constraints::new().size_limit({
+            let mut limit = SizeLimit::new();
+            if let Some(max_multipart_size) = max_multipart_size(opts) {
+                limit = limit.whole_stream(max_multipart_size as u64);
+            }
+            if let Some(max_request_size) = opts.max_request_size {
+                limit = limit
+                    .for_field("operations", max_request_size as u64)
+                    .for_field("map", max_request_size as u64);
+            }
+            limit
+        }),
+    );
+
+    let mut builder = None;
+    let mut map = None;
+    let mut files = Vec::new();
+
+    while let Some(mut field) = multipart.next_field().await? {
+        match field.name() {
+            Some("operations") => {
+                let request_str = field.text().await?;
+                let request: GQLRequest = serde_json::from_str(&request_str)
+                    .map_err(ParseRequestError::InvalidRequest)?;
+                builder = Some(request.into_query_builder().await?);
+            }
+            Some("map") => {
+                let map_str = field.text().await?;
+                map = Some(
+                    serde_json::from_str::<HashMap<String, Vec<String>>>(&map_str)
+                        .map_err(ParseRequestError::InvalidFilesMap)?,
+                );
+            }
+            _ => {
+                if let Some(name) = field.name().map(ToString::to_string) {
+                    if let Some(filename) = field.file_name().map(ToString::to_string) {
+                        let content_type = field.content_type().map(|mime| mime.to_string());
+                        if let Some(max_num_files) = opts.max_num_files {
+                            if files.len() >= max_num_files {
+                                return Err(ParseRequestError::TooManyFiles);
+                            }
+                        }
+                        let mut data = Vec::new();
+                        let mut file = match opts.file_spill_threshold {
+                            Some(_) => None,
+                            None => Some(tempfile::tempfile().map_err(ParseRequestError::Io)?),
+                        };
+                        let mut size = 0;
+                        while let Some(chunk) = field.chunk().await? {
+                            size += chunk.len();
+                            if let Some(max_file_size) = opts.max_file_size {
+                                if size > max_file_size {
+                                    return Err(ParseRequestError::PayloadTooLarge);
+                                }
+                            }
+                            match &mut file {
+                                Some(file) => {
+                                    file.write_all(&chunk).map_err(ParseRequestError::Io)?
+                                }
+                                None => {
+                                    data.extend_from_slice(&chunk);
+                                    if matches!(opts.file_spill_threshold, Some(threshold) if data.len() > threshold)
+                                    {
+                                        let mut spilled =
+                                            tempfile::tempfile().map_err(ParseRequestError::Io)?;
+                                        spilled.write_all(&data).map_err(ParseRequestError::Io)?;
+                                        data = Vec::new();
+                                        file = Some(spilled);
+                                    }
                                 }
-                                file.seek(SeekFrom::Start(0))?;
-                                files.push((name, filename, content_type, file));
                             }
                         }
+                        let content = match file {
+                            Some(mut file) => {
+                                file.seek(SeekFrom::Start(0))?;
+                                UploadContent::File(file)
+                            }
+                            None => UploadContent::Memory(data.into()),
+                        };
+                        files.push((name, filename, content_type, content));
                     }
                 }
             }
+        }
+    }
 
-            let mut builder = match builder {
-                Some(builder) => builder,
-                None => return Err(ParseRequestError::MissingOperatorsPart),
-            };
-            let map = match &mut map {
-                Some(map) => map,
-                None => return Err(ParseRequestError::MissingMapPart),
-            };
-
-            for (name, filename, content_type, file) in files {
-                if let Some(var_paths) = map.remove(&name) {
-                    for var_path in var_paths {
-                        builder.set_upload(
-                            &var_path,
-                            filename.clone(),
-                            content_type.clone(),
-                            file.try_clone().unwrap(),
-                        );
-                    }
-                }
-            }
+    let mut builder = match builder {
+        Some(builder) => builder,
+        None => return Err(ParseRequestError::MissingOperatorsPart),
+    };
+    let map = match &mut map {
+        Some(map) => map,
+        None => return Err(ParseRequestError::MissingMapPart),
+    };
 
-            if !map.is_empty() {
-                return Err(ParseRequestError::MissingFiles);
+    for (name, filename, content_type, content) in files {
+        if let Some(var_paths) = map.remove(&name) {
+            for var_path in var_paths {
+                builder.set_upload_content(
+                    &var_path,
+                    filename.clone(),
+                    content_type.clone(),
+                    content.clone(),
+                );
             }
-
-            Ok(builder)
-        } else {
-            let mut data = Vec::new();
-            self.1
-                .read_to_end(&mut data)
-                .await
-                .map_err(ParseRequestError::Io)?;
-            let gql_request: GQLRequest =
-                serde_json::from_slice(&data).map_err(ParseRequestError::InvalidRequest)?;
-            gql_request.into_query_builder().await
         }
     }
+
+    if !map.is_empty() {
+        return Err(ParseRequestError::MissingFiles);
+    }
+
+    Ok(builder)
+}
+
+fn max_multipart_size(opts: &IntoQueryBuilderOpts) -> Option<usize> {
+    match (
+        opts.max_file_size,
+        opts.max_num_files,
+        opts.max_request_size,
+    ) {
+        (Some(max_file_size), Some(max_num_files), Some(max_request_size)) => Some(
+            max_file_size
+                .saturating_mul(max_num_files)
+                .saturating_add(max_request_size.saturating_mul(2))
+                .saturating_add(MULTIPART_OVERHEAD),
+        ),
+        _ => None,
+    }
 }
 
 fn reader_stream(
@@ -1,24 +1,42 @@
 //! A helper module that supports HTTP
+//!
+//! `http::Request<B>`, whose body is a stream of bytes such as `hyper::Body`, implements
+//! `IntoQueryBuilder` and `IntoBatchQueryBuilder`, and `GQLResponse` and `GQLBatchResponse` can be
+//! converted into `http::Response<B>`, so it can be used directly with hyper.
+//! `execute_http_request` executes requests with a complete body, such as `http::Request<Bytes>`.
+//! With the `tower` feature, `GraphQLService` wraps a schema as a tower service.
+//! With the `cbor` feature, request bodies with the content type `application/cbor` are read as CBOR.
 
+mod altair_source;
 mod graphiql_source;
+mod http_request;
 mod into_query_builder;
 mod multipart_stream;
 mod playground_source;
 mod stream_body;
+#[cfg(feature = "tower")]
+mod tower_service;
 
 use itertools::Itertools;
 
+pub use altair_source::altair_source;
 pub use graphiql_source::graphiql_source;
+pub use http_request::{execute_http_request, execute_http_request_opts};
+pub use into_query_builder::{parse_query_string, receive_batch_request, receive_request};
 pub use multipart_stream::multipart_stream;
-pub use playground_source::{playground_source, GraphQLPlaygroundConfig};
+pub use playground_source::{playground_source, GraphQLPlaygroundConfig, GraphQLPlaygroundTab};
 pub use stream_body::StreamBody;
+#[cfg(feature = "tower")]
+pub use tower_service::GraphQLService;
 
-use crate::query::{IntoQueryBuilder, IntoQueryBuilderOpts};
+use crate::query::{IntoBatchQueryBuilder, IntoQueryBuilder, IntoQueryBuilderOpts};
 use crate::{
-    Error, ParseRequestError, Pos, QueryBuilder, QueryError, QueryResponse, Result, Variables,
+    BatchQueryBuilder, BatchQueryResponse, Error, ParseRequestError, Pos, QueryBuilder, QueryError,
+    QueryResponse, Result, ServerError, Variables,
 };
+use serde::de::Error as _;
 use serde::ser::{SerializeMap, SerializeSeq};
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Deserializable GraphQL Request object
 #[derive(Deserialize, Clone, PartialEq, Debug)]
@@ -31,9 +49,25 @@ pub struct GQLRequest {
     pub operation_name: Option<String>,
 
     /// Variables for this query
+    ///
+    /// For GET requests, variables are passed in the query string as a JSON encoded string,
+    /// so a string value is decoded as JSON, and an empty string means no variables.
+    #[serde(default, deserialize_with = "deserialize_variables")]
     pub variables: Option<serde_json::Value>,
 }
 
+fn deserialize_variables<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<serde_json::Value>, D::Error> {
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        Some(serde_json::Value::String(s)) if s.is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => {
+            serde_json::from_str(&s).map(Some).map_err(D::Error::custom)
+        }
+        value => Ok(value),
+    }
+}
+
 #[async_trait::async_trait]
 impl IntoQueryBuilder for GQLRequest {
     async fn into_query_builder_opts(
@@ -53,12 +87,72 @@ impl IntoQueryBuilder for GQLRequest {
     }
 }
 
+/// Deserializable GraphQL batch request object
+///
+/// It's a single `GQLRequest` or a list of `GQLRequest`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum GQLBatchRequest {
+    /// Single request
+    Single(GQLRequest),
+
+    /// Batch requests
+    Batch(Vec<GQLRequest>),
+}
+
+impl<'de> Deserialize<'de> for GQLBatchRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        // Branch on the shape of the value, so the error of the request itself is reported.
+        match serde_json::Value::deserialize(deserializer)? {
+            value @ serde_json::Value::Array(_) => serde_json::from_value(value)
+                .map(GQLBatchRequest::Batch)
+                .map_err(D::Error::custom),
+            value => serde_json::from_value(value)
+                .map(GQLBatchRequest::Single)
+                .map_err(D::Error::custom),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IntoBatchQueryBuilder for GQLBatchRequest {
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        match self {
+            GQLBatchRequest::Single(request) => Ok(BatchQueryBuilder::Single(
+                request.into_query_builder_opts(opts).await?,
+            )),
+            GQLBatchRequest::Batch(requests) => {
+                if let Some(max_batch_size) = opts.max_batch_size {
+                    if requests.len() > max_batch_size {
+                        return Err(ParseRequestError::BatchTooLarge);
+                    }
+                }
+                let mut builders = Vec::with_capacity(requests.len());
+                for request in requests {
+                    builders.push(request.into_query_builder_opts(opts).await?);
+                }
+                Ok(BatchQueryBuilder::Batch(builders))
+            }
+        }
+    }
+}
+
 /// Serializable GraphQL Response object
 pub struct GQLResponse(pub Result<QueryResponse>);
 
 impl Serialize for GQLResponse {
     fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
-        match &self.0 {
+        GQLResponseRef(&self.0).serialize(serializer)
+    }
+}
+
+pub(crate) struct GQLResponseRef<'a>(pub(crate) &'a Result<QueryResponse>);
+
+impl<'a> Serialize for GQLResponseRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.0 {
             Ok(res) => {
                 let mut map = serializer.serialize_map(None)?;
                 if let Some(label) = &res.label {
@@ -71,6 +165,10 @@ impl Serialize for GQLResponse {
                 }
                 map.serialize_key("data")?;
                 map.serialize_value(&res.data)?;
+                if !res.errors.is_empty() {
+                    map.serialize_key("errors")?;
+                    map.serialize_value(&res.errors.iter().map(GQLServerError).collect_vec())?;
+                }
                 if res.extensions.is_some() {
                     map.serialize_key("extensions")?;
                     map.serialize_value(&res.extensions)?;
@@ -87,6 +185,24 @@ impl Serialize for GQLResponse {
     }
 }
 
+/// Serializable GraphQL batch response object
+pub struct GQLBatchResponse(pub BatchQueryResponse);
+
+impl Serialize for GQLBatchResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match &self.0 {
+            BatchQueryResponse::Single(res) => GQLResponseRef(res).serialize(serializer),
+            BatchQueryResponse::Batch(responses) => {
+                let mut seq = serializer.serialize_seq(Some(responses.len()))?;
+                for res in responses {
+                    seq.serialize_element(&GQLResponseRef(res))?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
 /// Serializable error type
 pub struct GQLError<'a>(pub &'a Error);
 
@@ -146,6 +262,21 @@ impl<'a> Serialize for GQLError<'a> {
                 }
                 seq.end()
             }
+            Error::Remote { errors } => {
+                let mut seq = serializer.serialize_seq(Some(errors.len()))?;
+                for error in errors {
+                    seq.serialize_element(&GQLServerError(error))?;
+                }
+                seq.end()
+            }
+            Error::Timeout => {
+                let mut seq = serializer.serialize_seq(Some(1))?;
+                seq.serialize_element(&serde_json::json!({
+                    "message": self.0.to_string(),
+                    "extensions": { "code": "TIMEOUT" },
+                }))?;
+                seq.end()
+            }
         }
     }
 }
@@ -164,6 +295,27 @@ impl<'a> Serialize for GQLErrorPos<'a> {
     }
 }
 
+struct GQLServerError<'a>(&'a ServerError);
+
+impl<'a> Serialize for GQLServerError<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("message", &self.0.message)?;
+        map.serialize_entry(
+            "locations",
+            &self.0.locations.iter().map(GQLErrorPos).collect_vec(),
+        )?;
+        map.serialize_entry("path", &self.0.path)?;
+        if let Some(extensions) = &self.0.extensions {
+            map.serialize_entry("extensions", extensions)?;
+        }
+        map.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,12 +368,98 @@ mod tests {
         assert_eq!(request.query, "{ a b c }");
     }
 
+    #[test]
+    fn test_request_with_encoded_variables() {
+        let request: GQLRequest = serde_json::from_value(json! ({
+            "query": "{ a b c }",
+            "operationName": "a",
+            "variables": r#"{"v1": 100, "v2": [1, 2, 3]}"#,
+        }))
+        .unwrap();
+        assert_eq!(
+            request.variables,
+            Some(json!({
+                "v1": 100,
+                "v2": [1, 2, 3],
+            }))
+        );
+        assert_eq!(request.operation_name.as_deref(), Some("a"));
+        assert_eq!(request.query, "{ a b c }");
+    }
+
+    #[test]
+    fn test_request_with_empty_encoded_variables() {
+        let request: GQLRequest =
+            serde_urlencoded::from_str("query=%7B%20a%20b%20c%20%7D&variables=").unwrap();
+        assert!(request.variables.is_none());
+        assert_eq!(request.query, "{ a b c }");
+    }
+
+    #[test]
+    fn test_batch_request() {
+        let request: GQLBatchRequest = serde_json::from_value(json! ({
+            "query": "{ a b c }"
+        }))
+        .unwrap();
+        assert!(matches!(request, GQLBatchRequest::Single(_)));
+
+        let request: GQLBatchRequest = serde_json::from_value(json! ([
+            { "query": "{ a }" },
+            { "query": "{ b }", "operationName": "b" },
+        ]))
+        .unwrap();
+        if let GQLBatchRequest::Batch(requests) = request {
+            assert_eq!(requests.len(), 2);
+            assert_eq!(requests[0].query, "{ a }");
+            assert_eq!(requests[1].operation_name.as_deref(), Some("b"));
+        } else {
+            panic!("expected a batch request");
+        }
+    }
+
+    #[test]
+    fn test_batch_response() {
+        let resp = GQLBatchResponse(BatchQueryResponse::Batch(vec![
+            Ok(QueryResponse {
+                label: None,
+                path: None,
+                data: json!({"ok": true}),
+                errors: Vec::new(),
+                extensions: None,
+                cache_control: Default::default(),
+            }),
+            Err(Error::Query {
+                pos: Pos {
+                    line: 10,
+                    column: 20,
+                },
+                path: None,
+                err: QueryError::NotSupported,
+            }),
+        ]));
+        assert_eq!(
+            serde_json::to_value(resp).unwrap(),
+            json!([
+                { "data": { "ok": true } },
+                {
+                    "errors": [{
+                        "message":"Not supported.",
+                        "locations": [
+                            {"line": 10, "column": 20}
+                        ]
+                    }]
+                }
+            ])
+        );
+    }
+
     #[test]
     fn test_response_data() {
         let resp = GQLResponse(Ok(QueryResponse {
             label: None,
             path: None,
             data: json!({"ok": true}),
+            errors: Vec::new(),
             extensions: None,
             cache_control: Default::default(),
         }));
@@ -235,6 +473,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_response_partial_data() {
+        let resp = GQLResponse(Ok(QueryResponse {
+            label: None,
+            path: None,
+            data: json!({"user": {"name": "jack", "age": null}}),
+            errors: vec![ServerError {
+                message: "forbidden".to_string(),
+                locations: vec![Pos { line: 1, column: 9 }],
+                path: vec![json!("user"), json!("age")],
+                extensions: None,
+            }],
+            extensions: None,
+            cache_control: Default::default(),
+        }));
+        assert_eq!(
+            serde_json::to_value(resp).unwrap(),
+            json! ({
+                "data": {
+                    "user": { "name": "jack", "age": null },
+                },
+                "errors": [{
+                    "message": "forbidden",
+                    "locations": [{"line": 1, "column": 9}],
+                    "path": ["user", "age"],
+                }],
+            })
+        );
+    }
+
     #[test]
     fn test_field_error_with_extension() {
         let err = Error::Query {
@@ -556,12 +556,30 @@ pub fn playground_source(config: GraphQLPlaygroundConfig) -> String {
 }
 
 /// Config for GraphQL Playground
+///
+/// # Example
+///
+/// ```rust
+/// use async_graphql::http::*;
+///
+/// playground_source(
+///     GraphQLPlaygroundConfig::new("http://localhost:8000")
+///         .with_header("Authorization", "Bearer 123456")
+///         .with_setting("editor.theme", "light")
+///         .with_setting("request.credentials", "include")
+///         .with_tab(GraphQLPlaygroundTab::new("http://localhost:8000", "{ hello }").name("Hello")),
+/// );
+/// ```
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GraphQLPlaygroundConfig<'a> {
     endpoint: &'a str,
     subscription_endpoint: Option<&'a str>,
     headers: Option<HashMap<&'a str, &'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<HashMap<&'a str, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tabs: Option<Vec<GraphQLPlaygroundTab<'a>>>,
 }
 
 impl<'a> GraphQLPlaygroundConfig<'a> {
@@ -571,6 +589,8 @@ impl<'a> GraphQLPlaygroundConfig<'a> {
             endpoint,
             subscription_endpoint: None,
             headers: Default::default(),
+            settings: Default::default(),
+            tabs: Default::default(),
         }
     }
 
@@ -591,4 +611,67 @@ impl<'a> GraphQLPlaygroundConfig<'a> {
         }
         self
     }
+
+    /// Set a playground setting, such as `editor.theme` or `request.credentials`.
+    ///
+    /// **Reference:** <https://github.com/prisma-labs/graphql-playground#settings>
+    pub fn with_setting(mut self, name: &'a str, value: impl Into<serde_json::Value>) -> Self {
+        self.settings
+            .get_or_insert_with(HashMap::new)
+            .insert(name, value.into());
+        self
+    }
+
+    /// Add a preset tab.
+    pub fn with_tab(mut self, tab: GraphQLPlaygroundTab<'a>) -> Self {
+        self.tabs.get_or_insert_with(Vec::new).push(tab);
+        self
+    }
+}
+
+/// Preset tab for GraphQL Playground
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLPlaygroundTab<'a> {
+    endpoint: &'a str,
+    query: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variables: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    headers: Option<HashMap<&'a str, &'a str>>,
+}
+
+impl<'a> GraphQLPlaygroundTab<'a> {
+    /// Create a tab with the endpoint and the query.
+    pub fn new(endpoint: &'a str, query: &'a str) -> Self {
+        Self {
+            endpoint,
+            query,
+            name: None,
+            variables: None,
+            headers: None,
+        }
+    }
+
+    /// Set the tab name.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the variables, it's a JSON string.
+    pub fn variables(mut self, variables: &'a str) -> Self {
+        self.variables = Some(variables);
+        self
+    }
+
+    /// Set HTTP header for the tab.
+    pub fn with_header(mut self, name: &'a str, value: &'a str) -> Self {
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(name, value);
+        self
+    }
 }
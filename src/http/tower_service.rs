@@ -0,0 +1,78 @@
+use crate::http::http_request::parse_request_error_response;
+use crate::http::GQLBatchResponse;
+use crate::query::{IntoBatchQueryBuilder, IntoQueryBuilderOpts};
+use crate::{ObjectType, Schema, SubscriptionType};
+use bytes::Buf;
+use futures::{Future, Stream};
+use http::{Request, Response};
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower_service::Service;
+
+/// Tower service for GraphQL queries and mutations
+///
+/// It implements `tower_service::Service<http::Request<B>>` for request bodies that are streams of
+/// bytes, such as `hyper::Body`, so it can be composed with tower middleware and mounted in any
+/// tower-compatible server. Batch requests are supported.
+///
+/// Requests that cannot be parsed get a plain text `400 Bad Request` (or `413 Payload Too Large`)
+/// response.
+pub struct GraphQLService<Query, Mutation, Subscription> {
+    schema: Schema<Query, Mutation, Subscription>,
+    opts: IntoQueryBuilderOpts,
+}
+
+impl<Query, Mutation, Subscription> Clone for GraphQLService<Query, Mutation, Subscription> {
+    fn clone(&self) -> Self {
+        GraphQLService {
+            schema: self.schema.clone(),
+            opts: self.opts.clone(),
+        }
+    }
+}
+
+impl<Query, Mutation, Subscription> GraphQLService<Query, Mutation, Subscription> {
+    /// Create a service for the schema.
+    pub fn new(schema: Schema<Query, Mutation, Subscription>) -> Self {
+        GraphQLService {
+            schema,
+            opts: Default::default(),
+        }
+    }
+
+    /// Set the options `IntoQueryBuilderOpts`.
+    pub fn opts(self, opts: IntoQueryBuilderOpts) -> Self {
+        GraphQLService { opts, ..self }
+    }
+}
+
+impl<Query, Mutation, Subscription, B, D, E> Service<Request<B>>
+    for GraphQLService<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    B: Stream<Item = std::result::Result<D, E>> + Send + Unpin + 'static,
+    D: Buf + Send + 'static,
+    E: Send + 'static,
+{
+    type Response = Response<String>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let schema = self.schema.clone();
+        let opts = self.opts.clone();
+        Box::pin(async move {
+            match req.into_batch_query_builder_opts(&opts).await {
+                Ok(builder) => Ok(GQLBatchResponse(builder.execute(&schema).await).into()),
+                Err(err) => Ok(parse_request_error_response(err)),
+            }
+        })
+    }
+}
@@ -47,6 +47,7 @@
 //! * Limit query complexity/depth
 //! * Error Extensions
 //! * Apollo Federation
+//! * Supports `wasm32-unknown-unknown` through wasm-bindgen, without the `bson` feature (no query timeouts or file uploads)
 //!
 //! ## Integrations
 //!
@@ -68,6 +69,7 @@
 //! * [GraphQL](https://graphql.org)
 //! * [GraphQL Multipart Request](https://github.com/jaydenseric/graphql-multipart-request-spec)
 //! * [GraphQL Cursor Connections Specification](https://facebook.github.io/relay/graphql/connections.htm)
+//! * [GraphQL Global Object Identification Specification](https://relay.dev/graphql/objectidentification.htm)
 //! * [GraphQL over WebSocket Protocol](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md)
 //! * [Apollo Tracing](https://github.com/apollographql/apollo-tracing)
 //! * [Apollo Federation](https://www.apollographql.com/docs/apollo-server/federation/introduction)
@@ -120,8 +122,12 @@ mod subscription;
 mod types;
 mod validation;
 
+pub mod directive;
+pub mod dynamic;
 pub mod extensions;
 pub mod guard;
+pub mod remote;
+pub mod test;
 pub mod validators;
 
 #[doc(hidden)]
@@ -140,27 +146,33 @@ pub use serde_json;
 
 pub mod http;
 
-pub use base::{ScalarType, Type};
+pub use base::{Description, DynObjectType, ObjectExtension, ScalarType, Type};
 pub use context::{
     Context, ContextBase, Data, QueryEnv, QueryPathNode, QueryPathSegment, Variables,
+    VisibleContext,
 };
 pub use error::{
     Error, ErrorExtensions, FieldError, FieldResult, InputValueError, InputValueResult,
-    ParseRequestError, QueryError, ResultExt, RuleError,
+    ParseRequestError, QueryError, ResultExt, RuleError, ServerError,
 };
 pub use look_ahead::Lookahead;
 pub use parser::{Pos, Positioned, Value};
 pub use query::{
-    IntoQueryBuilder, IntoQueryBuilderOpts, QueryBuilder, QueryResponse, StreamResponse,
+    BatchQueryBuilder, BatchQueryResponse, IntoBatchQueryBuilder, IntoQueryBuilder,
+    IntoQueryBuilderOpts, QueryBuilder, QueryResponse, StreamResponse,
 };
 pub use registry::CacheControl;
 pub use scalars::{Any, Json, ID};
 pub use schema::{Schema, SchemaBuilder, SchemaEnv};
 pub use subscription::{
-    SimpleBroker, SubscriptionStreams, SubscriptionTransport, WebSocketTransport,
+    ConnectionParams, SimpleBroker, SubscriptionServer, SubscriptionStreams, SubscriptionTransport,
+    WebSocketCloseReason, WebSocketProtocols, WebSocketTransport, ALL_WEBSOCKET_PROTOCOLS,
 };
+#[cfg(all(not(target_arch = "wasm32"), feature = "uuid"))]
+pub use subscription::{LongPollResponse, LongPollSubscriptions};
 pub use types::{
-    connection, Deferred, EmptyMutation, EmptySubscription, MaybeUndefined, Streamed, Upload,
+    connection, page, relay, Deferred, EmptyMutation, EmptySubscription, MaybeUndefined, Streamed,
+    Upload,
 };
 pub use validation::ValidationMode;
 
@@ -175,7 +187,9 @@ pub use context::ContextSelectionSet;
 pub mod registry;
 
 #[doc(hidden)]
-pub use base::{BoxFieldFuture, InputObjectType, InputValueType, ObjectType, OutputValueType};
+pub use base::{
+    BoxFieldFuture, ComplexObject, InputObjectType, InputValueType, ObjectType, OutputValueType,
+};
 #[doc(hidden)]
 pub use resolver::{collect_fields, do_resolve};
 #[doc(hidden)]
@@ -192,7 +206,17 @@ pub use types::{EnumItem, EnumType};
 /// | name          | Object name               | string   | Y        |
 /// | desc          | Object description        | string   | Y        |
 /// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
+/// | rename_fields | Rename rule for the field names: "lowercase", "UPPERCASE", "PascalCase", "camelCase" (default), "snake_case" or "SCREAMING_SNAKE_CASE" | string | Y |
+/// | rename_args | Rename rule for the argument names, like `rename_fields` | string | Y |
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
+/// | concrete      | Register a generic type as a GraphQL object for these type parameters, e.g. `concrete(name = "UserEdge", params(User))`. Can be repeated | `name = string, params(type, ...)` | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the object from introspection and validation for this request, e.g. `visible = "is_admin"` | string | Y |
+/// | doc           | How doc comments are used as the descriptions of the type and its fields: `first_line`, `max_lines = n` to keep only the first lines, `hide_prefix = "..."` to remove the lines starting with the prefix | `doc(option, ...)` | Y |
+/// | use_type_description | Use the doc comment of the type, with `#[derive(Description)]`, instead of the doc comment of the impl block | bool | Y |
+/// | directive     | Directives to apply to the type in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | shareable     | Mark the type with the Apollo Federation v2 `@shareable` directive | bool | Y |
+/// | inaccessible  | Mark the type with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 ///
 /// # Field parameters
 ///
@@ -207,6 +231,12 @@ pub use types::{EnumItem, EnumType};
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the field from introspection and validation for this request | string | Y |
+/// | directive     | Directives to apply to the field in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | shareable     | Mark the field with the Apollo Federation v2 `@shareable` directive | bool | Y |
+/// | inaccessible  | Mark the field with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | override_from | Apply the Apollo Federation v2 `@override(from: "...")` directive, the field is migrated from the named subgraph | string | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 ///
 /// # Field argument parameters
 ///
@@ -215,7 +245,9 @@ pub use types::{EnumItem, EnumType};
 /// | name        | Argument name             | string   | Y        |
 /// | desc        | Argument description      | string   | Y        |
 /// | default     | Argument default value    | string   | Y        |
+/// | default_with | Expression for the argument default value, evaluated when the argument is absent | string | Y |
 /// | validator   | Input value validator     | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | secret      | The value of the argument is secret, the variables used by it are replaced with `***` when passed to extensions | bool | Y |
 ///
 /// # The field returns the value type
 ///
@@ -302,6 +334,18 @@ pub use async_graphql_derive::Object;
 /// | name          | Object name               | string   | Y        |
 /// | desc          | Object description        | string   | Y        |
 /// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
+/// | rename_fields | Rename rule for the field names: "lowercase", "UPPERCASE", "PascalCase", "camelCase" (default), "snake_case" or "SCREAMING_SNAKE_CASE" | string | Y |
+/// | concrete      | Register a generic type as a GraphQL object for these type parameters, e.g. `concrete(name = "UserEdge", params(User))`. Can be repeated | `name = string, params(type, ...)` | Y |
+/// | complex       | Add the computed fields defined with `ComplexObject` | bool | Y |
+/// | input_name    | Also define an input object with this name, with the same fields. The fields use the `name`, `desc`, `default` and `validator` attributes of `InputObject` | string | Y |
+/// | by_ref        | The getters of the fields return references by default, like the `ref` attribute of the fields | bool | Y |
+/// | remote        | Implement `From<T>` for the object, where `T` is the path of a struct with the same fields defined in another crate. Each field is converted with `Into`, skipped fields use `Default` | string | Y |
+/// | doc           | How doc comments are used as the descriptions of the type and its fields: `first_line`, `max_lines = n` to keep only the first lines, `hide_prefix = "..."` to remove the lines starting with the prefix | `doc(option, ...)` | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the object from introspection and validation for this request, e.g. `visible = "is_admin"` | string | Y |
+/// | directive     | Directives to apply to the type in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | shareable     | Mark the type with the Apollo Federation v2 `@shareable` directive | bool | Y |
+/// | inaccessible  | Mark the type with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 ///
 /// # Field parameters
 ///
@@ -316,6 +360,18 @@ pub use async_graphql_derive::Object;
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | flatten       | Add the fields of this field's object type directly to this object, instead of a field of that type | bool | Y |
+/// | ref           | The getter returns a reference to the field, instead of a clone | bool | Y |
+/// | owned         | The getter returns a clone of the field, even if the object has the `by_ref` attribute | bool | Y |
+/// | with          | Function of `&T` that returns `Result<U, E>` to convert the value of the field, e.g. to decode it lazily. The error is returned as the error of the field. Requires `type` | string | Y |
+/// | type          | The type `U` of the field, when `with` is used | string | Y |
+/// | getter        | Function of `&T` that returns the value of the field when converting from the `remote` struct `T`, instead of the field with the same name | string | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the field from introspection and validation for this request | string | Y |
+/// | directive     | Directives to apply to the field in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | shareable     | Mark the field with the Apollo Federation v2 `@shareable` directive | bool | Y |
+/// | inaccessible  | Mark the field with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | override_from | Apply the Apollo Federation v2 `@override(from: "...")` directive, the field is migrated from the named subgraph | string | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 ///
 /// # Examples
 ///
@@ -338,6 +394,56 @@ pub use async_graphql_derive::Object;
 /// ```
 pub use async_graphql_derive::SimpleObject;
 
+/// Define the computed fields of a `SimpleObject`
+///
+/// The `SimpleObject` must have the `complex` attribute. The methods of the `impl` block are fields
+/// like the ones of `Object`, and are added to the fields of the structure.
+///
+/// # Macro parameters
+///
+/// | Attribute     | description               | Type     | Optional |
+/// |---------------|---------------------------|----------|----------|
+/// | rename_fields | Rename rule for the field names, like `Object` | string | Y |
+/// | rename_args   | Rename rule for the argument names, like `Object` | string | Y |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[SimpleObject(complex)]
+/// struct User {
+///     first_name: String,
+///     last_name: String,
+/// }
+///
+/// #[ComplexObject]
+/// impl User {
+///     async fn full_name(&self) -> String {
+///         format!("{} {}", self.first_name, self.last_name)
+///     }
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn user(&self) -> User {
+///         User {
+///             first_name: "Alice".to_string(),
+///             last_name: "Smith".to_string(),
+///         }
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ user { firstName fullName } }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "user": { "firstName": "Alice", "fullName": "Alice Smith" } }));
+/// });
+/// ```
+pub use async_graphql_derive::ComplexObject;
+
 /// Derive a GraphQL enum
 ///
 /// # Examples
@@ -368,6 +474,21 @@ pub use async_graphql_derive::GQLEnum;
 /// ```
 pub use async_graphql_derive::GQLInputObject;
 
+/// Derive a GraphQL oneOf input object
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// #[derive(GQLOneofObject)]
+/// #[graphql(name = "MyFilter1")]
+/// enum MyFilter {
+///     ById(ID),
+///     ByName(String),
+/// }
+/// ```
+pub use async_graphql_derive::GQLOneofObject;
+
 /// Derive a GraphQL simple object
 ///
 /// # Examples
@@ -382,6 +503,172 @@ pub use async_graphql_derive::GQLInputObject;
 /// ```
 pub use async_graphql_derive::GQLSimpleObject;
 
+/// Define a GraphQL object that merges the fields of several objects
+///
+/// It is defined on a structure whose fields are objects, so a large type such as the root query can
+/// be split into modules. Fields are resolved by the first object that defines them, and the objects
+/// themselves are not added to the schema.
+///
+/// # Panics
+///
+/// Building the schema panics if more than one object defines the same field.
+///
+/// # Macro parameters
+///
+/// | Attribute     | description               | Type     | Optional |
+/// |---------------|---------------------------|----------|----------|
+/// | name          | Object name               | string   | Y        |
+/// | desc          | Object description        | string   | Y        |
+/// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(Default)]
+/// struct UserQuery;
+///
+/// #[Object]
+/// impl UserQuery {
+///     async fn user(&self) -> &str {
+///         "alice"
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct PostQuery;
+///
+/// #[Object]
+/// impl PostQuery {
+///     async fn post(&self) -> &str {
+///         "hello"
+///     }
+/// }
+///
+/// #[MergedObject(name = "Query")]
+/// #[derive(Default)]
+/// struct QueryRoot(UserQuery, PostQuery);
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot::default(), EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ user post }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "user": "alice", "post": "hello" }));
+/// });
+/// ```
+pub use async_graphql_derive::MergedObject;
+
+/// Derive a GraphQL merged object
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct UserQuery;
+///
+/// #[Object]
+/// impl UserQuery {
+///     async fn user(&self) -> &str {
+///         "alice"
+///     }
+/// }
+///
+/// #[derive(GQLMergedObject)]
+/// #[graphql(name = "Query")]
+/// struct QueryRoot {
+///     users: UserQuery,
+/// }
+/// ```
+pub use async_graphql_derive::GQLMergedObject;
+
+/// Define a GraphQL subscription that merges the fields of several subscriptions
+///
+/// Similar to `MergedObject`, but the fields of the structure are subscription types, so
+/// subscription fields can be defined next to the rest of the code of their module.
+///
+/// # Panics
+///
+/// Building the schema panics if more than one subscription defines the same field.
+///
+/// # Macro parameters
+///
+/// | Attribute     | description               | Type     | Optional |
+/// |---------------|---------------------------|----------|----------|
+/// | name          | Object name               | string   | Y        |
+/// | desc          | Object description        | string   | Y        |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures::{Stream, StreamExt};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// #[derive(Default)]
+/// struct UserSubscription;
+///
+/// #[Subscription]
+/// impl UserSubscription {
+///     async fn users(&self) -> impl Stream<Item = i32> {
+///         futures::stream::iter(0..2)
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct PostSubscription;
+///
+/// #[Subscription]
+/// impl PostSubscription {
+///     async fn posts(&self) -> impl Stream<Item = i32> {
+///         futures::stream::iter(10..12)
+///     }
+/// }
+///
+/// #[MergedSubscription(name = "Subscription")]
+/// #[derive(Default)]
+/// struct SubscriptionRoot(UserSubscription, PostSubscription);
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot::default());
+///     let mut stream = schema
+///         .create_subscription_stream("subscription { posts }", None, Default::default(), None)
+///         .await
+///         .unwrap();
+///     assert_eq!(stream.next().await.unwrap().unwrap(), serde_json::json!({ "posts": 10 }));
+/// });
+/// ```
+pub use async_graphql_derive::MergedSubscription;
+
+/// Derive a GraphQL merged subscription
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures::Stream;
+///
+/// struct UserSubscription;
+///
+/// #[Subscription]
+/// impl UserSubscription {
+///     async fn users(&self) -> impl Stream<Item = i32> {
+///         futures::stream::iter(0..2)
+///     }
+/// }
+///
+/// #[derive(GQLMergedSubscription)]
+/// #[graphql(name = "Subscription")]
+/// struct SubscriptionRoot {
+///     users: UserSubscription,
+/// }
+/// ```
+pub use async_graphql_derive::GQLMergedSubscription;
+
 /// Define a GraphQL enum
 ///
 /// # Macro parameters
@@ -390,6 +677,13 @@ pub use async_graphql_derive::GQLSimpleObject;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Enum name                 | string   | Y        |
 /// | desc        | Enum description          | string   | Y        |
+/// | remote      | Path of a foreign enum with the same variants, to generate `From` conversions both ways | string | Y |
+/// | rename_items | Rename rule for the item names: "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case" or "SCREAMING_SNAKE_CASE" (default) | string | Y |
+/// | doc           | How doc comments are used as the descriptions of the type and its items: `first_line`, `max_lines = n` to keep only the first lines, `hide_prefix = "..."` to remove the lines starting with the prefix | `doc(option, ...)` | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the enum from introspection and validation for this request, e.g. `visible = "is_admin"` | string | Y |
+/// | directive     | Directives to apply to the type in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | inaccessible  | Mark the type with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 ///
 /// # Item parameters
 ///
@@ -398,6 +692,8 @@ pub use async_graphql_derive::GQLSimpleObject;
 /// | name        | Item name                 | string   | Y        |
 /// | desc        | Item description          | string   | Y        |
 /// | deprecation | Item deprecation reason   | string   | Y        |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the item from introspection and validation for this request | string | Y |
+/// | value       | Custom value of the item in responses and string input values, e.g. a legacy database value. The item name is still used in queries and introspection | string | Y |
 /// | ref         | The resolver function returns a borrowing value  | bool   | Y        |
 ///
 /// # Examples
@@ -446,6 +742,12 @@ pub use async_graphql_derive::Enum;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | rename_fields | Rename rule for the field names: "lowercase", "UPPERCASE", "PascalCase", "camelCase" (default), "snake_case" or "SCREAMING_SNAKE_CASE" | string | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the input object from introspection and validation for this request, e.g. `visible = "is_admin"` | string | Y |
+/// | doc           | How doc comments are used as the descriptions of the type and its fields: `first_line`, `max_lines = n` to keep only the first lines, `hide_prefix = "..."` to remove the lines starting with the prefix | `doc(option, ...)` | Y |
+/// | directive     | Directives to apply to the type in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | inaccessible  | Mark the type with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 ///
 /// # Field parameters
 ///
@@ -454,6 +756,11 @@ pub use async_graphql_derive::Enum;
 /// | name        | Field name                | string   | Y        |
 /// | desc        | Field description         | string   | Y        |
 /// | default     | Field default value       | string   | Y        |
+/// | default_with | Expression for the field default value, evaluated when the field is absent | string | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the field from introspection and validation for this request | string | Y |
+/// | directive     | Directives to apply to the field in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | inaccessible  | Mark the field with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
 /// | validator   | Input value validator     | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
 ///
 /// # Examples
@@ -491,6 +798,75 @@ pub use async_graphql_derive::Enum;
 /// ```
 pub use async_graphql_derive::InputObject;
 
+/// Define a GraphQL oneOf input object
+///
+/// A oneOf input object is an input object of which exactly one field must be provided, with a
+/// non-null value. It is defined with an enum whose variants each have one unnamed field, and
+/// each variant is a field of the input object.
+///
+/// # Macro parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | name        | Object name               | string   | Y        |
+/// | desc        | Object description        | string   | Y        |
+/// | rename_fields | Rename rule for the field names: "lowercase", "UPPERCASE", "PascalCase", "camelCase" (default), "snake_case" or "SCREAMING_SNAKE_CASE" | string | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the input object from introspection and validation for this request, e.g. `visible = "is_admin"` | string | Y |
+/// | doc           | How doc comments are used as the descriptions of the type and its fields: `first_line`, `max_lines = n` to keep only the first lines, `hide_prefix = "..."` to remove the lines starting with the prefix | `doc(option, ...)` | Y |
+/// | directive     | Directives to apply to the type in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | inaccessible  | Mark the type with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
+///
+/// # Field parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | name        | Field name                | string   | Y        |
+/// | desc        | Field description         | string   | Y        |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the field from introspection and validation for this request | string | Y |
+/// | directive     | Directives to apply to the field in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
+/// | inaccessible  | Mark the field with the Apollo Federation v2 `@inaccessible` directive | bool | Y |
+/// | tag           | Apply the Apollo Federation v2 `@tag(name: "...")` directive. Can be repeated | string | Y |
+/// | validator   | Input value validator     | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[OneofObject]
+/// enum UserFilter {
+///     ById(i32),
+///     ByName(String),
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn user(&self, filter: UserFilter) -> String {
+///         match filter {
+///             UserFilter::ById(id) => format!("user {}", id),
+///             UserFilter::ByName(name) => name,
+///         }
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute(r#"
+///     {
+///         user1: user(filter: {byId: 1})
+///         user2: user(filter: {byName: "Alice"})
+///     }"#).await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "user1": "user 1", "user2": "Alice" }));
+///
+///     assert!(schema.execute(r#"{ user(filter: {byId: 1, byName: "Alice"}) }"#).await.is_err());
+/// }
+/// ```
+pub use async_graphql_derive::OneofObject;
+
 /// Define a GraphQL interface
 ///
 /// # Macro parameters
@@ -499,6 +875,8 @@ pub use async_graphql_derive::InputObject;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | implements  | Implement another interface, the possible types of this interface must also be variants of it. Can be repeated. | string | Y |
+/// | concrete    | Register a generic interface as a GraphQL interface for these type parameters, e.g. `concrete(name = "UserNode", params(User))`. Can be repeated | `name = string, params(type, ...)` | Y |
 ///
 /// # Field parameters
 ///
@@ -509,7 +887,8 @@ pub use async_graphql_derive::InputObject;
 /// | type        | Field type                | string   | N        |
 /// | desc        | Field description         | string   | Y        |
 /// | deprecation | Field deprecation reason  | string   | Y        |
-/// | args        | Field arguments           |          | Y        |
+/// | args        | Field arguments. The possible types must define the same arguments, any additional argument must be optional | | Y |
+/// | default_impl | The field is resolved by a method of the interface, for the possible types that don't define it | bool | Y |
 ///
 /// # Field argument parameters
 ///
@@ -519,6 +898,8 @@ pub use async_graphql_derive::InputObject;
 /// | type        | Argument type             | string   | N        |
 /// | desc        | Argument description      | string   | Y        |
 /// | default     | Argument default value    | string   | Y        |
+/// | default_with | Expression for the argument default value, evaluated when the argument is absent | string | Y |
+/// | secret      | The value of the argument is secret, the variables used by it are replaced with `***` when passed to extensions | bool | Y |
 ///
 /// # Define an interface
 ///
@@ -627,6 +1008,7 @@ pub use async_graphql_derive::GQLInterface;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | concrete    | Register a generic union as a GraphQL union for these type parameters, e.g. `concrete(name = "UserResult", params(User))`. Can be repeated | `name = string, params(type, ...)` | Y |
 ///
 /// # Define a union
 ///
@@ -700,6 +1082,10 @@ pub use async_graphql_derive::GQLUnion;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | rename_fields | Rename rule for the field names: "lowercase", "UPPERCASE", "PascalCase", "camelCase" (default), "snake_case" or "SCREAMING_SNAKE_CASE" | string | Y |
+/// | rename_args | Rename rule for the argument names, like `rename_fields` | string | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the subscription type from introspection and validation for this request, e.g. `visible = "is_admin"` | string | Y |
+/// | directive     | Directives to apply to the type in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
 ///
 /// # Field parameters
 ///
@@ -710,6 +1096,8 @@ pub use async_graphql_derive::GQLUnion;
 /// | deprecation | Field deprecation reason  | string   | Y        |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | feature       | It's like a `#[cfg(feature = "foo")]` attribute but instead of not compiling this field it will just return a proper `FieldError` to tell you this feature is not enabled | string ("feature1,feature2") | Y |
+/// | visible       | Function of `&VisibleContext` that returns `false` to hide the field from introspection and validation for this request | string | Y |
+/// | directive     | Directives to apply to the field in the SDL and introspection, e.g. `directive(tag(name = "internal"))`. Can be repeated | `name(arg = value, ...)` | Y |
 ///
 /// # Field argument parameters
 ///
@@ -718,7 +1106,9 @@ pub use async_graphql_derive::GQLUnion;
 /// | name        | Argument name             | string   | Y        |
 /// | desc        | Argument description      | string   | Y        |
 /// | default     | Argument default value    | string   | Y        |
+/// | default_with | Expression for the argument default value, evaluated when the argument is absent | string | Y |
 /// | validator   | Input value validator     | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | secret      | The value of the argument is secret, the variables used by it are replaced with `***` when passed to extensions | bool | Y |
 ///
 /// # Examples
 ///
@@ -755,3 +1145,93 @@ pub use async_graphql_derive::DataSource;
 /// | desc        | Scalar description        | string   | Y        |
 ///
 pub use async_graphql_derive::Scalar;
+
+/// Define a scalar that wraps another scalar
+///
+/// Derived on a tuple struct with one field. Without a `name`, the type is the same GraphQL type
+/// as the wrapped scalar. With a `name`, it is a new scalar that is parsed and serialized like
+/// the wrapped scalar.
+///
+/// # Macro parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | name        | Scalar name, the wrapped scalar is used if not specified | string | Y |
+/// | desc        | Scalar description        | string   | Y        |
+/// | validator   | Function of `&T` that returns `Result<(), E: Display>` to validate the input value, e.g. `validator = "check_email"` | string | Y |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// fn check_email(s: &String) -> std::result::Result<(), &'static str> {
+///     if s.contains('@') {
+///         Ok(())
+///     } else {
+///         Err("invalid email")
+///     }
+/// }
+///
+/// #[derive(NewType)]
+/// struct UserId(i32);
+///
+/// /// An email address
+/// #[derive(NewType)]
+/// #[graphql(name = "Email", validator = "check_email")]
+/// struct Email(String);
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn user(&self, id: UserId, email: Email) -> String {
+///         format!("{}:{}", id.0, email.0)
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute(r#"{ user(id: 1, email: "a@b.c") }"#).await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "user": "1:a@b.c" }));
+///     assert!(schema.execute(r#"{ user(id: 1, email: "abc") }"#).await.is_err());
+/// });
+/// ```
+pub use async_graphql_derive::NewType;
+
+/// Implement `Description` with the doc comment of the type
+///
+/// It is used with the `use_type_description` attribute of `Object`.
+///
+/// # Macro parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | doc         | How the doc comment is used, like the `doc` attribute of `Object` | `doc(option, ...)` | Y |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// /// The query root
+/// ///
+/// /// Internal notes that are only in rustdoc.
+/// #[derive(Description)]
+/// #[graphql(doc(first_line))]
+/// struct QueryRoot;
+///
+/// #[Object(use_type_description)]
+/// impl QueryRoot {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute(r#"{ __type(name: "QueryRoot") { description } }"#).await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "__type": { "description": "The query root" } }));
+/// });
+/// ```
+pub use async_graphql_derive::Description;
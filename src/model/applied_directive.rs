@@ -0,0 +1,32 @@
+use crate::registry;
+use async_graphql_derive::{Object, SimpleObject};
+
+pub struct __AppliedDirective<'a> {
+    pub directive: &'a registry::MetaAppliedDirective,
+}
+
+/// An argument of a directive applied to a type or a field, with its value in GraphQL syntax.
+#[SimpleObject(internal)]
+pub struct __AppliedDirectiveArgument {
+    name: String,
+    value: String,
+}
+
+/// A directive applied to a type or a field in the schema, such as `@tag(name: "internal")`.
+#[Object(internal)]
+impl<'a> __AppliedDirective<'a> {
+    async fn name(&self) -> String {
+        self.directive.name.to_string()
+    }
+
+    async fn args(&self) -> Vec<__AppliedDirectiveArgument> {
+        self.directive
+            .args
+            .iter()
+            .map(|(name, value)| __AppliedDirectiveArgument {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,5 @@
-use crate::model::{__InputValue, __Type};
-use crate::registry;
+use crate::model::{__AppliedDirective, __InputValue, __Type};
+use crate::{registry, Context};
 use async_graphql_derive::Object;
 use itertools::Itertools;
 
@@ -16,13 +16,16 @@ impl<'a> __Field<'a> {
     }
 
     async fn description(&self) -> Option<String> {
-        self.field.description.map(|s| s.to_string())
+        self.field.description.clone()
     }
 
-    async fn args(&self) -> Vec<__InputValue<'a>> {
+    async fn args(&self, ctx: &Context<'_>) -> Vec<__InputValue<'a>> {
         self.field
             .args
             .values()
+            .filter(|input_value| {
+                registry::is_visible(&ctx.visible_context(), &input_value.visible)
+            })
             .map(|input_value| __InputValue {
                 registry: self.registry,
                 input_value,
@@ -42,4 +45,12 @@ impl<'a> __Field<'a> {
     async fn deprecation_reason(&self) -> Option<String> {
         self.field.deprecation.map(|s| s.to_string())
     }
+
+    async fn applied_directives(&self) -> Vec<__AppliedDirective<'a>> {
+        self.field
+            .directives
+            .iter()
+            .map(|directive| __AppliedDirective { directive })
+            .collect()
+    }
 }
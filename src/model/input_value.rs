@@ -1,4 +1,4 @@
-use crate::model::__Type;
+use crate::model::{__AppliedDirective, __Type};
 use crate::registry;
 use async_graphql_derive::Object;
 
@@ -15,7 +15,7 @@ impl<'a> __InputValue<'a> {
     }
 
     async fn description(&self) -> Option<String> {
-        self.input_value.description.map(|s| s.to_string())
+        self.input_value.description.clone()
     }
 
     #[field(name = "type")]
@@ -26,4 +26,12 @@ impl<'a> __InputValue<'a> {
     async fn default_value(&self) -> Option<String> {
         self.input_value.default_value.clone()
     }
+
+    async fn applied_directives(&self) -> Vec<__AppliedDirective<'a>> {
+        self.input_value
+            .directives
+            .iter()
+            .map(|directive| __AppliedDirective { directive })
+            .collect()
+    }
 }
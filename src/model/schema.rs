@@ -1,5 +1,5 @@
 use crate::model::{__Directive, __Type};
-use crate::registry;
+use crate::{registry, Context};
 use async_graphql_derive::Object;
 use itertools::Itertools;
 
@@ -11,11 +11,12 @@ pub struct __Schema<'a> {
 #[Object(internal)]
 impl<'a> __Schema<'a> {
     /// A list of all types supported by this server.
-    async fn types(&self) -> Vec<__Type<'a>> {
+    async fn types(&self, ctx: &Context<'_>) -> Vec<__Type<'a>> {
         let mut types = self
             .registry
             .types
             .values()
+            .filter(|ty| ty.is_visible(&ctx.visible_context()))
             .map(|ty| (ty.name(), __Type::new_simple(self.registry, ty)))
             .collect_vec();
         types.sort_by(|a, b| a.0.cmp(b.0));
@@ -1,5 +1,5 @@
-use crate::model::{__EnumValue, __Field, __InputValue, __TypeKind};
-use crate::registry;
+use crate::model::{__AppliedDirective, __EnumValue, __Field, __InputValue, __TypeKind};
+use crate::{registry, Context};
 use async_graphql_derive::Object;
 use itertools::Itertools;
 
@@ -74,12 +74,8 @@ impl<'a> __Type<'a> {
                 registry::MetaType::Scalar { description, .. } => {
                     description.map(|s| s.to_string())
                 }
-                registry::MetaType::Object { description, .. } => {
-                    description.map(|s| s.to_string())
-                }
-                registry::MetaType::Interface { description, .. } => {
-                    description.map(|s| s.to_string())
-                }
+                registry::MetaType::Object { description, .. } => description.clone(),
+                registry::MetaType::Interface { description, .. } => description.clone(),
                 registry::MetaType::Union { description, .. } => description.map(|s| s.to_string()),
                 registry::MetaType::Enum { description, .. } => description.map(|s| s.to_string()),
                 registry::MetaType::InputObject { description, .. } => {
@@ -93,6 +89,7 @@ impl<'a> __Type<'a> {
 
     async fn fields(
         &self,
+        ctx: &Context<'_>,
         #[arg(default = false)] include_deprecated: bool,
     ) -> Option<Vec<__Field<'a>>> {
         if let TypeDetail::Named(ty) = &self.detail {
@@ -102,6 +99,7 @@ impl<'a> __Type<'a> {
                     .filter(|field| {
                         (include_deprecated || field.deprecation.is_none())
                             && !field.name.starts_with("__")
+                            && registry::is_visible(&ctx.visible_context(), &field.visible)
                     })
                     .map(|field| __Field {
                         registry: self.registry,
@@ -115,7 +113,9 @@ impl<'a> __Type<'a> {
     }
 
     async fn interfaces(&self) -> Option<Vec<__Type<'a>>> {
-        if let TypeDetail::Named(registry::MetaType::Object { name, .. }) = &self.detail {
+        if let TypeDetail::Named(registry::MetaType::Object { name, .. })
+        | TypeDetail::Named(registry::MetaType::Interface { name, .. }) = &self.detail
+        {
             Some(
                 self.registry
                     .implements
@@ -156,13 +156,17 @@ impl<'a> __Type<'a> {
 
     async fn enum_values(
         &self,
+        ctx: &Context<'_>,
         #[arg(default = false)] include_deprecated: bool,
     ) -> Option<Vec<__EnumValue<'a>>> {
         if let TypeDetail::Named(registry::MetaType::Enum { enum_values, .. }) = &self.detail {
             Some(
                 enum_values
                     .values()
-                    .filter(|field| include_deprecated || field.deprecation.is_none())
+                    .filter(|value| {
+                        (include_deprecated || value.deprecation.is_none())
+                            && registry::is_visible(&ctx.visible_context(), &value.visible)
+                    })
                     .map(|value| __EnumValue {
                         registry: self.registry,
                         value,
@@ -174,13 +178,16 @@ impl<'a> __Type<'a> {
         }
     }
 
-    async fn input_fields(&self) -> Option<Vec<__InputValue<'a>>> {
+    async fn input_fields(&self, ctx: &Context<'_>) -> Option<Vec<__InputValue<'a>>> {
         if let TypeDetail::Named(registry::MetaType::InputObject { input_fields, .. }) =
             &self.detail
         {
             Some(
                 input_fields
                     .values()
+                    .filter(|input_value| {
+                        registry::is_visible(&ctx.visible_context(), &input_value.visible)
+                    })
                     .map(|input_value| __InputValue {
                         registry: self.registry,
                         input_value,
@@ -192,6 +199,25 @@ impl<'a> __Type<'a> {
         }
     }
 
+    async fn is_one_of(&self) -> Option<bool> {
+        if let TypeDetail::Named(registry::MetaType::InputObject { oneof, .. }) = &self.detail {
+            Some(*oneof)
+        } else {
+            None
+        }
+    }
+
+    async fn applied_directives(&self) -> Vec<__AppliedDirective<'a>> {
+        if let TypeDetail::Named(ty) = &self.detail {
+            ty.directives()
+                .iter()
+                .map(|directive| __AppliedDirective { directive })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     async fn of_type(&self) -> Option<__Type<'a>> {
         if let TypeDetail::List(ty) = &self.detail {
             Some(__Type::new(self.registry, &ty))
@@ -1,5 +1,6 @@
 use crate::extensions::{Extension, ResolveInfo};
 use crate::parser::query::{Selection, TypeCondition};
+use crate::resolver::resolve_field;
 use crate::{ContextSelectionSet, Error, ObjectType, QueryError, Result};
 use std::future::Future;
 use std::pin::Pin;
@@ -80,7 +81,7 @@ fn do_resolve<'a, T: ObjectType + Send + Sync>(
                     let value = ctx_field
                         .query_env
                         .extensions
-                        .log_error(root.resolve_field(&ctx_field).await)?;
+                        .log_error(resolve_field(&ctx_field, root).await)?;
                     values.insert(field_name, value);
 
                     ctx_field.query_env.extensions.resolve_end(&resolve_info);
@@ -1,21 +1,31 @@
-use crate::context::{Data, DeferList, ResolveId};
+use crate::context::{Data, DeferList, ResolveId, ResponseErrors};
 use crate::error::ParseRequestError;
 use crate::extensions::{BoxExtension, Extension};
 use crate::mutation_resolver::do_mutation_resolve;
+use crate::parser::parse_query;
 use crate::registry::CacheControl;
 use crate::{
     do_resolve, ContextBase, Error, ObjectType, Pos, QueryEnv, QueryError, Result, Schema,
-    SubscriptionType, Variables,
+    ServerError, SubscriptionType, Variables,
 };
 use async_graphql_parser::query::OperationType;
+use async_graphql_parser::UploadContent;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::{self, Either};
 use futures::{Stream, StreamExt};
+#[cfg(not(target_arch = "wasm32"))]
+use futures_timer::Delay;
 use itertools::Itertools;
 use std::any::Any;
 use std::borrow::Cow;
 use std::fs::File;
+#[cfg(not(target_arch = "wasm32"))]
+use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
 
 /// IntoQueryBuilder options
 #[derive(Default, Clone)]
@@ -25,6 +35,24 @@ pub struct IntoQueryBuilderOpts {
 
     /// Maximum number of files.
     pub max_num_files: Option<usize>,
+
+    /// Maximum size of a request body that is not multipart, and of the `operations` and `map` parts
+    /// of a multipart request.
+    ///
+    /// If `max_file_size`, `max_num_files` and `max_request_size` are all set, the whole multipart
+    /// request is also limited to the size of that many files plus `max_request_size` for each of
+    /// the `operations` and `map` parts, so other parts cannot make it larger. Otherwise there is
+    /// no limit for the whole multipart request.
+    pub max_request_size: Option<usize>,
+
+    /// Maximum size of an uploaded file that is kept in memory.
+    ///
+    /// Larger files are streamed to a temporary file. If not set, every uploaded file is stored in
+    /// a temporary file.
+    pub file_spill_threshold: Option<usize>,
+
+    /// Maximum number of operations in a batch request.
+    pub max_batch_size: Option<usize>,
 }
 
 #[allow(missing_docs)]
@@ -40,6 +68,22 @@ pub trait IntoQueryBuilder: Sized {
     ) -> std::result::Result<QueryBuilder, ParseRequestError>;
 }
 
+#[allow(missing_docs)]
+#[async_trait::async_trait]
+pub trait IntoBatchQueryBuilder: Sized {
+    async fn into_batch_query_builder(
+        self,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        self.into_batch_query_builder_opts(&Default::default())
+            .await
+    }
+
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError>;
+}
+
 /// Query response
 #[derive(Debug)]
 pub struct QueryResponse {
@@ -54,6 +98,9 @@ pub struct QueryResponse {
     /// Data of query result
     pub data: serde_json::Value,
 
+    /// Errors of the fields that were resolved with partial data, e.g. delegated fields
+    pub errors: Vec<ServerError>,
+
     /// Extensions result
     pub extensions: Option<serde_json::Value>,
 
@@ -70,6 +117,14 @@ impl QueryResponse {
             self.path = Some(prefix);
         }
 
+        if let Some(path) = &self.path {
+            for error in &mut self.errors {
+                let mut new_path = path.clone();
+                new_path.append(&mut error.path);
+                error.path = new_path;
+            }
+        }
+
         self.label = self.path.as_ref().map(|path| {
             path.iter()
                 .map(|value| {
@@ -86,6 +141,7 @@ impl QueryResponse {
     }
 
     pub(crate) fn merge(&mut self, resp: QueryResponse) {
+        self.errors.extend(resp.errors);
         let mut p = &mut self.data;
         for item in resp.path.unwrap_or_default() {
             match item {
@@ -117,6 +173,7 @@ impl QueryResponse {
 }
 
 /// Response for `Schema::execute_stream` and `QueryBuilder::execute_stream`
+#[allow(clippy::large_enum_variant)]
 pub enum StreamResponse {
     /// There is no `@defer` or `@stream` directive in the query, this is the final result.
     Single(Result<QueryResponse>),
@@ -133,6 +190,20 @@ impl StreamResponse {
             StreamResponse::Stream(stream) => stream,
         }
     }
+
+    /// Wait for all the incremental results and merge them into a complete result.
+    pub async fn into_complete(self) -> Result<QueryResponse> {
+        match self {
+            StreamResponse::Single(res) => res,
+            StreamResponse::Stream(mut stream) => {
+                let mut resp = stream.next().await.unwrap()?;
+                while let Some(resp_part) = stream.next().await.transpose()? {
+                    resp.merge(resp_part);
+                }
+                Ok(resp)
+            }
+        }
+    }
 }
 
 /// Query builder
@@ -141,7 +212,9 @@ pub struct QueryBuilder {
     pub(crate) operation_name: Option<String>,
     pub(crate) variables: Variables,
     pub(crate) ctx_data: Option<Data>,
-    extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+    pub(crate) extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl QueryBuilder {
@@ -153,6 +226,8 @@ impl QueryBuilder {
             variables: Default::default(),
             ctx_data: None,
             extensions: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: None,
         }
     }
 
@@ -193,6 +268,32 @@ impl QueryBuilder {
         self
     }
 
+    /// Set the maximum time the query can take to execute with `QueryBuilder::execute`.
+    ///
+    /// If the query takes longer, it is aborted and `Error::Timeout` is returned, which is
+    /// serialized with a `TIMEOUT` code in the extensions.
+    ///
+    /// Not available on `wasm32`, where there is no timer thread.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        QueryBuilder {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Returns the type of the operation that will be executed.
+    ///
+    /// Returns `None` if the query cannot be parsed or the operation is not found.
+    pub fn operation_type(&self) -> Option<OperationType> {
+        let mut document = parse_query(&self.query_source).ok()?;
+        if document.retain_operation(self.operation_name.as_deref()) {
+            Some(document.current_operation().ty)
+        } else {
+            None
+        }
+    }
+
     /// Set uploaded file path
     pub fn set_upload(
         &mut self,
@@ -200,6 +301,21 @@ impl QueryBuilder {
         filename: String,
         content_type: Option<String>,
         content: File,
+    ) {
+        self.variables.set_upload(
+            var_path,
+            filename,
+            content_type,
+            UploadContent::File(content),
+        );
+    }
+
+    pub(crate) fn set_upload_content(
+        &mut self,
+        var_path: &str,
+        filename: String,
+        content_type: Option<String>,
+        content: UploadContent,
     ) {
         self.variables
             .set_upload(var_path, filename, content_type, content);
@@ -271,8 +387,12 @@ impl QueryBuilder {
         Mutation: ObjectType + Send + Sync + 'static,
         Subscription: SubscriptionType + Send + Sync + 'static,
     {
-        let (mut document, cache_control, extensions) =
-            schema.prepare_query(&self.query_source, &self.extensions)?;
+        let (mut document, cache_control, extensions) = schema.prepare_query(
+            &self.query_source,
+            &self.variables,
+            &self.extensions,
+            self.ctx_data.as_ref(),
+        )?;
 
         // execute
         let inc_resolve_id = AtomicUsize::default();
@@ -304,6 +424,7 @@ impl QueryBuilder {
             path_prefix: Vec::new(),
             futures: Default::default(),
         };
+        let errors = ResponseErrors::default();
         let ctx = ContextBase {
             path_node: None,
             resolve_id: ResolveId::root(),
@@ -312,6 +433,7 @@ impl QueryBuilder {
             schema_env: &schema.env,
             query_env: &env,
             defer_list: Some(&defer_list),
+            errors: &errors,
         };
 
         env.extensions.execution_start();
@@ -334,12 +456,34 @@ impl QueryBuilder {
             label: None,
             path: None,
             data,
+            errors: errors.take(),
             extensions: env.extensions.result(),
             cache_control,
         };
         Ok((res, defer_list))
     }
 
+    /// Execute the subscription, returns a stream of results.
+    pub async fn execute_subscription<Query, Mutation, Subscription>(
+        self,
+        schema: &Schema<Query, Mutation, Subscription>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send + 'static>>>
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+    {
+        let stream = schema
+            .create_subscription_stream(
+                &self.query_source,
+                self.operation_name.as_deref(),
+                self.variables,
+                self.ctx_data.map(Arc::new),
+            )
+            .await?;
+        Ok(Box::pin(stream))
+    }
+
     /// Execute the query, always return a complete result.
     pub async fn execute<Query, Mutation, Subscription>(
         self,
@@ -350,16 +494,97 @@ impl QueryBuilder {
         Mutation: ObjectType + Send + Sync + 'static,
         Subscription: SubscriptionType + Send + Sync + 'static,
     {
-        let resp = self.execute_stream(schema).await;
-        match resp {
-            StreamResponse::Single(res) => res,
-            StreamResponse::Stream(mut stream) => {
-                let mut resp = stream.next().await.unwrap()?;
-                while let Some(resp_part) = stream.next().await.transpose()? {
-                    resp.merge(resp_part);
-                }
-                Ok(resp)
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(timeout) = self.timeout {
+                return execute_with_timeout(self.execute_complete(schema), timeout).await;
+            }
+        }
+        self.execute_complete(schema).await
+    }
+
+    async fn execute_complete<Query, Mutation, Subscription>(
+        self,
+        schema: &Schema<Query, Mutation, Subscription>,
+    ) -> Result<QueryResponse>
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+    {
+        self.execute_stream(schema).await.into_complete().await
+    }
+}
+
+/// Execute the query, returns a `TIMEOUT` error if it takes longer than `timeout`.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn execute_with_timeout(
+    execute: impl Future<Output = Result<QueryResponse>>,
+    timeout: Duration,
+) -> Result<QueryResponse> {
+    match future::select(Box::pin(execute), Delay::new(timeout)).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => Err(Error::Timeout),
+    }
+}
+
+/// Batch query builder
+///
+/// A request body may contain a single operation or a list of operations.
+/// Each operation in a batch is executed concurrently and the responses are returned in the same order.
+pub enum BatchQueryBuilder {
+    /// Single operation
+    Single(QueryBuilder),
+
+    /// Multiple operations
+    Batch(Vec<QueryBuilder>),
+}
+
+impl BatchQueryBuilder {
+    /// Add a context data to every operation, see `QueryBuilder::data`.
+    pub fn data<D: Any + Clone + Send + Sync>(self, data: D) -> Self {
+        match self {
+            BatchQueryBuilder::Single(builder) => BatchQueryBuilder::Single(builder.data(data)),
+            BatchQueryBuilder::Batch(builders) => BatchQueryBuilder::Batch(
+                builders
+                    .into_iter()
+                    .map(|builder| builder.data(data.clone()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Execute all the operations, always return complete results.
+    pub async fn execute<Query, Mutation, Subscription>(
+        self,
+        schema: &Schema<Query, Mutation, Subscription>,
+    ) -> BatchQueryResponse
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+    {
+        match self {
+            BatchQueryBuilder::Single(builder) => {
+                BatchQueryResponse::Single(builder.execute(schema).await)
             }
+            BatchQueryBuilder::Batch(builders) => BatchQueryResponse::Batch(
+                futures::future::join_all(
+                    builders.into_iter().map(|builder| builder.execute(schema)),
+                )
+                .await,
+            ),
         }
     }
 }
+
+/// Response for `BatchQueryBuilder::execute`
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum BatchQueryResponse {
+    /// Response for a single operation
+    Single(Result<QueryResponse>),
+
+    /// Responses for multiple operations
+    Batch(Vec<Result<QueryResponse>>),
+}
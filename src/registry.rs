@@ -1,6 +1,6 @@
 use crate::parser::query::Type as ParsedType;
 use crate::validators::InputValueValidator;
-use crate::{model, Any, Type as _, Value};
+use crate::{model, Any, Type as _, Value, VisibleContext};
 use indexmap::map::IndexMap;
 use indexmap::set::IndexSet;
 use itertools::Itertools;
@@ -92,26 +92,68 @@ impl<'a> MetaTypeName<'a> {
     }
 }
 
+/// Function that returns `true` if a type, a field or an enum value is visible to the request
+///
+/// It takes a `VisibleContext` rather than a `Context` because it is called during validation,
+/// before any field is resolved.
+pub type MetaVisibleFn = fn(&VisibleContext<'_>) -> bool;
+
+pub fn is_visible(ctx: &VisibleContext<'_>, visible: &Option<MetaVisibleFn>) -> bool {
+    match visible {
+        Some(f) => f(ctx),
+        None => true,
+    }
+}
+
+/// A directive applied to a type or a field of the schema, e.g. `@tag(name: "internal")`
 #[derive(Clone)]
-pub struct MetaInputValue {
+pub struct MetaAppliedDirective {
     pub name: &'static str,
-    pub description: Option<&'static str>,
+    pub args: IndexMap<&'static str, Value>,
+}
+
+impl MetaAppliedDirective {
+    pub fn sdl(&self) -> String {
+        if self.args.is_empty() {
+            format!("@{}", self.name)
+        } else {
+            format!(
+                "@{}({})",
+                self.name,
+                self.args
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .join(", ")
+            )
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MetaInputValue {
+    pub name: String,
+    pub description: Option<String>,
     pub ty: String,
     pub default_value: Option<String>,
     pub validator: Option<Arc<dyn InputValueValidator>>,
+    pub visible: Option<MetaVisibleFn>,
+    pub directives: Vec<MetaAppliedDirective>,
+    pub is_secret: bool,
 }
 
 #[derive(Clone)]
 pub struct MetaField {
     pub name: String,
-    pub description: Option<&'static str>,
-    pub args: IndexMap<&'static str, MetaInputValue>,
+    pub description: Option<String>,
+    pub args: IndexMap<String, MetaInputValue>,
     pub ty: String,
     pub deprecation: Option<&'static str>,
     pub cache_control: CacheControl,
     pub external: bool,
     pub requires: Option<&'static str>,
     pub provides: Option<&'static str>,
+    pub visible: Option<MetaVisibleFn>,
+    pub directives: Vec<MetaAppliedDirective>,
 }
 
 #[derive(Clone)]
@@ -119,6 +161,7 @@ pub struct MetaEnumValue {
     pub name: &'static str,
     pub description: Option<&'static str>,
     pub deprecation: Option<&'static str>,
+    pub visible: Option<MetaVisibleFn>,
 }
 
 /// Cache control values
@@ -205,15 +248,17 @@ pub enum MetaType {
     },
     Object {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         fields: IndexMap<String, MetaField>,
         cache_control: CacheControl,
         extends: bool,
         keys: Option<Vec<String>>,
+        visible: Option<MetaVisibleFn>,
+        directives: Vec<MetaAppliedDirective>,
     },
     Interface {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         fields: IndexMap<String, MetaField>,
         possible_types: IndexSet<String>,
         extends: bool,
@@ -228,11 +273,16 @@ pub enum MetaType {
         name: String,
         description: Option<&'static str>,
         enum_values: IndexMap<&'static str, MetaEnumValue>,
+        visible: Option<MetaVisibleFn>,
+        directives: Vec<MetaAppliedDirective>,
     },
     InputObject {
         name: String,
         description: Option<&'static str>,
         input_fields: IndexMap<String, MetaInputValue>,
+        visible: Option<MetaVisibleFn>,
+        directives: Vec<MetaAppliedDirective>,
+        oneof: bool,
     },
 }
 
@@ -249,6 +299,24 @@ impl MetaType {
         }
     }
 
+    pub fn is_visible(&self, ctx: &VisibleContext<'_>) -> bool {
+        match self {
+            MetaType::Object { visible, .. } => is_visible(ctx, visible),
+            MetaType::Enum { visible, .. } => is_visible(ctx, visible),
+            MetaType::InputObject { visible, .. } => is_visible(ctx, visible),
+            _ => true,
+        }
+    }
+
+    pub fn directives(&self) -> &[MetaAppliedDirective] {
+        match self {
+            MetaType::Object { directives, .. } => directives,
+            MetaType::Enum { directives, .. } => directives,
+            MetaType::InputObject { directives, .. } => directives,
+            _ => &[],
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             MetaType::Scalar { name, .. } => &name,
@@ -334,13 +402,16 @@ pub struct MetaDirective {
     pub name: &'static str,
     pub description: Option<&'static str>,
     pub locations: Vec<model::__DirectiveLocation>,
-    pub args: IndexMap<&'static str, MetaInputValue>,
+    pub args: IndexMap<String, MetaInputValue>,
 }
 
 pub struct Registry {
     pub types: HashMap<String, MetaType>,
     pub directives: HashMap<String, MetaDirective>,
     pub implements: HashMap<String, HashSet<String>>,
+    pub default_fields: HashMap<String, HashSet<String>>,
+    pub rust_types: HashMap<String, &'static str>,
+    pub federation_v2: bool,
     pub query_type: String,
     pub mutation_type: Option<String>,
     pub subscription_type: Option<String>,
@@ -349,10 +420,41 @@ pub struct Registry {
 impl Registry {
     pub fn create_type<T: crate::Type, F: FnMut(&mut Registry) -> MetaType>(
         &mut self,
-        mut f: F,
+        f: F,
     ) -> String {
-        let name = T::type_name();
-        if !self.types.contains_key(name.as_ref()) {
+        self.create_type_with_name(&T::type_name(), std::any::type_name::<T>(), f);
+        T::qualified_type_name()
+    }
+
+    /// Like `create_type`, but registers the type with its input type name.
+    pub fn create_input_type<T: crate::InputValueType, F: FnMut(&mut Registry) -> MetaType>(
+        &mut self,
+        f: F,
+    ) -> String {
+        self.create_type_with_name(&T::input_type_name(), std::any::type_name::<T>(), f);
+        T::qualified_input_type_name()
+    }
+
+    fn create_type_with_name<F: FnMut(&mut Registry) -> MetaType>(
+        &mut self,
+        name: &str,
+        rust_type: &'static str,
+        mut f: F,
+    ) {
+        if let Some(ty) = self.types.get(name) {
+            // Several Rust types can be mapped to the same scalar, e.g. `i32` and `i64` to `Int`.
+            if let Some(prev_rust_type) = self.rust_types.get(name) {
+                if *prev_rust_type != rust_type && !matches!(ty, MetaType::Scalar { .. }) {
+                    panic!(
+                        "The types \"{}\" and \"{}\" have the same GraphQL name \"{}\".",
+                        prev_rust_type, rust_type, name
+                    );
+                }
+            }
+        } else {
+            self.rust_types.insert(name.to_string(), rust_type);
+            // Insert a placeholder first, so that recursive types and types referencing each other
+            // are only created once.
             self.types.insert(
                 name.to_string(),
                 MetaType::Object {
@@ -362,12 +464,61 @@ impl Registry {
                     cache_control: Default::default(),
                     extends: false,
                     keys: None,
+                    visible: None,
+                    directives: Default::default(),
                 },
             );
             let ty = f(self);
             self.types.insert(name.to_string(), ty);
         }
-        T::qualified_type_name()
+    }
+
+    /// Add the fields of the object type `T` to the fields of the merged object `type_name`.
+    ///
+    /// `T` is not added to the schema, unless it is also used by another type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has a field with the same name as an existing field.
+    pub fn merge_object_fields<T: crate::Type>(
+        &mut self,
+        type_name: &str,
+        fields: &mut IndexMap<String, MetaField>,
+        cache_control: &mut CacheControl,
+    ) {
+        let obj_type_name = T::type_name();
+        let obj_type = if self.types.contains_key(obj_type_name.as_ref()) {
+            match &self.types[obj_type_name.as_ref()] {
+                MetaType::Object {
+                    fields,
+                    cache_control,
+                    ..
+                } => Some((fields.clone(), *cache_control)),
+                _ => None,
+            }
+        } else {
+            T::create_type_info(self);
+            match self.types.remove(obj_type_name.as_ref()) {
+                Some(MetaType::Object {
+                    fields,
+                    cache_control,
+                    ..
+                }) => Some((fields, cache_control)),
+                _ => None,
+            }
+        };
+        if let Some((obj_fields, obj_cache_control)) = obj_type {
+            for (name, field) in obj_fields {
+                if fields.contains_key(&name) {
+                    panic!(
+                        "Field \"{}\" of the merged object \"{}\" is defined by more than one object.",
+                        name, type_name
+                    );
+                }
+                fields.insert(name, field);
+            }
+            cache_control.merge(&obj_cache_control);
+        }
     }
 
     pub fn add_directive(&mut self, directive: MetaDirective) {
@@ -388,6 +539,117 @@ impl Registry {
             });
     }
 
+    /// Check that every interface implemented by another interface is correctly implemented.
+    ///
+    /// The implementing interface must define all the fields of the implemented interface and
+    /// implement its interfaces too, and all of its possible types must also be possible types
+    /// of the implemented interface.
+    /// Mark the field of the interface as having a default implementation, the possible types of
+    /// the interface don't need to define it.
+    pub fn add_default_field(&mut self, interface: &str, field: &str) {
+        self.default_fields
+            .entry(interface.to_string())
+            .or_default()
+            .insert(field.to_string());
+    }
+
+    pub(crate) fn check_interface_implements(&self) {
+        for (name, interfaces) in &self.implements {
+            let (kind, fields, possible_types) = match self.types.get(name) {
+                Some(MetaType::Interface {
+                    fields,
+                    possible_types,
+                    ..
+                }) => ("Interface", fields, Some(possible_types)),
+                Some(MetaType::Object { fields, .. }) => ("Type", fields, None),
+                _ => continue,
+            };
+
+            for interface in interfaces {
+                if interface == name {
+                    panic!("Interface \"{}\" can't implement itself.", name);
+                }
+
+                let (interface_fields, interface_possible_types) = match self.types.get(interface) {
+                    Some(MetaType::Interface {
+                        fields,
+                        possible_types,
+                        ..
+                    }) => (fields, possible_types),
+                    _ => panic!(
+                        "{} \"{}\" implements \"{}\", which is not an interface.",
+                        kind, name, interface
+                    ),
+                };
+
+                for (field_name, interface_field) in interface_fields {
+                    let field = match fields.get(field_name) {
+                        Some(field) => field,
+                        None if kind == "Type"
+                            && self
+                                .default_fields
+                                .get(interface)
+                                .map(|fields| fields.contains(field_name))
+                                .unwrap_or_default() =>
+                        {
+                            continue
+                        }
+                        None => panic!(
+                            "{} \"{}\" implements \"{}\", but does not define the field \"{}\".",
+                            kind, name, interface, field_name
+                        ),
+                    };
+
+                    for (arg_name, interface_arg) in &interface_field.args {
+                        match field.args.get(arg_name) {
+                            Some(arg) if arg.ty == interface_arg.ty => {}
+                            Some(arg) => panic!(
+                                "Field \"{}.{}\" has the argument \"{}\" of type \"{}\", but the interface \"{}\" defines it as \"{}\".",
+                                name, field_name, arg_name, arg.ty, interface, interface_arg.ty
+                            ),
+                            None => panic!(
+                                "Field \"{}.{}\" does not define the argument \"{}\" of the interface \"{}\".",
+                                name, field_name, arg_name, interface
+                            ),
+                        }
+                    }
+
+                    for (arg_name, arg) in &field.args {
+                        if !interface_field.args.contains_key(arg_name)
+                            && arg.ty.ends_with('!')
+                            && arg.default_value.is_none()
+                        {
+                            panic!(
+                                "Field \"{}.{}\" has the required argument \"{}\", which is not defined by the interface \"{}\".",
+                                name, field_name, arg_name, interface
+                            );
+                        }
+                    }
+                }
+
+                if let Some(possible_types) = possible_types {
+                    for transitive in self.implements.get(interface).into_iter().flatten() {
+                        if !interfaces.contains(transitive) {
+                            panic!(
+                                "Interface \"{}\" implements \"{}\", so it must also implement \"{}\".",
+                                name, interface, transitive
+                            );
+                        }
+                    }
+
+                    for ty in possible_types {
+                        if !interface_possible_types.contains(ty) {
+                            panic!(
+                                "Type \"{}\" implements \"{}\", so it must also implement \"{}\".",
+                                ty, name, interface
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     pub fn add_keys(&mut self, ty: &str, keys: &str) {
         let all_keys = match self.types.get_mut(ty) {
             Some(MetaType::Object { keys: all_keys, .. }) => all_keys,
@@ -448,6 +710,9 @@ impl Registry {
             if let Some(provides) = field.provides {
                 write!(sdl, " @provides(fields: \"{}\")", provides).ok();
             }
+            for directive in &field.directives {
+                write!(sdl, " {}", directive.sdl()).ok();
+            }
             writeln!(sdl).ok();
         }
     }
@@ -465,6 +730,7 @@ impl Registry {
                 fields,
                 extends,
                 keys,
+                directives,
                 ..
             } => {
                 if name == &self.query_type && fields.len() == 4 {
@@ -490,6 +756,9 @@ impl Registry {
                         write!(sdl, "@key(fields: \"{}\") ", key).ok();
                     }
                 }
+                for directive in directives {
+                    write!(sdl, "{} ", directive.sdl()).ok();
+                }
                 writeln!(sdl, "{{").ok();
                 Self::create_federation_fields(sdl, fields.values());
                 writeln!(sdl, "}}").ok();
@@ -505,6 +774,11 @@ impl Registry {
                     write!(sdl, "extend ").ok();
                 }
                 write!(sdl, "interface {} ", name).ok();
+                if let Some(implements) = self.implements.get(name) {
+                    if !implements.is_empty() {
+                        write!(sdl, "implements {} ", implements.iter().join(" & ")).ok();
+                    }
+                }
                 if let Some(keys) = keys {
                     for key in keys {
                         write!(sdl, "@key(fields: \"{}\") ", key).ok();
@@ -515,9 +789,15 @@ impl Registry {
                 writeln!(sdl, "}}").ok();
             }
             MetaType::Enum {
-                name, enum_values, ..
+                name,
+                enum_values,
+                directives,
+                ..
             } => {
                 write!(sdl, "enum {} ", name).ok();
+                for directive in directives {
+                    write!(sdl, "{} ", directive.sdl()).ok();
+                }
                 writeln!(sdl, "{{").ok();
                 for value in enum_values.values() {
                     writeln!(sdl, "{}", value.name).ok();
@@ -525,9 +805,19 @@ impl Registry {
                 writeln!(sdl, "}}").ok();
             }
             MetaType::InputObject {
-                name, input_fields, ..
+                name,
+                input_fields,
+                directives,
+                oneof,
+                ..
             } => {
                 write!(sdl, "input {} ", name).ok();
+                if *oneof {
+                    write!(sdl, "@oneOf ").ok();
+                }
+                for directive in directives {
+                    write!(sdl, "{} ", directive.sdl()).ok();
+                }
                 writeln!(sdl, "{{").ok();
                 for field in input_fields.values() {
                     writeln!(sdl, "{}", federation_input_value(&field)).ok();
@@ -552,6 +842,17 @@ impl Registry {
 
     pub fn create_federation_sdl(&self) -> String {
         let mut sdl = String::new();
+        if self.federation_v2 {
+            writeln!(
+                sdl,
+                "extend schema @link(url: \"https://specs.apollo.dev/federation/v2.0\", import: [{}])",
+                FEDERATION_V2_DIRECTIVES
+                    .iter()
+                    .map(|name| format!("\"@{}\"", name))
+                    .join(", ")
+            )
+            .ok();
+        }
         for ty in self.types.values() {
             if ty.name().starts_with("__") {
                 continue;
@@ -565,6 +866,212 @@ impl Registry {
         sdl
     }
 
+    /// Export the schema in SDL, including descriptions, deprecations, default values and
+    /// applied directives.
+    ///
+    /// Types and directives are sorted by name so that the output is stable.
+    pub fn export_sdl(&self) -> String {
+        const BUILTIN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+        const BUILTIN_DIRECTIVES: &[&str] = &["include", "skip", "deprecated", "oneOf"];
+
+        let mut sdl = String::new();
+
+        writeln!(sdl, "schema {{").ok();
+        writeln!(sdl, "  query: {}", self.query_type).ok();
+        if let Some(mutation_type) = &self.mutation_type {
+            writeln!(sdl, "  mutation: {}", mutation_type).ok();
+        }
+        if let Some(subscription_type) = &self.subscription_type {
+            writeln!(sdl, "  subscription: {}", subscription_type).ok();
+        }
+        writeln!(sdl, "}}").ok();
+
+        for directive in self
+            .directives
+            .values()
+            .filter(|directive| !BUILTIN_DIRECTIVES.contains(&directive.name))
+            .sorted_by_key(|directive| directive.name)
+        {
+            writeln!(sdl).ok();
+            write_description(&mut sdl, directive.description, "");
+            write!(sdl, "directive @{}", directive.name).ok();
+            if !directive.args.is_empty() {
+                write!(
+                    sdl,
+                    "({})",
+                    directive.args.values().map(export_argument).join(", ")
+                )
+                .ok();
+            }
+            writeln!(
+                sdl,
+                " on {}",
+                directive
+                    .locations
+                    .iter()
+                    .map(|location| format!("{:?}", location))
+                    .join(" | ")
+            )
+            .ok();
+        }
+
+        for ty in self
+            .types
+            .values()
+            .filter(|ty| !ty.name().starts_with("__") && !BUILTIN_SCALARS.contains(&ty.name()))
+            .sorted_by_key(|ty| ty.name())
+        {
+            writeln!(sdl).ok();
+            self.export_type(ty, &mut sdl);
+        }
+
+        sdl
+    }
+
+    fn export_type(&self, ty: &MetaType, sdl: &mut String) {
+        match ty {
+            MetaType::Scalar {
+                name, description, ..
+            } => {
+                write_description(sdl, *description, "");
+                writeln!(sdl, "scalar {}", name).ok();
+            }
+            MetaType::Object {
+                name,
+                description,
+                fields,
+                extends,
+                keys,
+                ..
+            }
+            | MetaType::Interface {
+                name,
+                description,
+                fields,
+                extends,
+                keys,
+                ..
+            } => {
+                write_description(sdl, description.as_deref(), "");
+                if *extends {
+                    write!(sdl, "extend ").ok();
+                }
+                match ty {
+                    MetaType::Object { .. } => write!(sdl, "type {}", name).ok(),
+                    _ => write!(sdl, "interface {}", name).ok(),
+                };
+                if let Some(implements) = self.implements.get(name) {
+                    if !implements.is_empty() {
+                        write!(
+                            sdl,
+                            " implements {}",
+                            implements.iter().sorted().join(" & ")
+                        )
+                        .ok();
+                    }
+                }
+                if let Some(keys) = keys {
+                    for key in keys {
+                        write!(sdl, " @key(fields: {})", quote_string(key)).ok();
+                    }
+                }
+                for directive in ty.directives() {
+                    write!(sdl, " {}", directive.sdl()).ok();
+                }
+                writeln!(sdl, " {{").ok();
+                for field in fields
+                    .values()
+                    .filter(|field| !field.name.starts_with("__"))
+                {
+                    write_description(sdl, field.description.as_deref(), "  ");
+                    write!(sdl, "  {}", field.name).ok();
+                    if !field.args.is_empty() {
+                        write!(
+                            sdl,
+                            "({})",
+                            field.args.values().map(export_argument).join(", ")
+                        )
+                        .ok();
+                    }
+                    write!(sdl, ": {}", field.ty).ok();
+                    write_deprecation(sdl, field.deprecation);
+                    if field.external {
+                        write!(sdl, " @external").ok();
+                    }
+                    if let Some(requires) = field.requires {
+                        write!(sdl, " @requires(fields: {})", quote_string(requires)).ok();
+                    }
+                    if let Some(provides) = field.provides {
+                        write!(sdl, " @provides(fields: {})", quote_string(provides)).ok();
+                    }
+                    for directive in &field.directives {
+                        write!(sdl, " {}", directive.sdl()).ok();
+                    }
+                    writeln!(sdl).ok();
+                }
+                writeln!(sdl, "}}").ok();
+            }
+            MetaType::Union {
+                name,
+                description,
+                possible_types,
+            } => {
+                write_description(sdl, *description, "");
+                writeln!(
+                    sdl,
+                    "union {} = {}",
+                    name,
+                    possible_types.iter().join(" | ")
+                )
+                .ok();
+            }
+            MetaType::Enum {
+                name,
+                description,
+                enum_values,
+                directives,
+                ..
+            } => {
+                write_description(sdl, *description, "");
+                write!(sdl, "enum {}", name).ok();
+                for directive in directives {
+                    write!(sdl, " {}", directive.sdl()).ok();
+                }
+                writeln!(sdl, " {{").ok();
+                for value in enum_values.values() {
+                    write_description(sdl, value.description, "  ");
+                    write!(sdl, "  {}", value.name).ok();
+                    write_deprecation(sdl, value.deprecation);
+                    writeln!(sdl).ok();
+                }
+                writeln!(sdl, "}}").ok();
+            }
+            MetaType::InputObject {
+                name,
+                description,
+                input_fields,
+                directives,
+                oneof,
+                ..
+            } => {
+                write_description(sdl, *description, "");
+                write!(sdl, "input {}", name).ok();
+                if *oneof {
+                    write!(sdl, " @oneOf").ok();
+                }
+                for directive in directives {
+                    write!(sdl, " {}", directive.sdl()).ok();
+                }
+                writeln!(sdl, " {{").ok();
+                for field in input_fields.values() {
+                    write_description(sdl, field.description.as_deref(), "  ");
+                    writeln!(sdl, "  {}", export_input_value(field)).ok();
+                }
+                writeln!(sdl, "}}").ok();
+            }
+        }
+    }
+
     pub(crate) fn has_entities(&self) -> bool {
         self.types.values().any(|ty| match ty {
             MetaType::Object {
@@ -628,6 +1135,8 @@ impl Registry {
                             external: false,
                             requires: None,
                             provides: None,
+                            visible: None,
+                            directives: Default::default(),
                         },
                     );
                     fields
@@ -635,6 +1144,8 @@ impl Registry {
                 cache_control: Default::default(),
                 extends: false,
                 keys: None,
+                visible: None,
+                directives: Default::default(),
             },
         );
 
@@ -654,6 +1165,8 @@ impl Registry {
                     external: false,
                     requires: None,
                     provides: None,
+                    visible: None,
+                    directives: Default::default(),
                 },
             );
 
@@ -665,13 +1178,16 @@ impl Registry {
                     args: {
                         let mut args = IndexMap::new();
                         args.insert(
-                            "representations",
+                            "representations".to_string(),
                             MetaInputValue {
-                                name: "representations",
+                                name: "representations".to_string(),
                                 description: None,
                                 ty: "[_Any!]!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                visible: None,
+                                directives: Default::default(),
+                                is_secret: false,
                             },
                         );
                         args
@@ -682,6 +1198,8 @@ impl Registry {
                     external: false,
                     requires: None,
                     provides: None,
+                    visible: None,
+                    directives: Default::default(),
                 },
             );
         }
@@ -689,12 +1207,69 @@ impl Registry {
 }
 
 fn federation_input_value(input_value: &MetaInputValue) -> String {
-    if let Some(default_value) = &input_value.default_value {
+    let mut sdl = if let Some(default_value) = &input_value.default_value {
         format!(
             "{}: {} = {}",
             input_value.name, input_value.ty, default_value
         )
     } else {
         format!("{}: {}", input_value.name, input_value.ty)
+    };
+    for directive in &input_value.directives {
+        write!(sdl, " {}", directive.sdl()).ok();
+    }
+    sdl
+}
+
+const FEDERATION_V2_DIRECTIVES: &[&str] = &[
+    "key",
+    "requires",
+    "provides",
+    "external",
+    "shareable",
+    "override",
+    "inaccessible",
+    "tag",
+];
+
+fn quote_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap()
+}
+
+fn write_description(sdl: &mut String, description: Option<&str>, indent: &str) {
+    if let Some(description) = description {
+        if description.contains('\n') {
+            writeln!(sdl, "{}\"\"\"", indent).ok();
+            for line in description.replace("\"\"\"", "\\\"\"\"").lines() {
+                writeln!(sdl, "{}{}", indent, line).ok();
+            }
+            writeln!(sdl, "{}\"\"\"", indent).ok();
+        } else {
+            writeln!(sdl, "{}{}", indent, quote_string(description)).ok();
+        }
+    }
+}
+
+fn write_deprecation(sdl: &mut String, deprecation: Option<&str>) {
+    if let Some(reason) = deprecation {
+        write!(sdl, " @deprecated(reason: {})", quote_string(reason)).ok();
+    }
+}
+
+fn export_argument(arg: &MetaInputValue) -> String {
+    match &arg.description {
+        Some(description) => format!("{} {}", quote_string(description), export_input_value(arg)),
+        None => export_input_value(arg),
+    }
+}
+
+fn export_input_value(input_value: &MetaInputValue) -> String {
+    let mut sdl = format!("{}: {}", input_value.name, input_value.ty);
+    if let Some(default_value) = &input_value.default_value {
+        write!(sdl, " = {}", default_value).ok();
+    }
+    for directive in &input_value.directives {
+        write!(sdl, " {}", directive.sdl()).ok();
     }
+    sdl
 }
@@ -0,0 +1,362 @@
+//! Remote schema delegation
+//!
+//! A field of the local schema can be delegated to a field of the query or mutation root of a
+//! remote GraphQL service. The field, its arguments and its sub-selection are forwarded with the
+//! variables and fragments they use, and the remote response is spliced into the local result.
+//! The paths of the remote errors are rewritten to the paths of the local response. If the remote
+//! service returns partial data, it is spliced and the errors are added to the
+//! [`QueryResponse::errors`](../struct.QueryResponse.html#structfield.errors) of the response,
+//! otherwise the query fails with all the remote errors. A subscription event has no errors
+//! besides its data, so it fails with the remote errors in both cases.
+//!
+//! The request is sent by a [`RemoteTransport`](trait.RemoteTransport.html), so any HTTP client
+//! can be used.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::remote::{delegate, Remote, RemoteTransport};
+//!
+//! /// The local definition of the remote `User` type.
+//! #[SimpleObject]
+//! struct User {
+//!     name: String,
+//! }
+//!
+//! struct UserService;
+//!
+//! #[async_trait::async_trait]
+//! impl RemoteTransport for UserService {
+//!     async fn send(&self, request: serde_json::Value) -> FieldResult<serde_json::Value> {
+//!         // Send the request to the remote service, e.g. with an HTTP client.
+//!         assert_eq!(request["query"], "query($id: Int!) { u: user(id: $id) { name } }");
+//!         assert_eq!(request["variables"], serde_json::json!({ "id": 1 }));
+//!         Ok(serde_json::json!({ "data": { "u": { "name": "jack" } } }))
+//!     }
+//! }
+//!
+//! struct QueryRoot;
+//!
+//! #[Object]
+//! impl QueryRoot {
+//!     async fn user(&self, ctx: &Context<'_>, _id: i32) -> FieldResult<Remote<User>> {
+//!         delegate(ctx, &UserService).await
+//!     }
+//! }
+//!
+//! async_std::task::block_on(async move {
+//!     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+//!     let res = QueryBuilder::new("query($id: Int!) { u: user(id: $id) { name } }")
+//!         .variables(Variables::parse_from_json(serde_json::json!({ "id": 1 })).unwrap())
+//!         .execute(&schema)
+//!         .await
+//!         .unwrap();
+//!     assert_eq!(res.data, serde_json::json!({ "u": { "name": "jack" } }));
+//! });
+//! ```
+
+use crate::parser::query::{
+    Directive, Field, FragmentDefinition, OperationType, Selection, SelectionSet, TypeCondition,
+};
+use crate::{
+    registry, Context, ContextSelectionSet, Error, FieldResult, OutputValueType, Positioned,
+    Result, ServerError, Type, Value,
+};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::marker::PhantomData;
+
+/// Sends the requests of delegated fields to a remote GraphQL service
+#[async_trait::async_trait]
+pub trait RemoteTransport: Send + Sync {
+    /// Send a request with the `query` and the `variables`, returns the response of the remote
+    /// service, a JSON object with `data` and `errors`.
+    async fn send(&self, request: serde_json::Value) -> FieldResult<serde_json::Value>;
+}
+
+/// The response of a delegated field
+///
+/// `T` is the local definition of the type of the remote field, it is only used to register the
+/// type in the local schema.
+pub struct Remote<T> {
+    data: serde_json::Value,
+    errors: Vec<serde_json::Value>,
+    _mark: PhantomData<T>,
+}
+
+impl<T: Type> Type for Remote<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Type + Send + Sync> OutputValueType for Remote<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        if self.errors.is_empty() {
+            return Ok(self.data.clone());
+        }
+
+        // The first segment of the remote paths is the delegated field itself.
+        let path = ctx
+            .path_node
+            .as_ref()
+            .map(|path| path.to_json())
+            .unwrap_or_default();
+        let errors = self
+            .errors
+            .iter()
+            .map(|error| {
+                let mut local_path = path.clone();
+                if let Some(serde_json::Value::Array(remote_path)) = error.get("path") {
+                    local_path.extend(remote_path.iter().skip(1).cloned());
+                }
+                ServerError {
+                    message: match error.get("message") {
+                        Some(serde_json::Value::String(message)) => message.clone(),
+                        Some(message) => message.to_string(),
+                        None => String::new(),
+                    },
+                    locations: vec![field.position()],
+                    path: local_path,
+                    extensions: error
+                        .get("extensions")
+                        .filter(|extensions| extensions.is_object())
+                        .cloned(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if self.data.is_null() {
+            return Err(Error::Remote { errors });
+        }
+        ctx.errors.add(errors);
+        Ok(self.data.clone())
+    }
+}
+
+/// Delegate the current field to the field with the same name of the remote service.
+pub async fn delegate<T: Type>(
+    ctx: &Context<'_>,
+    transport: &dyn RemoteTransport,
+) -> FieldResult<Remote<T>> {
+    let field = ctx.item;
+    let response_key = ctx.result_name().to_string();
+    let operation = ctx.query_env.document.current_operation();
+
+    // The directives of the delegated field are executed locally, so they are not forwarded.
+    let mut variables = HashSet::new();
+    let mut fragments = HashSet::new();
+    for (_, value) in &field.arguments {
+        collect_variables(value, &mut variables);
+    }
+    collect_selection_set(ctx, &field.selection_set, &mut variables, &mut fragments);
+
+    let mut query = String::new();
+    query.push_str(match operation.ty {
+        OperationType::Mutation => "mutation",
+        _ => "query",
+    });
+    let variable_definitions = operation
+        .variable_definitions
+        .iter()
+        .filter(|def| variables.contains(def.name.as_str()))
+        .collect::<Vec<_>>();
+    if !variable_definitions.is_empty() {
+        query.push('(');
+        for (idx, def) in variable_definitions.iter().enumerate() {
+            if idx > 0 {
+                query.push_str(", ");
+            }
+            write!(query, "${}: {}", def.name.node, def.var_type.node).unwrap();
+            if let Some(default_value) = &def.default_value {
+                write!(query, " = {}", default_value.node).unwrap();
+            }
+        }
+        query.push(')');
+    }
+    query.push_str(" { ");
+    write!(query, "{}: {}", response_key, field.name.node).unwrap();
+    write_arguments(&mut query, &field.arguments);
+    write_selection_set(&mut query, &field.selection_set);
+    query.push_str(" }");
+
+    let mut fragments = fragments.into_iter().collect::<Vec<_>>();
+    fragments.sort_unstable();
+    for name in fragments {
+        if let Some(fragment) = ctx.query_env.document.fragments().get(name) {
+            query.push(' ');
+            write_fragment(&mut query, fragment);
+        }
+    }
+
+    let request = serde_json::json!({
+        "query": query,
+        "variables": variable_definitions
+            .iter()
+            .filter_map(|def| {
+                ctx.query_env
+                    .variables
+                    .get(def.name.as_str())
+                    .map(|value| (def.name.to_string(), serde_json::Value::from(value.clone())))
+            })
+            .collect::<serde_json::Map<_, _>>(),
+    });
+    let mut response = transport.send(request).await?;
+
+    Ok(Remote {
+        data: response
+            .get_mut("data")
+            .and_then(|data| data.get_mut(&response_key))
+            .map(serde_json::Value::take)
+            .unwrap_or_default(),
+        errors: match response.get_mut("errors").map(serde_json::Value::take) {
+            Some(serde_json::Value::Array(errors)) => errors,
+            _ => Vec::new(),
+        },
+        _mark: PhantomData,
+    })
+}
+
+fn collect_field<'a>(
+    ctx: &'a Context<'_>,
+    field: &'a Field,
+    variables: &mut HashSet<&'a str>,
+    fragments: &mut HashSet<&'a str>,
+) {
+    for (_, value) in &field.arguments {
+        collect_variables(value, variables);
+    }
+    collect_directives(&field.directives, variables);
+    collect_selection_set(ctx, &field.selection_set, variables, fragments);
+}
+
+fn collect_selection_set<'a>(
+    ctx: &'a Context<'_>,
+    selection_set: &'a SelectionSet,
+    variables: &mut HashSet<&'a str>,
+    fragments: &mut HashSet<&'a str>,
+) {
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => collect_field(ctx, field, variables, fragments),
+            Selection::FragmentSpread(fragment_spread) => {
+                collect_directives(&fragment_spread.directives, variables);
+                let name = fragment_spread.fragment_name.as_str();
+                if fragments.insert(name) {
+                    if let Some(fragment) = ctx.query_env.document.fragments().get(name) {
+                        collect_directives(&fragment.directives, variables);
+                        collect_selection_set(ctx, &fragment.selection_set, variables, fragments);
+                    }
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                collect_directives(&inline_fragment.directives, variables);
+                collect_selection_set(ctx, &inline_fragment.selection_set, variables, fragments);
+            }
+        }
+    }
+}
+
+fn collect_directives<'a>(
+    directives: &'a [Positioned<Directive>],
+    variables: &mut HashSet<&'a str>,
+) {
+    for directive in directives {
+        for (_, value) in &directive.arguments {
+            collect_variables(value, variables);
+        }
+    }
+}
+
+fn collect_variables<'a>(value: &'a Value, variables: &mut HashSet<&'a str>) {
+    match value {
+        Value::Variable(name) => {
+            variables.insert(name.as_str());
+        }
+        Value::List(items) => items
+            .iter()
+            .for_each(|item| collect_variables(item, variables)),
+        Value::Object(obj) => obj
+            .values()
+            .for_each(|item| collect_variables(item, variables)),
+        _ => {}
+    }
+}
+
+fn write_arguments(query: &mut String, arguments: &[(Positioned<String>, Positioned<Value>)]) {
+    if arguments.is_empty() {
+        return;
+    }
+    query.push('(');
+    for (idx, (name, value)) in arguments.iter().enumerate() {
+        if idx > 0 {
+            query.push_str(", ");
+        }
+        write!(query, "{}: {}", name.node, value.node).unwrap();
+    }
+    query.push(')');
+}
+
+fn write_directives(query: &mut String, directives: &[Positioned<Directive>]) {
+    for directive in directives {
+        write!(query, " @{}", directive.name.node).unwrap();
+        write_arguments(query, &directive.arguments);
+    }
+}
+
+fn write_selection_set(query: &mut String, selection_set: &SelectionSet) {
+    if selection_set.items.is_empty() {
+        return;
+    }
+    query.push_str(" {");
+    for selection in &selection_set.items {
+        query.push(' ');
+        match &selection.node {
+            Selection::Field(field) => {
+                if let Some(alias) = &field.alias {
+                    write!(query, "{}: ", alias.node).unwrap();
+                }
+                query.push_str(&field.name.node);
+                write_arguments(query, &field.arguments);
+                write_directives(query, &field.directives);
+                write_selection_set(query, &field.selection_set);
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                write!(query, "...{}", fragment_spread.fragment_name.node).unwrap();
+                write_directives(query, &fragment_spread.directives);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                query.push_str("...");
+                if let Some(type_condition) = &inline_fragment.type_condition {
+                    let TypeCondition::On(name) = &type_condition.node;
+                    write!(query, " on {}", name.node).unwrap();
+                }
+                write_directives(query, &inline_fragment.directives);
+                write_selection_set(query, &inline_fragment.selection_set);
+            }
+        }
+    }
+    query.push_str(" }");
+}
+
+fn write_fragment(query: &mut String, fragment: &FragmentDefinition) {
+    let TypeCondition::On(name) = &fragment.type_condition.node;
+    write!(query, "fragment {} on {}", fragment.name.node, name.node).unwrap();
+    write_directives(query, &fragment.directives);
+    write_selection_set(query, &fragment.selection_set);
+}
@@ -1,8 +1,11 @@
 use crate::base::BoxFieldFuture;
+use crate::directive::DirectiveContext;
 use crate::extensions::{Extension, ResolveInfo};
 use crate::parser::query::{Selection, TypeCondition};
-use crate::{ContextSelectionSet, Error, ObjectType, QueryError, Result};
+use crate::{Context, ContextSelectionSet, Error, ObjectExtension, ObjectType, QueryError, Result};
+use futures::future::BoxFuture;
 use futures::{future, TryFutureExt};
+use std::any::Any;
 
 #[allow(missing_docs)]
 pub async fn do_resolve<'a, T: ObjectType + Send + Sync>(
@@ -98,7 +101,7 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
                         ctx_field.query_env.extensions.resolve_start(&resolve_info);
 
                         let res = ctx_field.query_env.extensions.log_error(
-                            root.resolve_field(&ctx_field)
+                            resolve_field(&ctx_field, root)
                                 .map_ok(move |value| (field_name, value))
                                 .await,
                         )?;
@@ -158,3 +161,97 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
 
     Ok(())
 }
+
+/// Resolve a field of `root`, applying the custom directives of the field.
+pub(crate) async fn resolve_field<T: ObjectType + Send + Sync>(
+    ctx: &Context<'_>,
+    root: &T,
+) -> Result<serde_json::Value> {
+    let directives = ctx
+        .item
+        .directives
+        .iter()
+        .filter_map(|directive| {
+            ctx.schema_env
+                .custom_directives
+                .get(directive.name.node.as_str())
+                .map(|handler| (DirectiveContext { ctx, directive }, handler))
+        })
+        .collect::<Vec<_>>();
+    if directives.is_empty() {
+        return resolve_object_field(ctx, root).await;
+    }
+
+    let mut value = None;
+    for (directive_ctx, handler) in &directives {
+        value = handler.before_resolve(directive_ctx).await.map_err(|err| {
+            err.into_error_with_path(
+                directive_ctx.directive.position(),
+                ctx.path_node.as_ref().unwrap().to_json(),
+            )
+        })?;
+        if value.is_some() {
+            break;
+        }
+    }
+    let mut value = match value {
+        Some(value) => value,
+        None => resolve_object_field(ctx, root).await?,
+    };
+    for (directive_ctx, handler) in &directives {
+        value = handler
+            .after_resolve(directive_ctx, value)
+            .await
+            .map_err(|err| {
+                err.into_error_with_path(
+                    directive_ctx.directive.position(),
+                    ctx.path_node.as_ref().unwrap().to_json(),
+                )
+            })?;
+    }
+    Ok(value)
+}
+
+/// Resolves a field added to an object by an `ObjectExtension`.
+pub(crate) type ExtensionResolver = for<'a> fn(
+    &'a (dyn Any + Sync),
+    &'a Context<'a>,
+) -> Option<BoxFuture<'a, Result<serde_json::Value>>>;
+
+pub(crate) fn resolve_extension_field<'a, E: ObjectExtension>(
+    target: &'a (dyn Any + Sync),
+    ctx: &'a Context<'a>,
+) -> Option<BoxFuture<'a, Result<serde_json::Value>>> {
+    let target: &dyn Any = target;
+    let extension = E::from_target(target.downcast_ref::<E::Target>()?);
+    Some(Box::pin(async move { extension.resolve_field(ctx).await }))
+}
+
+/// Resolve a field of `root`, or of the extensions of its type if `root` doesn't have it.
+async fn resolve_object_field<T: ObjectType + Send + Sync>(
+    ctx: &Context<'_>,
+    root: &T,
+) -> Result<serde_json::Value> {
+    let res = root.resolve_field(ctx).await;
+    if let Err(Error::Query {
+        err: QueryError::FieldNotFound { .. },
+        ..
+    }) = &res
+    {
+        let extensions = ctx.schema_env.type_extensions.get(T::type_name().as_ref());
+        if let (Some(extensions), Some(target)) = (extensions, root.as_any()) {
+            for resolve in extensions {
+                if let Some(fut) = resolve(target, ctx) {
+                    match fut.await {
+                        Err(Error::Query {
+                            err: QueryError::FieldNotFound { .. },
+                            ..
+                        }) => {}
+                        res => return res,
+                    }
+                }
+            }
+        }
+    }
+    res
+}
@@ -1,6 +1,9 @@
 use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
-use bson::{oid::ObjectId, DateTime as UtcDateTime};
+use bson::oid::ObjectId;
+#[cfg(feature = "chrono")]
+use bson::DateTime as UtcDateTime;
+#[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
 
 #[Scalar(internal)]
@@ -17,6 +20,7 @@ impl ScalarType for ObjectId {
     }
 }
 
+#[cfg(feature = "chrono")]
 #[Scalar(internal, name = "DateTime")]
 impl ScalarType for UtcDateTime {
     fn parse(value: Value) -> InputValueResult<Self> {
@@ -1,18 +1,22 @@
-use crate::{InputValueError, InputValueResult, Result, ScalarType, Value};
+use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
 use chrono_tz::Tz;
 use std::str::FromStr;
 
+/// Implement the TimeZone scalar
+///
+/// The input/output is the name of an IANA time zone, e.g. `America/New_York`.
 #[Scalar(internal, name = "TimeZone")]
 impl ScalarType for Tz {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
-            Value::String(s) => Ok(Tz::from_str(&s)?),
+            Value::String(s) => Tz::from_str(&s)
+                .map_err(|_| InputValueError::Custom(format!("Invalid time zone \"{}\".", s))),
             _ => Err(InputValueError::ExpectedType(value)),
         }
     }
 
     fn to_value(&self) -> Value {
-        Value::String(Tz::name(self))
+        Value::String(self.name().to_string())
     }
 }
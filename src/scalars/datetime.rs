@@ -1,6 +1,6 @@
 use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
 
 /// Implement the DateTime<Utc> scalar
 ///
@@ -18,3 +18,20 @@ impl ScalarType for DateTime<Utc> {
         Value::String(self.to_rfc3339())
     }
 }
+
+/// Implement the DateTime<FixedOffset> scalar
+///
+/// The input/output is a string in RFC3339 format, the offset of the input is preserved.
+#[Scalar(internal, name = "DateTimeFixedOffset")]
+impl ScalarType for DateTime<FixedOffset> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Ok(DateTime::parse_from_rfc3339(&s)?),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_rfc3339())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{InputValueError, InputValueResult, ScalarType, Value};
+use async_graphql_derive::Scalar;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Implement the Decimal scalar
+///
+/// The output is a string, so no precision is lost. The input is a string, or an integer.
+/// Floats are rejected because they may not be exact.
+#[Scalar(internal)]
+impl ScalarType for Decimal {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Decimal::from_str(&s).map_err(|err| {
+                InputValueError::Custom(format!("Invalid Decimal \"{}\": {}", s, err))
+            }),
+            Value::Int(n) => Ok(Decimal::from(n)),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => Decimal::from_str(s).is_ok(),
+            Value::Int(_) => true,
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
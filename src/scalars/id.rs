@@ -49,6 +49,7 @@ impl TryFrom<ID> for usize {
     }
 }
 
+#[cfg(feature = "uuid")]
 impl TryFrom<ID> for uuid::Uuid {
     type Error = uuid::Error;
 
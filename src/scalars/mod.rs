@@ -1,21 +1,34 @@
 mod any;
 mod bool;
-mod datetime;
 mod floats;
 mod id;
 mod integers;
 mod json;
-mod naive_date;
-mod naive_time;
+mod serde_scalar;
 mod string;
-mod uuid;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "bson")]
 mod bson;
-#[cfg(feature = "chrono_tz")]
+#[cfg(feature = "chrono-tz")]
 mod chrono_tz;
+#[cfg(feature = "chrono")]
+mod datetime;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "chrono")]
+mod naive_date;
+#[cfg(feature = "chrono")]
+mod naive_datetime;
+#[cfg(feature = "chrono")]
+mod naive_time;
+#[cfg(feature = "time")]
+mod time;
 #[cfg(feature = "url")]
 mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 pub use any::Any;
 pub use id::ID;
@@ -26,7 +39,9 @@ mod tests {
     use super::ID;
     use crate::Type;
     use bson::oid::ObjectId;
-    use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+    #[cfg(feature = "chrono")]
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    #[cfg(feature = "uuid")]
     use uuid::Uuid;
 
     #[test]
@@ -49,20 +64,47 @@ mod tests {
         assert_eq!(<ID as Type>::type_name(), "ID");
         assert_eq!(<ID as Type>::qualified_type_name(), "ID!");
 
-        assert_eq!(<NaiveDate as Type>::type_name(), "NaiveDate");
-        assert_eq!(<NaiveDate as Type>::qualified_type_name(), "NaiveDate!");
+        #[cfg(feature = "chrono")]
+        {
+            assert_eq!(<NaiveDate as Type>::type_name(), "NaiveDate");
+            assert_eq!(<NaiveDate as Type>::qualified_type_name(), "NaiveDate!");
 
-        assert_eq!(<NaiveTime as Type>::type_name(), "NaiveTime");
-        assert_eq!(<NaiveTime as Type>::qualified_type_name(), "NaiveTime!");
+            assert_eq!(<NaiveTime as Type>::type_name(), "NaiveTime");
+            assert_eq!(<NaiveTime as Type>::qualified_type_name(), "NaiveTime!");
 
-        assert_eq!(<DateTime::<Utc> as Type>::type_name(), "DateTimeUtc");
-        assert_eq!(
-            <DateTime::<Utc> as Type>::qualified_type_name(),
-            "DateTimeUtc!"
-        );
+            assert_eq!(<NaiveDateTime as Type>::type_name(), "NaiveDateTime");
+            assert_eq!(
+                <NaiveDateTime as Type>::qualified_type_name(),
+                "NaiveDateTime!"
+            );
 
-        assert_eq!(<Uuid as Type>::type_name(), "UUID");
-        assert_eq!(<Uuid as Type>::qualified_type_name(), "UUID!");
+            assert_eq!(<DateTime::<Utc> as Type>::type_name(), "DateTimeUtc");
+            assert_eq!(
+                <DateTime::<Utc> as Type>::qualified_type_name(),
+                "DateTimeUtc!"
+            );
+
+            assert_eq!(
+                <DateTime::<FixedOffset> as Type>::type_name(),
+                "DateTimeFixedOffset"
+            );
+            assert_eq!(
+                <DateTime::<FixedOffset> as Type>::qualified_type_name(),
+                "DateTimeFixedOffset!"
+            );
+        }
+
+        #[cfg(feature = "uuid")]
+        {
+            assert_eq!(<Uuid as Type>::type_name(), "UUID");
+            assert_eq!(<Uuid as Type>::qualified_type_name(), "UUID!");
+        }
+
+        #[cfg(feature = "chrono-tz")]
+        {
+            assert_eq!(<chrono_tz::Tz as Type>::type_name(), "TimeZone");
+            assert_eq!(<chrono_tz::Tz as Type>::qualified_type_name(), "TimeZone!");
+        }
 
         #[cfg(feature = "bson")]
         {
@@ -2,11 +2,16 @@ use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
 use chrono::NaiveDate;
 
+/// Implement the NaiveDate scalar
+///
+/// The input/output is a string in `%Y-%m-%d` format, e.g. `2024-01-31`.
 #[Scalar(internal)]
 impl ScalarType for NaiveDate {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
-            Value::String(s) => Ok(NaiveDate::parse_from_str(&s, "%Y-%m-%d")?),
+            Value::String(s) => NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(|err| {
+                InputValueError::Custom(format!("Invalid NaiveDate \"{}\": {}", s, err))
+            }),
             _ => Err(InputValueError::ExpectedType(value)),
         }
     }
@@ -0,0 +1,24 @@
+use crate::{InputValueError, InputValueResult, ScalarType, Value};
+use async_graphql_derive::Scalar;
+use chrono::NaiveDateTime;
+
+/// Implement the NaiveDateTime scalar
+///
+/// The input/output is a string in `%Y-%m-%dT%H:%M:%S%.f` format, e.g. `2024-01-31T13:45:00`, the fraction of the seconds is optional.
+#[Scalar(internal)]
+impl ScalarType for NaiveDateTime {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => {
+                NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S%.f").map_err(|err| {
+                    InputValueError::Custom(format!("Invalid NaiveDateTime \"{}\": {}", s, err))
+                })
+            }
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+    }
+}
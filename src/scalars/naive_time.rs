@@ -2,11 +2,16 @@ use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
 use chrono::NaiveTime;
 
+/// Implement the NaiveTime scalar
+///
+/// The input/output is a string in `%H:%M:%S` format, e.g. `13:45:00`.
 #[Scalar(internal)]
 impl ScalarType for NaiveTime {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
-            Value::String(s) => Ok(NaiveTime::parse_from_str(&s, "%H:%M:%S")?),
+            Value::String(s) => NaiveTime::parse_from_str(&s, "%H:%M:%S").map_err(|err| {
+                InputValueError::Custom(format!("Invalid NaiveTime \"{}\": {}", s, err))
+            }),
             _ => Err(InputValueError::ExpectedType(value)),
         }
     }
@@ -0,0 +1,108 @@
+/// Define a scalar for a type that implements `Serialize` and `Deserialize`
+///
+/// The scalar is parsed and serialized with serde, so any JSON value is accepted and returned.
+/// It is useful for opaque values such as configuration blobs. Because of the orphan rule, the
+/// type must be defined in the same crate, types of other crates can be wrapped in a newtype.
+///
+/// The name of the scalar defaults to the name of the type, a name and a description can be
+/// specified with `scalar!(Type, "Name", "Description")`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     threads: i32,
+///     verbose: bool,
+/// }
+///
+/// scalar!(Config, "AppConfig", "The application configuration");
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn config(&self, config: Config) -> Config {
+///         Config {
+///             threads: config.threads * 2,
+///             verbose: !config.verbose,
+///         }
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ config(config: { threads: 2, verbose: false }) }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "config": { "threads": 4, "verbose": true } }));
+/// });
+/// ```
+#[macro_export]
+macro_rules! scalar {
+    ($ty:ty, $name:expr, $desc:expr) => {
+        $crate::scalar_internal!($ty, $name, ::std::option::Option::Some($desc));
+    };
+    ($ty:ty, $name:expr) => {
+        $crate::scalar_internal!($ty, $name, ::std::option::Option::None);
+    };
+    ($ty:ty) => {
+        $crate::scalar_internal!($ty, ::std::stringify!($ty), ::std::option::Option::None);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! scalar_internal {
+    ($ty:ty, $name:expr, $desc:expr) => {
+        impl $crate::ScalarType for $ty {
+            fn parse(value: $crate::Value) -> $crate::InputValueResult<Self> {
+                ::std::result::Result::Ok($crate::serde_json::from_value(value.into())?)
+            }
+
+            fn to_value(&self) -> $crate::Value {
+                $crate::serde_json::to_value(self)
+                    .unwrap_or_else(|_| $crate::serde_json::Value::Null)
+                    .into()
+            }
+        }
+
+        impl $crate::Type for $ty {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed($name)
+            }
+
+            fn create_type_info(registry: &mut $crate::registry::Registry) -> String {
+                registry.create_type::<$ty, _>(|_| $crate::registry::MetaType::Scalar {
+                    name: ::std::string::ToString::to_string($name),
+                    description: $desc,
+                    is_valid: |value| <$ty as $crate::ScalarType>::is_valid(value),
+                })
+            }
+        }
+
+        impl $crate::InputValueType for $ty {
+            fn parse(
+                value: ::std::option::Option<$crate::Value>,
+            ) -> $crate::InputValueResult<Self> {
+                <$ty as $crate::ScalarType>::parse(value.unwrap_or_default())
+            }
+
+            fn to_value(&self) -> $crate::Value {
+                <$ty as $crate::ScalarType>::to_value(self)
+            }
+        }
+
+        #[$crate::async_trait::async_trait]
+        impl $crate::OutputValueType for $ty {
+            async fn resolve(
+                &self,
+                _: &$crate::ContextSelectionSet<'_>,
+                _field: &$crate::Positioned<$crate::parser::query::Field>,
+            ) -> $crate::Result<$crate::serde_json::Value> {
+                ::std::result::Result::Ok($crate::ScalarType::to_value(self).into())
+            }
+        }
+    };
+}
@@ -0,0 +1,58 @@
+use crate::{InputValueError, InputValueResult, ScalarType, Value};
+use async_graphql_derive::Scalar;
+use time::{Date, Format, OffsetDateTime, Time};
+
+/// Implement the OffsetDateTime scalar
+///
+/// The input/output is a string in RFC3339 format, e.g. `2024-01-31T13:45:00+08:00`.
+#[Scalar(internal)]
+impl ScalarType for OffsetDateTime {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => OffsetDateTime::parse(&s, Format::Rfc3339).map_err(|err| {
+                InputValueError::Custom(format!("Invalid OffsetDateTime \"{}\": {}", s, err))
+            }),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format(Format::Rfc3339))
+    }
+}
+
+/// Implement the Date scalar
+///
+/// The input/output is a string in `%Y-%m-%d` format, e.g. `2024-01-31`.
+#[Scalar(internal)]
+impl ScalarType for Date {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Date::parse(&s, "%Y-%m-%d")
+                .map_err(|err| InputValueError::Custom(format!("Invalid Date \"{}\": {}", s, err))),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format("%Y-%m-%d"))
+    }
+}
+
+/// Implement the Time scalar
+///
+/// The input/output is a string in `%H:%M:%S` format, e.g. `13:45:00`.
+#[Scalar(internal)]
+impl ScalarType for Time {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Time::parse(&s, "%H:%M:%S")
+                .map_err(|err| InputValueError::Custom(format!("Invalid Time \"{}\": {}", s, err))),
+            _ => Err(InputValueError::ExpectedType(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format("%H:%M:%S"))
+    }
+}
@@ -2,15 +2,27 @@ use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
 use url::Url;
 
+/// Implement the Url scalar
+///
+/// The input/output is a string of an absolute URL. Literal values are validated with the query,
+/// so an invalid URL is reported before the resolvers run.
 #[Scalar(internal)]
 impl ScalarType for Url {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
-            Value::String(s) => Ok(Url::parse(&s)?),
+            Value::String(s) => Url::parse(&s)
+                .map_err(|err| InputValueError::Custom(format!("Invalid Url \"{}\": {}", s, err))),
             _ => Err(InputValueError::ExpectedType(value)),
         }
     }
 
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(s) => Url::parse(s).is_ok(),
+            _ => false,
+        }
+    }
+
     fn to_value(&self) -> Value {
         Value::String(self.to_string())
     }
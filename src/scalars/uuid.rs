@@ -2,6 +2,10 @@ use crate::{InputValueError, InputValueResult, ScalarType, Value};
 use async_graphql_derive::Scalar;
 use uuid::Uuid;
 
+/// Implement the UUID scalar
+///
+/// The input is a string in any format accepted by `Uuid::parse_str`, e.g. hyphenated or simple,
+/// and the output is the hyphenated string.
 #[Scalar(internal, name = "UUID")]
 impl ScalarType for Uuid {
     fn parse(value: Value) -> InputValueResult<Self> {
@@ -1,15 +1,17 @@
-use crate::context::Data;
+use crate::context::{Data, ResponseErrors, VisibleContext};
+use crate::directive::{CustomDirective, DirectiveArgs};
 use crate::extensions::{BoxExtension, Extension, Extensions};
 use crate::model::__DirectiveLocation;
 use crate::parser::parse_query;
 use crate::query::{QueryBuilder, StreamResponse};
-use crate::registry::{MetaDirective, MetaInputValue, Registry};
+use crate::registry::{MetaDirective, MetaInputValue, MetaType, Registry};
+use crate::resolver::{resolve_extension_field, ExtensionResolver};
 use crate::subscription::{create_connection, create_subscription_stream, SubscriptionTransport};
 use crate::types::QueryRoot;
 use crate::validation::{check_rules, CheckResult, ValidationMode};
 use crate::{
-    CacheControl, Error, ObjectType, Pos, QueryEnv, QueryError, QueryResponse, Result,
-    SubscriptionType, Type, Variables, ID,
+    CacheControl, Error, ObjectExtension, ObjectType, Pos, QueryEnv, QueryError, QueryResponse,
+    Result, SubscriptionType, Type, Variables, ID,
 };
 use async_graphql_parser::query::{Document, OperationType};
 use bytes::Bytes;
@@ -18,6 +20,7 @@ use futures::Stream;
 use indexmap::map::IndexMap;
 use itertools::Itertools;
 use std::any::Any;
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
@@ -34,6 +37,8 @@ pub struct SchemaBuilder<Query, Mutation, Subscription> {
     depth: Option<usize>,
     extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
     enable_federation: bool,
+    custom_directives: HashMap<&'static str, Arc<dyn CustomDirective>>,
+    type_extensions: HashMap<String, Vec<ExtensionResolver>>,
 }
 
 impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
@@ -45,6 +50,49 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
+    /// Register the object type `T` as a possible type of the interface `I`.
+    ///
+    /// It is used for the implementors of an interface that are not its variants, e.g. the
+    /// objects returned as `Box<dyn Trait>`, see [`dyn_interface!`](macro.dyn_interface.html).
+    pub fn register_implementor<I: Type + ?Sized, T: ObjectType>(mut self) -> Self {
+        I::create_type_info(&mut self.registry);
+        T::create_type_info(&mut self.registry);
+        self.registry
+            .add_implements(&T::type_name(), &I::type_name());
+        if let Some(MetaType::Interface { possible_types, .. }) =
+            self.registry.types.get_mut(I::type_name().as_ref())
+        {
+            possible_types.insert(T::type_name().to_string());
+        }
+        self
+    }
+
+    /// Add the fields of the extension `E` to the object type it extends, see
+    /// [`ObjectExtension`](trait.ObjectExtension.html).
+    pub fn extend_type<E: ObjectExtension>(mut self) -> Self {
+        let type_name = E::Target::type_name();
+        E::Target::create_type_info(&mut self.registry);
+        let mut fields = match self.registry.types.get(type_name.as_ref()) {
+            Some(MetaType::Object { fields, .. }) => fields.clone(),
+            _ => panic!("The type \"{}\" is not an object.", type_name),
+        };
+        let mut cache_control = CacheControl::default();
+        self.registry
+            .merge_object_fields::<E>(&type_name, &mut fields, &mut cache_control);
+        if let Some(MetaType::Object {
+            fields: target_fields,
+            ..
+        }) = self.registry.types.get_mut(type_name.as_ref())
+        {
+            *target_fields = fields;
+        }
+        self.type_extensions
+            .entry(type_name.to_string())
+            .or_default()
+            .push(resolve_extension_field::<E>);
+        self
+    }
+
     /// Disable introspection query
     pub fn disable_introspection(mut self) -> Self {
         self.query.disable_introspection = true;
@@ -91,8 +139,40 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
+    /// Enable Apollo Federation v2.
+    ///
+    /// The SDL returned by `_service` starts with the `@link` header that imports the federation
+    /// v2 directives, so `shareable`, `inaccessible`, `override_from` and `tag` can be used on
+    /// types and fields.
+    pub fn enable_federation_v2(mut self) -> Self {
+        self.enable_federation = true;
+        self.registry.federation_v2 = true;
+        self
+    }
+
+    /// Register a custom executable directive, see [`CustomDirective`](directive/trait.CustomDirective.html).
+    pub fn directive<D: CustomDirective>(mut self, directive: D) -> Self {
+        let mut args = DirectiveArgs {
+            registry: &mut self.registry,
+            args: Default::default(),
+        };
+        directive.args(&mut args);
+        let args = args.args;
+        self.registry.add_directive(MetaDirective {
+            name: directive.name(),
+            description: directive.description(),
+            locations: vec![__DirectiveLocation::FIELD],
+            args,
+        });
+        self.custom_directives
+            .insert(directive.name(), Arc::new(directive));
+        self
+    }
+
     /// Build schema.
     pub fn finish(mut self) -> Schema<Query, Mutation, Subscription> {
+        self.registry.check_interface_implements();
+
         // federation
         if self.enable_federation || self.registry.has_entities() {
             self.registry.create_federation_types();
@@ -109,6 +189,8 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry: self.registry,
                 data: self.data,
+                custom_directives: self.custom_directives,
+                type_extensions: self.type_extensions,
             })),
         }))
     }
@@ -118,11 +200,13 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
 pub struct SchemaEnvInner {
     pub registry: Registry,
     pub data: Data,
+    pub custom_directives: HashMap<&'static str, Arc<dyn CustomDirective>>,
+    pub(crate) type_extensions: HashMap<String, Vec<ExtensionResolver>>,
 }
 
 #[doc(hidden)]
 #[derive(Clone)]
-pub struct SchemaEnv(Arc<SchemaEnvInner>);
+pub struct SchemaEnv(pub(crate) Arc<SchemaEnvInner>);
 
 impl Deref for SchemaEnv {
     type Target = SchemaEnvInner;
@@ -166,6 +250,108 @@ where
     }
 }
 
+/// Create a registry with the built-in directives and scalars.
+pub(crate) fn create_registry(
+    query_type: String,
+    mutation_type: Option<String>,
+    subscription_type: Option<String>,
+) -> Registry {
+    let mut registry = Registry {
+        types: Default::default(),
+        directives: Default::default(),
+        implements: Default::default(),
+        default_fields: Default::default(),
+        rust_types: Default::default(),
+        federation_v2: false,
+        query_type,
+        mutation_type,
+        subscription_type,
+    };
+
+    registry.add_directive(MetaDirective {
+        name: "include",
+        description: Some("Directs the executor to include this field or fragment only when the `if` argument is true."),
+        locations: vec![
+            __DirectiveLocation::FIELD,
+            __DirectiveLocation::FRAGMENT_SPREAD,
+            __DirectiveLocation::INLINE_FRAGMENT
+        ],
+        args: {
+            let mut args = IndexMap::new();
+            args.insert("if".to_string(), MetaInputValue {
+                name: "if".to_string(),
+                description: Some("Included when true.".to_string()),
+                ty: "Boolean!".to_string(),
+                default_value: None,
+                validator: None,
+                visible: None,
+                directives: Default::default(),
+                is_secret: false,
+            });
+            args
+        }
+    });
+
+    registry.add_directive(MetaDirective {
+        name: "skip",
+        description: Some(
+            "Directs the executor to skip this field or fragment when the `if` argument is true.",
+        ),
+        locations: vec![
+            __DirectiveLocation::FIELD,
+            __DirectiveLocation::FRAGMENT_SPREAD,
+            __DirectiveLocation::INLINE_FRAGMENT,
+        ],
+        args: {
+            let mut args = IndexMap::new();
+            args.insert(
+                "if".to_string(),
+                MetaInputValue {
+                    name: "if".to_string(),
+                    description: Some("Skipped when true.".to_string()),
+                    ty: "Boolean!".to_string(),
+                    default_value: None,
+                    validator: None,
+                    visible: None,
+                    directives: Default::default(),
+                    is_secret: false,
+                },
+            );
+            args
+        },
+    });
+
+    registry.add_directive(MetaDirective {
+        name: "oneOf",
+        description: Some("Indicates that exactly one field of the input object must be provided."),
+        locations: vec![__DirectiveLocation::INPUT_OBJECT],
+        args: Default::default(),
+    });
+
+    registry.add_directive(MetaDirective {
+        name: "defer",
+        description: None,
+        locations: vec![__DirectiveLocation::FIELD],
+        args: Default::default(),
+    });
+
+    registry.add_directive(MetaDirective {
+        name: "stream",
+        description: None,
+        locations: vec![__DirectiveLocation::FIELD],
+        args: Default::default(),
+    });
+
+    // register scalars
+    bool::create_type_info(&mut registry);
+    i32::create_type_info(&mut registry);
+    f32::create_type_info(&mut registry);
+    String::create_type_info(&mut registry);
+    ID::create_type_info(&mut registry);
+
+    registry
+}
+
 impl<Query, Mutation, Subscription> Schema<Query, Mutation, Subscription>
 where
     Query: ObjectType + Send + Sync + 'static,
@@ -182,85 +368,19 @@ where
         mutation: Mutation,
         subscription: Subscription,
     ) -> SchemaBuilder<Query, Mutation, Subscription> {
-        let mut registry = Registry {
-            types: Default::default(),
-            directives: Default::default(),
-            implements: Default::default(),
-            query_type: Query::type_name().to_string(),
-            mutation_type: if Mutation::is_empty() {
+        let mut registry = create_registry(
+            Query::type_name().to_string(),
+            if Mutation::is_empty() {
                 None
             } else {
                 Some(Mutation::type_name().to_string())
             },
-            subscription_type: if Subscription::is_empty() {
+            if Subscription::is_empty() {
                 None
             } else {
                 Some(Subscription::type_name().to_string())
             },
-        };
-
-        registry.add_directive(MetaDirective {
-            name: "include",
-            description: Some("Directs the executor to include this field or fragment only when the `if` argument is true."),
-            locations: vec![
-                __DirectiveLocation::FIELD,
-                __DirectiveLocation::FRAGMENT_SPREAD,
-                __DirectiveLocation::INLINE_FRAGMENT
-            ],
-            args: {
-                let mut args = IndexMap::new();
-                args.insert("if", MetaInputValue {
-                    name: "if",
-                    description: Some("Included when true."),
-                    ty: "Boolean!".to_string(),
-                    default_value: None,
-                    validator: None,
-                });
-                args
-            }
-        });
-
-        registry.add_directive(MetaDirective {
-            name: "skip",
-            description: Some("Directs the executor to skip this field or fragment when the `if` argument is true."),
-            locations: vec![
-                __DirectiveLocation::FIELD,
-                __DirectiveLocation::FRAGMENT_SPREAD,
-                __DirectiveLocation::INLINE_FRAGMENT
-            ],
-            args: {
-                let mut args = IndexMap::new();
-                args.insert("if", MetaInputValue {
-                    name: "if",
-                    description: Some("Skipped when true."),
-                    ty: "Boolean!".to_string(),
-                    default_value: None,
-                    validator: None,
-                });
-                args
-            }
-        });
-
-        registry.add_directive(MetaDirective {
-            name: "defer",
-            description: None,
-            locations: vec![__DirectiveLocation::FIELD],
-            args: Default::default(),
-        });
-
-        registry.add_directive(MetaDirective {
-            name: "stream",
-            description: None,
-            locations: vec![__DirectiveLocation::FIELD],
-            args: Default::default(),
-        });
-
-        // register scalars
-        bool::create_type_info(&mut registry);
-        i32::create_type_info(&mut registry);
-        f32::create_type_info(&mut registry);
-        String::create_type_info(&mut registry);
-        ID::create_type_info(&mut registry);
+        );
 
         QueryRoot::<Query>::create_type_info(&mut registry);
         if !Mutation::is_empty() {
@@ -284,6 +404,8 @@ where
             depth: None,
             extensions: Default::default(),
             enable_federation: false,
+            custom_directives: Default::default(),
+            type_extensions: Default::default(),
         }
     }
 
@@ -296,6 +418,14 @@ where
         Self::build(query, mutation, subscription).finish()
     }
 
+    /// Returns the schema in SDL (Schema Definition Language).
+    ///
+    /// The output includes descriptions, deprecations, default values and directives, and
+    /// the types are sorted by name, so it can be committed and diffed in code review.
+    pub fn sdl(&self) -> String {
+        self.env.registry.export_sdl()
+    }
+
     /// Execute query without create the `QueryBuilder`.
     pub async fn execute(&self, query_source: &str) -> Result<QueryResponse> {
         QueryBuilder::new(query_source).execute(self).await
@@ -311,7 +441,9 @@ where
     pub(crate) fn prepare_query(
         &self,
         source: &str,
+        variables: &Variables,
         query_extensions: &[Box<dyn Fn() -> BoxExtension + Send + Sync>],
+        ctx_data: Option<&Data>,
     ) -> Result<(Document, CacheControl, Extensions)> {
         // create extension instances
         let extensions = Extensions(
@@ -329,16 +461,24 @@ where
 
         // check rules
         extensions.validation_start();
+        let empty_data = Data::default();
         let CheckResult {
             cache_control,
             complexity,
             depth,
+            secret_variables,
         } = extensions.log_error(check_rules(
             &self.env.registry,
             &document,
+            Some(variables),
+            Some(VisibleContext::new(
+                ctx_data.unwrap_or(&empty_data),
+                &self.env.data,
+            )),
             self.validation_mode,
         ))?;
         extensions.validation_end();
+        extensions.variables(&variables.mask_secrets(&secret_variables));
 
         // check limit
         if let Some(limit_complexity) = self.complexity {
@@ -365,7 +505,8 @@ where
         variables: Variables,
         ctx_data: Option<Arc<Data>>,
     ) -> Result<impl Stream<Item = Result<serde_json::Value>> + Send> {
-        let (mut document, _, extensions) = self.prepare_query(source, &Vec::new())?;
+        let (mut document, _, extensions) =
+            self.prepare_query(source, &variables, &Vec::new(), ctx_data.as_deref())?;
 
         if !document.retain_operation(operation_name) {
             return extensions.log_error(if let Some(name) = operation_name {
@@ -383,6 +524,7 @@ where
         }
 
         let resolve_id = AtomicUsize::default();
+        let errors = ResponseErrors::default();
         let env = QueryEnv::new(
             extensions,
             variables,
@@ -395,6 +537,7 @@ where
             &env.document.current_operation().selection_set,
             &resolve_id,
             None,
+            &errors,
         );
         let mut streams = Vec::new();
         ctx.query_env
@@ -4,6 +4,7 @@ use futures::channel::mpsc;
 use futures::task::{AtomicWaker, Context, Poll};
 use futures::{Stream, StreamExt};
 use slab::Slab;
+use std::collections::VecDeque;
 use std::future::Future;
 use std::pin::Pin;
 
@@ -52,6 +53,16 @@ pub trait SubscriptionTransport: Send + Sync + Unpin + 'static {
 
     /// When a response message is generated, you can convert the message to the format you want here.
     fn handle_response(&mut self, id: usize, res: Result<serde_json::Value>) -> Option<Bytes>;
+
+    /// When a subscription stream ends, you can return a message telling the client it is complete.
+    fn handle_complete(&mut self, _id: usize) -> Option<Bytes> {
+        None
+    }
+
+    /// Returns `true` if the connection should be closed after the last message returned by `handle_request` has been sent.
+    fn is_closed(&self) -> bool {
+        false
+    }
 }
 
 pub fn create_connection<Query, Mutation, Subscription, T: SubscriptionTransport>(
@@ -78,6 +89,8 @@ where
             rx_bytes,
             handle_request_fut: None,
             waker: AtomicWaker::new(),
+            closed: false,
+            pending: VecDeque::new(),
         };
         while let Some(data) = inner_stream.next().await {
             yield data;
@@ -108,6 +121,8 @@ struct SubscriptionStream<'a, Query, Mutation, Subscription, T: SubscriptionTran
     rx_bytes: mpsc::UnboundedReceiver<Bytes>,
     handle_request_fut: Option<HandleRequestBoxFut<'a, T>>,
     waker: AtomicWaker,
+    closed: bool,
+    pending: VecDeque<Bytes>,
 }
 
 impl<'a, Query, Mutation, Subscription, T> Stream
@@ -123,17 +138,29 @@ where
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = &mut *self;
 
+        if let Some(bytes) = this.pending.pop_front() {
+            return Poll::Ready(Some(bytes));
+        }
+
+        if this.closed {
+            return Poll::Ready(None);
+        }
+
         loop {
             // receive bytes
             if let Some(handle_request_fut) = &mut this.handle_request_fut {
                 match handle_request_fut.as_mut().poll(cx) {
                     Poll::Ready((Ok(bytes), transport, streams)) => {
+                        this.closed = transport.is_closed();
                         this.transport = Some(transport);
                         this.streams = Some(streams);
                         this.handle_request_fut = None;
                         if let Some(bytes) = bytes {
                             return Poll::Ready(Some(bytes));
                         }
+                        if this.closed {
+                            return Poll::Ready(None);
+                        }
                         continue;
                     }
                     Poll::Ready((Err(_), _, _)) => return Poll::Ready(None),
@@ -161,6 +188,7 @@ where
             if let (Some(streams), Some(transport)) = (&mut this.streams, &mut this.transport) {
                 if !streams.streams.is_empty() {
                     let mut closed = Vec::new();
+                    let mut completed = Vec::new();
 
                     for (id, incoming_stream) in &mut streams.streams {
                         match incoming_stream.as_mut().poll_next(cx) {
@@ -173,13 +201,22 @@ where
                                 }
                             }
                             Poll::Ready(None) => {
-                                closed.push(id);
+                                completed.push(id);
                             }
                             Poll::Pending => {}
                         }
                     }
 
                     closed.iter().for_each(|id| streams.remove(*id));
+                    completed.iter().for_each(|id| streams.remove(*id));
+                    this.pending.extend(
+                        completed
+                            .into_iter()
+                            .filter_map(|id| transport.handle_complete(id)),
+                    );
+                    if let Some(bytes) = this.pending.pop_front() {
+                        return Poll::Ready(Some(bytes));
+                    }
                     this.waker.register(cx.waker());
                     return Poll::Pending;
                 } else {
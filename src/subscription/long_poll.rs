@@ -0,0 +1,187 @@
+use crate::http::GQLError;
+use crate::{ObjectType, QueryBuilder, Result, Schema, SubscriptionType};
+use futures::{FutureExt, Stream, StreamExt};
+use futures_timer::Delay;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+type SubscriptionStream = Pin<Box<dyn Stream<Item = Result<serde_json::Value>> + Send>>;
+
+struct Entry {
+    stream: Arc<futures::lock::Mutex<SubscriptionStream>>,
+    last_poll: Instant,
+}
+
+/// Subscriptions for clients that can only use HTTP long-polling
+///
+/// The client starts a subscription with a request that calls `LongPollSubscriptions::subscribe`
+/// and receives its id, then repeatedly sends requests that call `LongPollSubscriptions::poll`
+/// with the id. Each poll returns the events that are ready, or waits for the next event up to a
+/// timeout.
+///
+/// The subscription stream is only driven while it is polled, so buffering the events that happen
+/// between two polls is left to the stream. For example, `SimpleBroker` buffers them in a channel,
+/// while a stream that produces values on demand (such as an interval) only does so during a poll.
+///
+/// Subscriptions that are not polled for longer than the TTL are dropped. A subscription stays
+/// registered while it is polled, so it survives a poll request that is dropped before it
+/// completes, such as when the client disconnects.
+///
+/// The ids are random UUIDs, so it requires the `uuid` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures::Stream;
+/// use std::time::Duration;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// struct SubscriptionRoot;
+///
+/// #[Subscription]
+/// impl SubscriptionRoot {
+///     async fn values(&self) -> impl Stream<Item = i32> {
+///         futures::stream::iter(0..3)
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+///     let subscriptions = LongPollSubscriptions::new(Duration::from_secs(60));
+///
+///     // POST /subscriptions
+///     let id = subscriptions
+///         .subscribe(&schema, QueryBuilder::new("subscription { values }"))
+///         .await
+///         .unwrap();
+///
+///     // GET /subscriptions/{id}
+///     let resp = subscriptions.poll(&id, Duration::from_secs(30)).await.unwrap();
+///     assert_eq!(resp.events.len(), 3);
+///     assert!(resp.complete);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct LongPollSubscriptions {
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+    ttl: Duration,
+}
+
+/// The events returned by `LongPollSubscriptions::poll`
+#[derive(Debug, Serialize)]
+pub struct LongPollResponse {
+    /// The results of the subscription, serialized like GraphQL responses.
+    pub events: Vec<serde_json::Value>,
+
+    /// `true` if the subscription has ended, and the id can no longer be polled.
+    pub complete: bool,
+}
+
+impl LongPollSubscriptions {
+    /// Create the subscriptions with the time after which a subscription that is not polled is
+    /// dropped.
+    pub fn new(ttl: Duration) -> Self {
+        LongPollSubscriptions {
+            entries: Default::default(),
+            ttl,
+        }
+    }
+
+    /// Start a subscription, returns its id.
+    pub async fn subscribe<Query, Mutation, Subscription>(
+        &self,
+        schema: &Schema<Query, Mutation, Subscription>,
+        builder: QueryBuilder,
+    ) -> Result<String>
+    where
+        Query: ObjectType + Send + Sync + 'static,
+        Mutation: ObjectType + Send + Sync + 'static,
+        Subscription: SubscriptionType + Send + Sync + 'static,
+    {
+        let stream = builder.execute_subscription(schema).await?;
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut entries = self.entries.lock();
+        self.remove_expired(&mut entries);
+        entries.insert(
+            id.clone(),
+            Entry {
+                stream: Arc::new(futures::lock::Mutex::new(stream)),
+                last_poll: Instant::now(),
+            },
+        );
+        Ok(id)
+    }
+
+    /// Returns the events of the subscription since the last poll.
+    ///
+    /// If there are none, it waits for the next event for up to `wait`. Returns `None` if the id is
+    /// unknown, has expired, or is being polled by another request.
+    pub async fn poll(&self, id: &str, wait: Duration) -> Option<LongPollResponse> {
+        let stream = {
+            let mut entries = self.entries.lock();
+            self.remove_expired(&mut entries);
+            entries.get(id)?.stream.clone()
+        };
+        let mut stream = stream.try_lock()?;
+
+        let mut events = Vec::new();
+        let mut complete = false;
+        let first = futures::future::select(stream.next(), Delay::new(wait)).await;
+        if let futures::future::Either::Left((res, _)) = first {
+            match res {
+                Some(res) => {
+                    events.push(event(res));
+                    loop {
+                        match stream.next().now_or_never() {
+                            Some(Some(res)) => events.push(event(res)),
+                            Some(None) => {
+                                complete = true;
+                                break;
+                            }
+                            None => break,
+                        }
+                    }
+                }
+                None => complete = true,
+            }
+        }
+
+        // The subscription may have been stopped with `unsubscribe` during the poll.
+        let mut entries = self.entries.lock();
+        if complete {
+            entries.remove(id);
+        } else if let Some(entry) = entries.get_mut(id) {
+            entry.last_poll = Instant::now();
+        }
+        Some(LongPollResponse { events, complete })
+    }
+
+    /// Stop a subscription.
+    pub fn unsubscribe(&self, id: &str) {
+        self.entries.lock().remove(id);
+    }
+
+    fn remove_expired(&self, entries: &mut HashMap<String, Entry>) {
+        let ttl = self.ttl;
+        // A subscription that is being polled is not expired, however long the poll waits.
+        entries.retain(|_, entry| {
+            entry.last_poll.elapsed() < ttl || entry.stream.try_lock().is_none()
+        });
+    }
+}
+
+fn event(res: Result<serde_json::Value>) -> serde_json::Value {
+    match res {
+        Ok(data) => serde_json::json!({ "data": data }),
+        Err(err) => serde_json::json!({ "errors": GQLError(&err) }),
+    }
+}
@@ -1,9 +1,18 @@
 mod connection;
+#[cfg(all(not(target_arch = "wasm32"), feature = "uuid"))]
+mod long_poll;
+mod server;
 mod simple_broker;
 mod subscription_type;
 mod ws_transport;
 
 pub use connection::{create_connection, SubscriptionStreams, SubscriptionTransport};
+#[cfg(all(not(target_arch = "wasm32"), feature = "uuid"))]
+pub use long_poll::{LongPollResponse, LongPollSubscriptions};
+pub use server::SubscriptionServer;
 pub use simple_broker::SimpleBroker;
 pub use subscription_type::{create_subscription_stream, SubscriptionType};
-pub use ws_transport::WebSocketTransport;
+pub use ws_transport::{
+    ConnectionParams, WebSocketCloseReason, WebSocketProtocols, WebSocketTransport,
+    ALL_WEBSOCKET_PROTOCOLS,
+};
@@ -0,0 +1,141 @@
+use crate::subscription::create_connection;
+use crate::{ObjectType, Schema, SubscriptionTransport, SubscriptionType, WebSocketTransport};
+use bytes::Bytes;
+use futures::{select, Sink, SinkExt, Stream, StreamExt};
+
+/// Subscription server for any websocket implementation
+///
+/// It runs a `SubscriptionTransport` (`WebSocketTransport` by default) for a connection whose
+/// incoming text messages are a `Stream` of `String` and outgoing text messages a `Sink` of `String`,
+/// so it works with any websocket library, such as `tokio-tungstenite`, by mapping its message type.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures::channel::mpsc;
+/// use futures::{SinkExt, Stream, StreamExt};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// struct SubscriptionRoot;
+///
+/// #[Subscription]
+/// impl SubscriptionRoot {
+///     async fn values(&self) -> impl Stream<Item = i32> {
+///         futures::stream::iter(0..3)
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+///     let (mut client_tx, server_rx) = mpsc::unbounded::<String>();
+///     let (server_tx, mut client_rx) = mpsc::unbounded::<String>();
+///
+///     async_std::task::spawn(
+///         SubscriptionServer::new(schema)
+///             .on_connect(|| println!("connected"))
+///             .on_disconnect(|| println!("disconnected"))
+///             .serve(server_tx, server_rx),
+///     );
+///
+///     client_tx.send(r#"{"type": "connection_init"}"#.to_string()).await.unwrap();
+///     assert_eq!(client_rx.next().await.unwrap(), r#"{"type":"connection_ack"}"#);
+/// });
+/// ```
+pub struct SubscriptionServer<Query, Mutation, Subscription, T = WebSocketTransport> {
+    schema: Schema<Query, Mutation, Subscription>,
+    transport: T,
+    on_connect: Option<Box<dyn FnOnce() + Send>>,
+    on_disconnect: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl<Query, Mutation, Subscription> SubscriptionServer<Query, Mutation, Subscription> {
+    /// Create a subscription server with the default `WebSocketTransport`.
+    pub fn new(schema: Schema<Query, Mutation, Subscription>) -> Self {
+        Self::with_transport(schema, WebSocketTransport::default())
+    }
+}
+
+impl<Query, Mutation, Subscription, T> SubscriptionServer<Query, Mutation, Subscription, T> {
+    /// Create a subscription server with this transport.
+    pub fn with_transport(schema: Schema<Query, Mutation, Subscription>, transport: T) -> Self {
+        SubscriptionServer {
+            schema,
+            transport,
+            on_connect: None,
+            on_disconnect: None,
+        }
+    }
+
+    /// Set the function that is called when the server starts serving the connection.
+    pub fn on_connect(self, f: impl FnOnce() + Send + 'static) -> Self {
+        SubscriptionServer {
+            on_connect: Some(Box::new(f)),
+            ..self
+        }
+    }
+
+    /// Set the function that is called when the connection is closed, by the client or by the
+    /// transport.
+    pub fn on_disconnect(self, f: impl FnOnce() + Send + 'static) -> Self {
+        SubscriptionServer {
+            on_disconnect: Some(Box::new(f)),
+            ..self
+        }
+    }
+}
+
+impl<Query, Mutation, Subscription, T> SubscriptionServer<Query, Mutation, Subscription, T>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    T: SubscriptionTransport,
+{
+    /// Serve the connection until it is closed.
+    pub async fn serve<Tx, Rx>(self, sink: Tx, stream: Rx)
+    where
+        Tx: Sink<String> + Unpin,
+        Rx: Stream<Item = String> + Unpin,
+    {
+        if let Some(on_connect) = self.on_connect {
+            on_connect();
+        }
+
+        let (mut stx, srx) = create_connection(self.schema, self.transport);
+        let mut sink = sink;
+        let mut srx = srx.fuse();
+        let mut stream = stream.fuse();
+
+        loop {
+            select! {
+                bytes = srx.next() => match bytes {
+                    Some(bytes) => {
+                        if let Ok(text) = String::from_utf8(bytes.to_vec()) {
+                            if sink.send(text).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    None => break,
+                },
+                text = stream.next() => match text {
+                    Some(text) => {
+                        if stx.send(Bytes::from(text)).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        if let Some(on_disconnect) = self.on_disconnect {
+            on_disconnect();
+        }
+    }
+}
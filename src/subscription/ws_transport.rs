@@ -5,9 +5,65 @@ use crate::{
     SubscriptionTransport, SubscriptionType, Variables,
 };
 use bytes::Bytes;
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
+/// All known websocket sub-protocols.
+pub const ALL_WEBSOCKET_PROTOCOLS: [&str; 2] = ["graphql-transport-ws", "graphql-ws"];
+
+/// Websocket sub-protocols for subscription
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum WebSocketProtocols {
+    /// [subscriptions-transport-ws protocol](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md).
+    #[default]
+    SubscriptionsTransportWS,
+
+    /// [graphql-ws protocol](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md).
+    GraphQLWS,
+}
+
+impl WebSocketProtocols {
+    /// Returns the `Sec-WebSocket-Protocol` header value for the protocol.
+    pub fn sec_websocket_protocol(&self) -> &'static str {
+        match self {
+            WebSocketProtocols::SubscriptionsTransportWS => "graphql-ws",
+            WebSocketProtocols::GraphQLWS => "graphql-transport-ws",
+        }
+    }
+
+    /// Select the protocol from the `Sec-WebSocket-Protocol` header value sent by the client.
+    ///
+    /// If the client does not request a known protocol, `SubscriptionsTransportWS` is used.
+    pub fn from_sec_websocket_protocol(value: &str) -> Self {
+        value
+            .split(',')
+            .find_map(|p| WebSocketProtocols::from_str(p.trim()).ok())
+            .unwrap_or_default()
+    }
+
+    fn data_type(&self) -> &'static str {
+        match self {
+            WebSocketProtocols::SubscriptionsTransportWS => "data",
+            WebSocketProtocols::GraphQLWS => "next",
+        }
+    }
+}
+
+impl FromStr for WebSocketProtocols {
+    type Err = ();
+
+    fn from_str(protocol: &str) -> std::result::Result<Self, Self::Err> {
+        match protocol {
+            "graphql-ws" => Ok(WebSocketProtocols::SubscriptionsTransportWS),
+            "graphql-transport-ws" => Ok(WebSocketProtocols::GraphQLWS),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct OperationMessage {
     #[serde(rename = "type")]
@@ -20,6 +76,82 @@ struct OperationMessage {
     payload: Option<serde_json::Value>,
 }
 
+/// The `payload` of the `connection_init` message of a websocket connection
+///
+/// It is added to the data of the connection, so the subscription resolvers can get it with
+/// `ctx.data::<ConnectionParams>()` and decode it into their own types. It is an empty object if
+/// the message has no payload, the same value that is passed to the function of `WebSocketTransport::new`.
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures::Stream;
+/// use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Params {
+///     locale: Option<String>,
+/// }
+///
+/// struct SubscriptionRoot;
+///
+/// #[Subscription]
+/// impl SubscriptionRoot {
+///     async fn greetings(&self, ctx: &Context<'_>) -> FieldResult<impl Stream<Item = String>> {
+///         let params: Params = ctx.data::<ConnectionParams>().decode()?;
+///         let greeting = match params.locale.as_deref() {
+///             Some("fr") => "Bonjour",
+///             _ => "Hello",
+///         };
+///         Ok(futures::stream::once(async move { greeting.to_string() }))
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionParams(pub serde_json::Value);
+
+impl ConnectionParams {
+    /// Decode the payload into `T`.
+    pub fn decode<T: DeserializeOwned>(&self) -> FieldResult<T> {
+        Ok(serde_json::from_value(self.0.clone())?)
+    }
+
+    /// Returns the value of the `key` field of the payload.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+}
+
+/// The close code and reason of a connection closed by the `WebSocketTransport`
+///
+/// The `graphql-transport-ws` sub-protocol has no error message for the connection, it closes the
+/// websocket with one of these close codes instead:
+///
+/// * `4400` - Invalid message
+/// * `4401` - Unauthorized, a subscription was started before the `connection_init` message
+/// * `4403` - Forbidden, the `connection_init` message was rejected
+/// * `4409` - Subscriber for the id already exists
+/// * `4429` - Too many initialisation requests
+///
+/// Under both sub-protocols, a query longer than the limit set with
+/// `WebSocketTransport::with_max_query_length` closes the websocket with the `1008` (policy
+/// violation) close code.
+///
+/// Get it with `WebSocketTransport::close_reason` before passing the transport to
+/// `Schema::subscription_connection`, and close the websocket with it when the stream ends.
+#[derive(Debug, Clone, Default)]
+pub struct WebSocketCloseReason(Arc<Mutex<Option<(u16, String)>>>);
+
+impl WebSocketCloseReason {
+    /// Returns the close code and reason, or `None` if the transport has not closed the connection.
+    pub fn get(&self) -> Option<(u16, String)> {
+        self.0.lock().clone()
+    }
+
+    fn set(&self, code: u16, reason: impl Into<String>) {
+        *self.0.lock() = Some((code, reason.into()));
+    }
+}
+
 /// WebSocket transport for subscription
 #[derive(Default)]
 pub struct WebSocketTransport {
@@ -27,10 +159,23 @@ pub struct WebSocketTransport {
     sid_to_id: HashMap<usize, String>,
     data: Arc<Data>,
     init_context_data: Option<Box<dyn Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync>>,
+    protocol: WebSocketProtocols,
+    initialized: bool,
+    closed: bool,
+    close_reason: WebSocketCloseReason,
+    max_query_length: Option<usize>,
 }
 
 impl WebSocketTransport {
     /// Creates a websocket transport and sets the function that converts the `payload` of the `connect_init` message to `Data`.
+    ///
+    /// The function is called for every `connect_init` message, with an empty object if it has no `payload`.
+    /// If the function returns an error, a `connection_error` message is sent and the connection is closed,
+    /// so it can be used to authenticate the connection.
+    ///
+    /// A connection is only initialized once, a repeated `connect_init` message closes it. Subscriptions
+    /// started before the initialization are rejected. Under the `graphql-transport-ws` sub-protocol the
+    /// connection is closed with the close codes described in `WebSocketCloseReason` instead.
     pub fn new<F: Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync + 'static>(
         init_context_data: F,
     ) -> Self {
@@ -39,6 +184,65 @@ impl WebSocketTransport {
             ..WebSocketTransport::default()
         }
     }
+
+    /// Sets the data for this connection, it can be accessed in all subscription resolvers with `Context::data`.
+    ///
+    /// The data returned by the `connect_init` function is merged into it.
+    pub fn with_data(self, data: Data) -> Self {
+        WebSocketTransport {
+            data: Arc::new(data),
+            ..self
+        }
+    }
+
+    /// Sets the websocket sub-protocol, the default is `WebSocketProtocols::SubscriptionsTransportWS`.
+    pub fn with_protocol(self, protocol: WebSocketProtocols) -> Self {
+        WebSocketTransport { protocol, ..self }
+    }
+
+    /// Sets the maximum length of the query in a `start` or `subscribe` message.
+    ///
+    /// If a longer query is received, the connection is closed with the `1008` (policy violation)
+    /// close code.
+    pub fn with_max_query_length(self, length: usize) -> Self {
+        WebSocketTransport {
+            max_query_length: Some(length),
+            ..self
+        }
+    }
+
+    /// Returns the close code and reason of the connection, which is shared with this transport.
+    pub fn close_reason(&self) -> WebSocketCloseReason {
+        self.close_reason.clone()
+    }
+
+    fn close(&mut self, code: u16, reason: impl Into<String>) -> FieldResult<Option<Bytes>> {
+        self.closed = true;
+        self.close_reason.set(code, reason);
+        Ok(None)
+    }
+
+    fn is_query_too_long(&self, msg: &OperationMessage) -> bool {
+        let query = msg
+            .payload
+            .as_ref()
+            .and_then(|payload| payload["query"].as_str());
+        matches!((self.max_query_length, query), (Some(max_query_length), Some(query)) if query.len() > max_query_length)
+    }
+
+    fn invalid_message(&mut self, err: impl Into<FieldError>) -> FieldResult<Option<Bytes>> {
+        match self.protocol {
+            WebSocketProtocols::GraphQLWS => self.close(4400, err.into().0),
+            WebSocketProtocols::SubscriptionsTransportWS => Err(err.into()),
+        }
+    }
+
+    fn invalid_message_payload(&mut self) -> FieldResult<Option<Bytes>> {
+        match self.protocol {
+            WebSocketProtocols::GraphQLWS => self.close(4400, "Invalid message payload"),
+            WebSocketProtocols::SubscriptionsTransportWS => Ok(None),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -58,12 +262,56 @@ impl SubscriptionTransport for WebSocketTransport {
     {
         match serde_json::from_slice::<OperationMessage>(&data) {
             Ok(msg) => match msg.ty.as_str() {
+                "start" | "subscribe" if self.is_query_too_long(&msg) => {
+                    self.close(1008, "Query is too long")
+                }
                 "connection_init" => {
-                    if let Some(payload) = msg.payload {
-                        if let Some(init_context_data) = &self.init_context_data {
-                            self.data = Arc::new(init_context_data(payload)?);
+                    if self.initialized {
+                        if self.protocol == WebSocketProtocols::GraphQLWS {
+                            return self.close(4429, "Too many initialisation requests");
+                        }
+                        self.closed = true;
+                        return Ok(Some(
+                            serde_json::to_vec(&OperationMessage {
+                                ty: "connection_error".to_string(),
+                                id: None,
+                                payload: Some(serde_json::json!({
+                                    "message": "Too many initialisation requests"
+                                })),
+                            })
+                            .unwrap()
+                            .into(),
+                        ));
+                    }
+                    self.initialized = true;
+
+                    // Streams are only started after the initialization, so nothing else holds the data yet.
+                    let mut conn_data =
+                        Arc::try_unwrap(std::mem::take(&mut self.data)).unwrap_or_default();
+                    let payload = msg.payload.unwrap_or_else(|| serde_json::json!({}));
+                    conn_data.insert(ConnectionParams(payload.clone()));
+
+                    if let Some(init_context_data) = &self.init_context_data {
+                        match init_context_data(payload) {
+                            Ok(data) => conn_data.merge(data),
+                            Err(_) if self.protocol == WebSocketProtocols::GraphQLWS => {
+                                return self.close(4403, "Forbidden");
+                            }
+                            Err(err) => {
+                                self.closed = true;
+                                return Ok(Some(
+                                    serde_json::to_vec(&OperationMessage {
+                                        ty: "connection_error".to_string(),
+                                        id: None,
+                                        payload: Some(serde_json::json!({ "message": err.0 })),
+                                    })
+                                    .unwrap()
+                                    .into(),
+                                ));
+                            }
                         }
                     }
+                    self.data = Arc::new(conn_data);
                     Ok(Some(
                         serde_json::to_vec(&OperationMessage {
                             ty: "connection_ack".to_string(),
@@ -74,8 +322,29 @@ impl SubscriptionTransport for WebSocketTransport {
                         .into(),
                     ))
                 }
-                "start" => {
+                "start" | "subscribe" if !self.initialized => match (self.protocol, msg.id) {
+                    (WebSocketProtocols::GraphQLWS, _) => self.close(4401, "Unauthorized"),
+                    (WebSocketProtocols::SubscriptionsTransportWS, Some(id)) => Ok(Some(
+                        serde_json::to_vec(&OperationMessage {
+                            ty: "error".to_string(),
+                            id: Some(id),
+                            payload: Some(serde_json::json!([{
+                                "message": "Connection is not initialised"
+                            }])),
+                        })
+                        .unwrap()
+                        .into(),
+                    )),
+                    (WebSocketProtocols::SubscriptionsTransportWS, None) => Ok(None),
+                },
+                "start" | "subscribe" => {
                     if let (Some(id), Some(payload)) = (msg.id, msg.payload) {
+                        if self.protocol == WebSocketProtocols::GraphQLWS
+                            && self.id_to_sid.contains_key(&id)
+                        {
+                            return self
+                                .close(4409, format!("Subscriber for {} already exists", id));
+                        }
                         if let Ok(request) = serde_json::from_value::<GQLRequest>(payload) {
                             let variables = request
                                 .variables
@@ -110,13 +379,13 @@ impl SubscriptionTransport for WebSocketTransport {
                                 )),
                             }
                         } else {
-                            Ok(None)
+                            self.invalid_message_payload()
                         }
                     } else {
-                        Ok(None)
+                        self.invalid_message_payload()
                     }
                 }
-                "stop" => {
+                "stop" | "complete" => {
                     if let Some(id) = msg.id {
                         if let Some(id) = self.id_to_sid.remove(&id) {
                             self.sid_to_id.remove(&id);
@@ -125,25 +394,54 @@ impl SubscriptionTransport for WebSocketTransport {
                     }
                     Ok(None)
                 }
+                "ping" if self.protocol == WebSocketProtocols::GraphQLWS => Ok(Some(
+                    serde_json::to_vec(&OperationMessage {
+                        ty: "pong".to_string(),
+                        id: None,
+                        payload: None,
+                    })
+                    .unwrap()
+                    .into(),
+                )),
+                "pong" if self.protocol == WebSocketProtocols::GraphQLWS => Ok(None),
                 "connection_terminate" => Err("connection_terminate".into()),
-                _ => Err("Unknown op".into()),
+                _ => self.invalid_message("Unknown op"),
             },
-            Err(err) => Err(err.into()),
+            Err(err) => self.invalid_message(err),
         }
     }
 
+    fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    fn handle_complete(&mut self, id: usize) -> Option<Bytes> {
+        let id = self.sid_to_id.remove(&id)?;
+        self.id_to_sid.remove(&id);
+        Some(
+            serde_json::to_vec(&OperationMessage {
+                ty: "complete".to_string(),
+                id: Some(id),
+                payload: None,
+            })
+            .unwrap()
+            .into(),
+        )
+    }
+
     fn handle_response(&mut self, id: usize, res: Result<serde_json::Value>) -> Option<Bytes> {
         if let Some(id) = self.sid_to_id.get(&id) {
             match res {
                 Ok(value) => Some(
                     serde_json::to_vec(&OperationMessage {
-                        ty: "data".to_string(),
+                        ty: self.protocol.data_type().to_string(),
                         id: Some(id.clone()),
                         payload: Some(
                             serde_json::to_value(GQLResponse(Ok(QueryResponse {
                                 label: None,
                                 path: None,
                                 data: value,
+                                errors: Vec::new(),
                                 extensions: None,
                                 cache_control: Default::default(),
                             })))
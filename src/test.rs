@@ -0,0 +1,361 @@
+//! Utilities for testing schemas
+//!
+//! `TestClient` executes operations on a schema in-process, without a web framework, and
+//! `TestResponse` has assertions for the data and the errors of the response.
+//! `TestClient::websocket` drives the websocket subscription protocol in-memory with
+//! `TestWebSocket`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::test::TestClient;
+//! use async_graphql::*;
+//!
+//! struct QueryRoot;
+//!
+//! #[Object]
+//! impl QueryRoot {
+//!     async fn add(&self, a: i32, b: i32) -> i32 {
+//!         a + b
+//!     }
+//!
+//!     async fn forbidden(&self) -> FieldResult<i32> {
+//!         Err(FieldError("Forbidden".to_string(), Some(serde_json::json!({ "code": "FORBIDDEN" }))))
+//!     }
+//! }
+//!
+//! async_std::task::block_on(async move {
+//!     let client = TestClient::new(Schema::new(QueryRoot, EmptyMutation, EmptySubscription));
+//!
+//!     client
+//!         .query("query($a: Int!) { add(a: $a, b: 20) }")
+//!         .variables(serde_json::json!({ "a": 10 }))
+//!         .execute()
+//!         .await
+//!         .assert_data(serde_json::json!({ "add": 30 }));
+//!
+//!     client
+//!         .query("{ forbidden }")
+//!         .execute()
+//!         .await
+//!         .assert_error_code("FORBIDDEN");
+//! });
+//! ```
+
+use crate::http::GQLResponseRef;
+use crate::{
+    ObjectType, QueryBuilder, QueryResponse, Result, Schema, SubscriptionType, Variables,
+    WebSocketTransport,
+};
+use bytes::Bytes;
+use futures::channel::mpsc;
+use futures::{SinkExt, Stream, StreamExt};
+use std::any::Any;
+use std::io::{Seek, SeekFrom, Write};
+use std::pin::Pin;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+/// In-process client for a schema
+pub struct TestClient<Query, Mutation, Subscription> {
+    schema: Schema<Query, Mutation, Subscription>,
+}
+
+impl<Query, Mutation, Subscription> TestClient<Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    /// Create a client for the schema.
+    pub fn new(schema: Schema<Query, Mutation, Subscription>) -> Self {
+        TestClient { schema }
+    }
+
+    /// Start a query or a mutation.
+    pub fn query(
+        &self,
+        source: impl Into<String>,
+    ) -> TestRequest<'_, Query, Mutation, Subscription> {
+        TestRequest {
+            schema: &self.schema,
+            builder: QueryBuilder::new(source),
+        }
+    }
+
+    /// Open a websocket subscription connection with the default `WebSocketTransport`.
+    pub fn websocket(&self) -> TestWebSocket {
+        self.websocket_with_transport(WebSocketTransport::default())
+    }
+
+    /// Open a websocket subscription connection with this transport.
+    pub fn websocket_with_transport(&self, transport: WebSocketTransport) -> TestWebSocket {
+        let (sink, stream) = self.schema.subscription_connection(transport);
+        TestWebSocket {
+            sink,
+            stream: Box::pin(stream),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Query or mutation of a `TestClient`
+pub struct TestRequest<'a, Query, Mutation, Subscription> {
+    schema: &'a Schema<Query, Mutation, Subscription>,
+    builder: QueryBuilder,
+}
+
+impl<'a, Query, Mutation, Subscription> TestRequest<'a, Query, Mutation, Subscription>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    /// Set the variables from a JSON object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `variables` is not a JSON object.
+    pub fn variables(self, variables: serde_json::Value) -> Self {
+        TestRequest {
+            builder: self
+                .builder
+                .variables(Variables::parse_from_json(variables).expect("Invalid variables")),
+            ..self
+        }
+    }
+
+    /// Set the operation name.
+    pub fn operation_name(self, name: impl Into<String>) -> Self {
+        TestRequest {
+            builder: self.builder.operator_name(name),
+            ..self
+        }
+    }
+
+    /// Add a context data, such as a value that an integration would extract from the request
+    /// headers.
+    pub fn data<D: Any + Send + Sync>(self, data: D) -> Self {
+        TestRequest {
+            builder: self.builder.data(data),
+            ..self
+        }
+    }
+
+    /// Upload a file for the variable at `var_path`, for example `variables.files.0`.
+    pub fn upload(
+        mut self,
+        var_path: &str,
+        filename: impl Into<String>,
+        content_type: Option<&str>,
+        content: impl AsRef<[u8]>,
+    ) -> Self {
+        let mut file = tempfile::tempfile().expect("Failed to create temporary file");
+        file.write_all(content.as_ref())
+            .and_then(|_| file.seek(SeekFrom::Start(0)))
+            .expect("Failed to write temporary file");
+        self.builder.set_upload(
+            var_path,
+            filename.into(),
+            content_type.map(ToString::to_string),
+            file,
+        );
+        self
+    }
+
+    /// Execute the operation.
+    pub async fn execute(self) -> TestResponse {
+        TestResponse(self.builder.execute(self.schema).await)
+    }
+}
+
+/// Response of a `TestRequest`
+pub struct TestResponse(pub Result<QueryResponse>);
+
+impl TestResponse {
+    /// Returns the response as it would be serialized to the client.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(GQLResponseRef(&self.0)).unwrap()
+    }
+
+    /// Assert that the operation succeeded with this data.
+    pub fn assert_data(&self, expected: serde_json::Value) -> &Self {
+        match &self.0 {
+            Ok(resp) => assert_eq!(resp.data, expected),
+            Err(_) => panic!("Expected data, got errors: {}", self.to_json()),
+        }
+        self
+    }
+
+    /// Assert that the operation failed with an error that has this `code` in its extensions.
+    pub fn assert_error_code(&self, code: &str) -> &Self {
+        let resp = self.to_json();
+        let has_code = resp["errors"]
+            .as_array()
+            .map(|errors| errors.iter().any(|err| err["extensions"]["code"] == code))
+            .unwrap_or_default();
+        assert!(
+            has_code,
+            "Expected an error with code {}, got: {}",
+            code, resp
+        );
+        self
+    }
+
+    /// Assert that the operation failed with an error that has this message.
+    pub fn assert_error_message(&self, message: &str) -> &Self {
+        let resp = self.to_json();
+        let has_message = resp["errors"]
+            .as_array()
+            .map(|errors| errors.iter().any(|err| err["message"] == message))
+            .unwrap_or_default();
+        assert!(
+            has_message,
+            "Expected an error with message {:?}, got: {}",
+            message, resp
+        );
+        self
+    }
+}
+
+/// In-memory websocket subscription connection of a `TestClient`
+///
+/// Messages are sent and received as JSON values. Receiving a message panics if none arrives
+/// within the timeout, 5 seconds by default.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::test::TestClient;
+/// use async_graphql::*;
+/// use futures::Stream;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// struct SubscriptionRoot;
+///
+/// #[Subscription]
+/// impl SubscriptionRoot {
+///     async fn values(&self) -> impl Stream<Item = i32> {
+///         futures::stream::iter(0..2)
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let client = TestClient::new(Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot));
+///     let mut ws = client.websocket();
+///     ws.init(None).await;
+///     ws.start("1", "subscription { values }", None).await;
+///     ws.assert_data("1", serde_json::json!({ "values": 0 })).await;
+///     ws.assert_data("1", serde_json::json!({ "values": 1 })).await;
+///     ws.assert_complete("1").await;
+/// });
+/// ```
+pub struct TestWebSocket {
+    sink: mpsc::UnboundedSender<Bytes>,
+    stream: Pin<Box<dyn Stream<Item = Bytes> + Send>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Duration,
+}
+
+impl TestWebSocket {
+    /// Set how long to wait for a message.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        TestWebSocket { timeout, ..self }
+    }
+
+    /// Send a message.
+    pub async fn send(&mut self, msg: serde_json::Value) {
+        self.sink
+            .send(Bytes::from(msg.to_string()))
+            .await
+            .expect("The connection is closed");
+    }
+
+    /// Receive a message, returns `None` if the connection has been closed.
+    pub async fn receive(&mut self) -> Option<serde_json::Value> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let bytes = {
+            let timeout = futures_timer::Delay::new(self.timeout);
+            match futures::future::select(self.stream.next(), timeout).await {
+                futures::future::Either::Left((bytes, _)) => bytes,
+                futures::future::Either::Right(_) => panic!("Timeout waiting for a message"),
+            }
+        };
+        #[cfg(target_arch = "wasm32")]
+        let bytes = self.stream.next().await;
+        bytes.map(|bytes| serde_json::from_slice(&bytes).expect("Invalid message"))
+    }
+
+    async fn receive_message(&mut self) -> serde_json::Value {
+        self.receive().await.expect("The connection is closed")
+    }
+
+    /// Send `connection_init` with an optional payload, and assert that the connection is
+    /// acknowledged.
+    pub async fn init(&mut self, payload: Option<serde_json::Value>) {
+        let mut msg = serde_json::json!({ "type": "connection_init" });
+        if let Some(payload) = payload {
+            msg["payload"] = payload;
+        }
+        self.send(msg).await;
+        let msg = self.receive_message().await;
+        assert_eq!(msg["type"], "connection_ack", "Unexpected message: {}", msg);
+    }
+
+    /// Start a subscription.
+    pub async fn start(&mut self, id: &str, query: &str, variables: Option<serde_json::Value>) {
+        self.send(serde_json::json!({
+            "type": "start",
+            "id": id,
+            "payload": { "query": query, "variables": variables },
+        }))
+        .await;
+    }
+
+    /// Stop a subscription.
+    pub async fn stop(&mut self, id: &str) {
+        self.send(serde_json::json!({ "type": "stop", "id": id }))
+            .await;
+    }
+
+    /// Assert that the next message is a result of the subscription with this data.
+    pub async fn assert_data(&mut self, id: &str, expected: serde_json::Value) {
+        let msg = self.receive_message().await;
+        assert!(
+            (msg["type"] == "data" || msg["type"] == "next") && msg["id"] == id,
+            "Expected data for {}, got: {}",
+            id,
+            msg
+        );
+        assert_eq!(msg["payload"]["data"], expected);
+    }
+
+    /// Assert that the next message is an error of the subscription, and returns its payload.
+    pub async fn assert_error(&mut self, id: &str) -> serde_json::Value {
+        let msg = self.receive_message().await;
+        assert!(
+            msg["type"] == "error" && msg["id"] == id,
+            "Expected an error for {}, got: {}",
+            id,
+            msg
+        );
+        msg["payload"].clone()
+    }
+
+    /// Assert that the next message tells that the subscription is complete.
+    pub async fn assert_complete(&mut self, id: &str) {
+        let msg = self.receive_message().await;
+        assert!(
+            msg["type"] == "complete" && msg["id"] == id,
+            "Expected complete for {}, got: {}",
+            id,
+            msg
+        );
+    }
+}
@@ -148,7 +148,7 @@ where
                         "pageInfo".to_string(),
                         registry::MetaField {
                             name: "pageInfo".to_string(),
-                            description: Some("Information to aid in pagination."),
+                            description: Some("Information to aid in pagination.".to_string()),
                             args: Default::default(),
                             ty: PageInfo::create_type_info(registry),
                             deprecation: None,
@@ -156,6 +156,8 @@ where
                             external: false,
                             requires: None,
                             provides: None,
+                            visible: None,
+                            directives: Default::default(),
                         },
                     );
 
@@ -163,7 +165,7 @@ where
                         "edges".to_string(),
                         registry::MetaField {
                             name: "edges".to_string(),
-                            description: Some("A list of edges."),
+                            description: Some("A list of edges.".to_string()),
                             args: Default::default(),
                             ty: <Option<Vec<Option<Edge<C, T, EE>>>> as Type>::create_type_info(
                                 registry,
@@ -173,6 +175,8 @@ where
                             external: false,
                             requires: None,
                             provides: None,
+                            visible: None,
+                            directives: Default::default(),
                         },
                     );
 
@@ -182,6 +186,8 @@ where
                 cache_control: Default::default(),
                 extends: false,
                 keys: None,
+                visible: None,
+                directives: Default::default(),
             }
         })
     }
@@ -60,7 +60,7 @@ where
 
             registry::MetaType::Object {
                 name: Self::type_name().to_string(),
-                description: Some("An edge in a connection."),
+                description: Some("An edge in a connection.".to_string()),
                 fields: {
                     let mut fields = IndexMap::new();
 
@@ -68,7 +68,7 @@ where
                         "node".to_string(),
                         registry::MetaField {
                             name: "node".to_string(),
-                            description: Some("The item at the end of the edge"),
+                            description: Some("The item at the end of the edge".to_string()),
                             args: Default::default(),
                             ty: T::create_type_info(registry),
                             deprecation: None,
@@ -76,6 +76,8 @@ where
                             external: false,
                             requires: None,
                             provides: None,
+                            visible: None,
+                            directives: Default::default(),
                         },
                     );
 
@@ -83,7 +85,7 @@ where
                         "cursor".to_string(),
                         registry::MetaField {
                             name: "cursor".to_string(),
-                            description: Some("A cursor for use in pagination"),
+                            description: Some("A cursor for use in pagination".to_string()),
                             args: Default::default(),
                             ty: String::create_type_info(registry),
                             deprecation: None,
@@ -91,6 +93,8 @@ where
                             external: false,
                             requires: None,
                             provides: None,
+                            visible: None,
+                            directives: Default::default(),
                         },
                     );
 
@@ -100,6 +104,8 @@ where
                 cache_control: Default::default(),
                 extends: false,
                 keys: None,
+                visible: None,
+                directives: Default::default(),
             }
         })
     }
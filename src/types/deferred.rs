@@ -1,4 +1,4 @@
-use crate::context::DeferList;
+use crate::context::{DeferList, ResponseErrors};
 use crate::registry::Registry;
 use crate::{ContextSelectionSet, OutputValueType, Positioned, QueryResponse, Result, Type};
 use async_graphql_parser::query::Field;
@@ -65,12 +65,14 @@ impl<T: OutputValueType + Send + Sync + 'static> OutputValueType for Deferred<T>
                             path_prefix: path_prefix.clone(),
                             futures: Default::default(),
                         };
+                        let errors = ResponseErrors::default();
                         let ctx = query_env.create_context(
                             &schema_env,
                             None,
                             &field.selection_set,
                             &inc_resolve_id,
                             Some(&defer_list),
+                            &errors,
                         );
                         let data = obj.resolve(&ctx, &field).await?;
 
@@ -79,6 +81,7 @@ impl<T: OutputValueType + Send + Sync + 'static> OutputValueType for Deferred<T>
                                 label: None,
                                 path: Some(path_prefix),
                                 data,
+                                errors: errors.take(),
                                 extensions: None,
                                 cache_control: Default::default(),
                             },
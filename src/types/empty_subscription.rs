@@ -22,6 +22,8 @@ impl Type for EmptySubscription {
             cache_control: Default::default(),
             extends: false,
             keys: None,
+            visible: None,
+            directives: Default::default(),
         })
     }
 }
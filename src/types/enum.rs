@@ -4,6 +4,7 @@ use crate::{InputValueError, InputValueResult, Type, Value};
 pub struct EnumItem<T> {
     pub name: &'static str,
     pub value: T,
+    pub custom_value: Option<&'static str>,
 }
 
 #[allow(missing_docs)]
@@ -12,15 +13,15 @@ pub trait EnumType: Type + Sized + Eq + Send + Copy + Sized + 'static {
     fn items() -> &'static [EnumItem<Self>];
 
     fn parse_enum(value: Value) -> InputValueResult<Self> {
-        let value = match &value {
-            Value::Enum(s) => s,
-            Value::String(s) => s.as_str(),
+        let (value, is_string) = match &value {
+            Value::Enum(s) => (s.as_str(), false),
+            Value::String(s) => (s.as_str(), true),
             _ => return Err(InputValueError::ExpectedType(value)),
         };
 
         let items = Self::items();
         for item in items {
-            if item.name == value {
+            if item.name == value || (is_string && item.custom_value == Some(value)) {
                 return Ok(item.value);
             }
         }
@@ -40,4 +41,18 @@ pub trait EnumType: Type + Sized + Eq + Send + Copy + Sized + 'static {
         }
         unreachable!()
     }
+
+    /// The value of the item in the response, the custom value of the item if it has one.
+    fn to_output_value(&self) -> Value {
+        let items = Self::items();
+        for item in items {
+            if item.value == *self {
+                return match item.custom_value {
+                    Some(custom_value) => Value::String(custom_value.to_string()),
+                    None => Value::Enum(item.name.into()),
+                };
+            }
+        }
+        unreachable!()
+    }
 }
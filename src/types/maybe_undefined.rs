@@ -107,6 +107,19 @@ impl<T: Type> Type for MaybeUndefined<T> {
 }
 
 impl<T: InputValueType> InputValueType for MaybeUndefined<T> {
+    fn input_type_name() -> Cow<'static, str> {
+        T::input_type_name()
+    }
+
+    fn qualified_input_type_name() -> String {
+        T::input_type_name().to_string()
+    }
+
+    fn create_input_type_info(registry: &mut registry::Registry) -> String {
+        T::create_input_type_info(registry);
+        T::input_type_name().to_string()
+    }
+
     fn parse(value: Option<Value>) -> InputValueResult<Self> {
         match value {
             None => Ok(MaybeUndefined::Undefined),
@@ -1,4 +1,6 @@
 pub mod connection;
+pub mod page;
+pub mod relay;
 
 mod deferred;
 mod empty_mutation;
@@ -16,6 +18,7 @@ pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
 pub use maybe_undefined::MaybeUndefined;
 pub use query_root::QueryRoot;
+pub(crate) use query_root::{add_introspection_fields, resolve_introspection_field};
 pub use r#enum::{EnumItem, EnumType};
 pub use streamed::Streamed;
 pub use upload::Upload;
@@ -0,0 +1,245 @@
+//! Offset based pagination
+
+use crate::{
+    do_resolve, registry, Context, ContextSelectionSet, FieldResult, ObjectType, OutputValueType,
+    Positioned, QueryError, Result, Type,
+};
+use async_graphql_derive::SimpleObject;
+use async_graphql_parser::query::Field;
+use indexmap::map::IndexMap;
+use std::borrow::Cow;
+use std::future::Future;
+
+/// Information about pagination in a page
+#[SimpleObject(internal)]
+pub struct OffsetPageInfo {
+    /// Are there more items after this page?
+    pub has_next: bool,
+
+    /// Are there items before this page?
+    pub has_prev: bool,
+}
+
+/// A page of items, with the total count of the items
+pub struct Page<T> {
+    items: Vec<T>,
+    offset: usize,
+    total_count: usize,
+}
+
+impl<T> Page<T> {
+    /// Create a new page with the items starting at `offset` and the total count of the items.
+    pub fn new(items: Vec<T>, offset: usize, total_count: usize) -> Self {
+        Self {
+            items,
+            offset,
+            total_count,
+        }
+    }
+
+    /// The items of the page.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// The information about pagination in the page.
+    pub fn page_info(&self) -> OffsetPageInfo {
+        OffsetPageInfo {
+            has_next: self.offset + self.items.len() < self.total_count,
+            has_prev: self.offset > 0,
+        }
+    }
+}
+
+impl<T> Type for Page<T>
+where
+    T: OutputValueType + Send + Sync,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}Page", T::type_name()))
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        registry.create_type::<Self, _>(|registry| registry::MetaType::Object {
+            name: Self::type_name().to_string(),
+            description: Some("A page of items.".to_string()),
+            fields: {
+                let mut fields = IndexMap::new();
+
+                fields.insert(
+                    "items".to_string(),
+                    registry::MetaField {
+                        name: "items".to_string(),
+                        description: Some("The items of the page.".to_string()),
+                        args: Default::default(),
+                        ty: <Vec<T> as Type>::create_type_info(registry),
+                        deprecation: None,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        directives: Default::default(),
+                    },
+                );
+
+                fields.insert(
+                    "totalCount".to_string(),
+                    registry::MetaField {
+                        name: "totalCount".to_string(),
+                        description: Some("The total count of the items.".to_string()),
+                        args: Default::default(),
+                        ty: i32::create_type_info(registry),
+                        deprecation: None,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        directives: Default::default(),
+                    },
+                );
+
+                fields.insert(
+                    "pageInfo".to_string(),
+                    registry::MetaField {
+                        name: "pageInfo".to_string(),
+                        description: Some("Information to aid in pagination.".to_string()),
+                        args: Default::default(),
+                        ty: OffsetPageInfo::create_type_info(registry),
+                        deprecation: None,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        directives: Default::default(),
+                    },
+                );
+
+                fields
+            },
+            cache_control: Default::default(),
+            extends: false,
+            keys: None,
+            visible: None,
+            directives: Default::default(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> ObjectType for Page<T>
+where
+    T: OutputValueType + Send + Sync,
+{
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        if ctx.name.node == "items" {
+            let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
+            return OutputValueType::resolve(&self.items, &ctx_obj, ctx.item).await;
+        } else if ctx.name.node == "totalCount" {
+            return Ok((self.total_count as i32).into());
+        } else if ctx.name.node == "pageInfo" {
+            let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
+            return OutputValueType::resolve(&self.page_info(), &ctx_obj, ctx.item).await;
+        }
+
+        Err(QueryError::FieldNotFound {
+            field_name: ctx.name.to_string(),
+            object: Self::type_name().to_string(),
+        }
+        .into_error(ctx.position()))
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> OutputValueType for Page<T>
+where
+    T: OutputValueType + Send + Sync,
+{
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> Result<serde_json::Value> {
+        do_resolve(ctx, self).await
+    }
+}
+
+/// Validate the `offset` and `limit` pagination arguments and query a page
+///
+/// A negative `offset` or `limit`, or a `limit` greater than `max_limit` is an error. If `limit`
+/// is not specified, `max_limit` is used. The function `f` is called with the offset and the
+/// limit.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::page::*;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn numbers(&self, offset: Option<i32>, limit: Option<i32>) -> FieldResult<Page<i32>> {
+///         page::query(offset, limit, 10, |offset, limit| async move {
+///             let items = (offset..(offset + limit).min(100)).map(|n| n as i32).collect();
+///             Ok(Page::new(items, offset, 100))
+///         }).await
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema
+///         .execute("{ numbers(offset: 97, limit: 5) { items totalCount pageInfo { hasNext hasPrev } } }")
+///         .await
+///         .unwrap()
+///         .data;
+///     assert_eq!(res, serde_json::json!({
+///         "numbers": {
+///             "items": [97, 98, 99],
+///             "totalCount": 100,
+///             "pageInfo": { "hasNext": false, "hasPrev": true },
+///         }
+///     }));
+///
+///     assert!(schema.execute("{ numbers(limit: 11) { totalCount } }").await.is_err());
+/// });
+/// ```
+pub async fn query<T, F, R>(
+    offset: Option<i32>,
+    limit: Option<i32>,
+    max_limit: usize,
+    f: F,
+) -> FieldResult<Page<T>>
+where
+    F: FnOnce(usize, usize) -> R,
+    R: Future<Output = FieldResult<Page<T>>>,
+{
+    let offset = match offset {
+        Some(offset) if offset < 0 => {
+            return Err("The \"offset\" parameter must be a non-negative number".into())
+        }
+        Some(offset) => offset as usize,
+        None => 0,
+    };
+
+    let limit = match limit {
+        Some(limit) if limit < 0 => {
+            return Err("The \"limit\" parameter must be a non-negative number".into())
+        }
+        Some(limit) if limit as usize > max_limit => {
+            return Err(format!(
+                "The \"limit\" parameter must not be greater than {}",
+                max_limit
+            )
+            .into())
+        }
+        Some(limit) => limit as usize,
+        None => max_limit,
+    };
+
+    f(offset, limit).await
+}
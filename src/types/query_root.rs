@@ -26,96 +26,127 @@ impl<T: Type> Type for QueryRoot<T> {
     }
 
     fn create_type_info(registry: &mut registry::Registry) -> String {
-        let schema_type = __Schema::create_type_info(registry);
         let root = T::create_type_info(registry);
-        if let Some(registry::MetaType::Object { fields, .. }) =
-            registry.types.get_mut(T::type_name().as_ref())
-        {
-            fields.insert(
-                "__schema".to_string(),
-                registry::MetaField {
-                    name: "__schema".to_string(),
-                    description: Some("Access the current type schema of this server."),
-                    args: Default::default(),
-                    ty: schema_type,
-                    deprecation: None,
-                    cache_control: Default::default(),
-                    external: false,
-                    requires: None,
-                    provides: None,
-                },
-            );
-
-            fields.insert(
-                "__type".to_string(),
-                registry::MetaField {
-                    name: "__type".to_string(),
-                    description: Some("Request the type information of a single type."),
-                    args: {
-                        let mut args = IndexMap::new();
-                        args.insert(
-                            "name",
-                            registry::MetaInputValue {
-                                name: "name",
-                                description: None,
-                                ty: "String!".to_string(),
-                                default_value: None,
-                                validator: None,
-                            },
-                        );
-                        args
-                    },
-                    ty: "__Type".to_string(),
-                    deprecation: None,
-                    cache_control: Default::default(),
-                    external: false,
-                    requires: None,
-                    provides: None,
-                },
-            );
-        }
+        add_introspection_fields(registry, T::type_name().as_ref());
         root
     }
 }
 
-#[async_trait::async_trait]
-impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
-    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
-        if ctx.name.node == "__schema" {
-            if self.disable_introspection {
-                return Err(Error::Query {
-                    pos: ctx.position(),
-                    path: Some(ctx.path_node.as_ref().unwrap().to_json().into()),
-                    err: QueryError::FieldNotFound {
-                        field_name: ctx.name.to_string(),
-                        object: Self::type_name().to_string(),
-                    },
-                });
-            }
+/// Add the `__schema` and `__type` fields to the query root type.
+pub(crate) fn add_introspection_fields(registry: &mut registry::Registry, type_name: &str) {
+    let schema_type = __Schema::create_type_info(registry);
+    if let Some(registry::MetaType::Object { fields, .. }) = registry.types.get_mut(type_name) {
+        fields.insert(
+            "__schema".to_string(),
+            registry::MetaField {
+                name: "__schema".to_string(),
+                description: Some("Access the current type schema of this server.".to_string()),
+                args: Default::default(),
+                ty: schema_type,
+                deprecation: None,
+                cache_control: Default::default(),
+                external: false,
+                requires: None,
+                provides: None,
+                visible: None,
+                directives: Default::default(),
+            },
+        );
 
-            let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
-            return OutputValueType::resolve(
+        fields.insert(
+            "__type".to_string(),
+            registry::MetaField {
+                name: "__type".to_string(),
+                description: Some("Request the type information of a single type.".to_string()),
+                args: {
+                    let mut args = IndexMap::new();
+                    args.insert(
+                        "name".to_string(),
+                        registry::MetaInputValue {
+                            name: "name".to_string(),
+                            description: None,
+                            ty: "String!".to_string(),
+                            default_value: None,
+                            validator: None,
+                            visible: None,
+                            directives: Default::default(),
+                            is_secret: false,
+                        },
+                    );
+                    args
+                },
+                ty: "__Type".to_string(),
+                deprecation: None,
+                cache_control: Default::default(),
+                external: false,
+                requires: None,
+                provides: None,
+                visible: None,
+                directives: Default::default(),
+            },
+        );
+    }
+}
+
+/// Resolve the `__schema` and `__type` fields, returns `None` for the other fields.
+pub(crate) async fn resolve_introspection_field(
+    ctx: &Context<'_>,
+) -> Option<Result<serde_json::Value>> {
+    if ctx.name.node == "__schema" {
+        let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
+        Some(
+            OutputValueType::resolve(
                 &__Schema {
                     registry: &ctx.schema_env.registry,
                 },
                 &ctx_obj,
                 ctx.item,
             )
-            .await;
-        } else if ctx.name.node == "__type" {
-            let type_name: String = ctx.param_value("name", None)?;
-            let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
-            return OutputValueType::resolve(
+            .await,
+        )
+    } else if ctx.name.node == "__type" {
+        let type_name: String = match ctx.param_value("name", None) {
+            Ok(type_name) => type_name,
+            Err(err) => return Some(Err(err)),
+        };
+        let ctx_obj = ctx.with_selection_set(&ctx.selection_set);
+        Some(
+            OutputValueType::resolve(
                 &ctx.schema_env
                     .registry
                     .types
                     .get(&type_name)
+                    .filter(|ty| ty.is_visible(&ctx.visible_context()))
                     .map(|ty| __Type::new_simple(&ctx.schema_env.registry, ty)),
                 &ctx_obj,
                 ctx.item,
             )
-            .await;
-        } else if ctx.name.node == "_entities" {
+            .await,
+        )
+    } else {
+        None
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        if ctx.name.node == "__schema" && self.disable_introspection {
+            return Err(Error::Query {
+                pos: ctx.position(),
+                path: Some(ctx.path_node.as_ref().unwrap().to_json().into()),
+                err: QueryError::FieldNotFound {
+                    field_name: ctx.name.to_string(),
+                    object: Self::type_name().to_string(),
+                },
+            });
+        }
+
+        if let Some(res) = resolve_introspection_field(ctx).await {
+            return res;
+        }
+
+        if ctx.name.node == "_entities" {
             let representations: Vec<Any> = ctx.param_value("representations", None)?;
             let mut res = Vec::new();
             for item in representations {
@@ -0,0 +1,147 @@
+//! Relay global object identification
+//!
+//! Reference: https://relay.dev/graphql/objectidentification.htm
+
+use crate::{Context, FieldResult, OutputValueType, ID};
+use async_graphql_derive::Object;
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
+
+/// Global object id
+///
+/// The type name and the key of an object, encoded as a base64 string of `TypeName:key`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalId {
+    /// The GraphQL type name of the object.
+    pub type_name: String,
+
+    /// The key of the object, unique within its type.
+    pub id: String,
+}
+
+impl GlobalId {
+    /// Create a global id with the type name and the key of an object.
+    pub fn new(type_name: impl Into<String>, id: impl Display) -> Self {
+        Self {
+            type_name: type_name.into(),
+            id: id.to_string(),
+        }
+    }
+
+    /// Encode the global id to an `ID`.
+    pub fn encode(&self) -> ID {
+        self.to_string().into()
+    }
+
+    /// Decode a global id.
+    pub fn decode(id: &str) -> Result<Self, anyhow::Error> {
+        let data = String::from_utf8(base64::decode(id)?)?;
+        match data.find(':') {
+            Some(idx) => Ok(Self {
+                type_name: data[..idx].to_string(),
+                id: data[idx + 1..].to_string(),
+            }),
+            None => Err(anyhow::anyhow!("Invalid global id.")),
+        }
+    }
+}
+
+impl Display for GlobalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            base64::encode(format!("{}:{}", self.type_name, self.id))
+        )
+    }
+}
+
+/// A type that can be fetched by its global id, usually the `Node` interface of the schema.
+///
+/// The interface is defined like any other interface, with an `id: ID!` field, and `fetch`
+/// dispatches to the fetcher of each type according to the type name of the global id.
+#[async_trait::async_trait]
+pub trait NodeType: OutputValueType + Send + Sync + Sized + 'static {
+    /// Fetch the object with the global id, returns `None` if it does not exist.
+    async fn fetch(ctx: &Context<'_>, id: GlobalId) -> FieldResult<Option<Self>>;
+}
+
+/// An object with the root `node(id: ID!)` field of the Relay specification
+///
+/// It is usually merged into the query root with `MergedObject`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::relay::{GlobalId, NodeQuery, NodeType};
+///
+/// struct User(i32);
+///
+/// #[Object]
+/// impl User {
+///     async fn id(&self) -> ID {
+///         GlobalId::new("User", self.0).encode()
+///     }
+///
+///     async fn name(&self) -> String {
+///         format!("user{}", self.0)
+///     }
+/// }
+///
+/// #[Interface(field(name = "id", type = "ID"))]
+/// enum Node {
+///     User(User),
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl NodeType for Node {
+///     async fn fetch(_ctx: &Context<'_>, id: GlobalId) -> FieldResult<Option<Self>> {
+///         Ok(match id.type_name.as_str() {
+///             "User" => Some(User(id.id.parse()?).into()),
+///             _ => None,
+///         })
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQuery;
+///
+/// #[Object]
+/// impl UserQuery {
+///     async fn me(&self) -> User {
+///         User(1)
+///     }
+/// }
+///
+/// #[MergedObject(name = "Query")]
+/// #[derive(Default)]
+/// struct QueryRoot(UserQuery, NodeQuery<Node>);
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot::default(), EmptyMutation, EmptySubscription);
+///     let id = GlobalId::new("User", 2).encode();
+///     let query = format!(r#"{{ node(id: "{}") {{ ... on User {{ name }} }} }}"#, id.as_str());
+///     let res = schema.execute(&query).await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "node": { "name": "user2" } }));
+/// });
+/// ```
+pub struct NodeQuery<N>(PhantomData<N>);
+
+impl<N> Default for NodeQuery<N> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[Object(internal)]
+impl<N: NodeType> NodeQuery<N> {
+    /// Fetches an object given its ID.
+    async fn node(
+        &self,
+        ctx: &Context<'_>,
+        #[arg(desc = "ID of the object.")] id: ID,
+    ) -> FieldResult<Option<N>> {
+        N::fetch(ctx, GlobalId::decode(&id)?).await
+    }
+}
@@ -1,4 +1,4 @@
-use crate::context::DeferList;
+use crate::context::{DeferList, ResponseErrors};
 use crate::registry::Registry;
 use crate::{ContextSelectionSet, OutputValueType, Positioned, QueryResponse, Result, Type};
 use async_graphql_parser::query::Field;
@@ -76,12 +76,14 @@ impl<T: OutputValueType + Send + Sync + 'static> OutputValueType for Streamed<T>
                                 path_prefix: path_prefix.clone(),
                                 futures: Default::default(),
                             };
+                            let errors = ResponseErrors::default();
                             let ctx = query_env.create_context(
                                 &schema_env,
                                 None,
                                 &field.selection_set,
                                 &inc_resolve_id,
                                 Some(&defer_list),
+                                &errors,
                             );
                             let data = item.resolve(&ctx, &field).await?;
 
@@ -90,6 +92,7 @@ impl<T: OutputValueType + Send + Sync + 'static> OutputValueType for Streamed<T>
                                     label: None,
                                     path: Some(path_prefix),
                                     data,
+                                    errors: errors.take(),
                                     extensions: None,
                                     cache_control: Default::default(),
                                 },
@@ -1,7 +1,10 @@
 use crate::{registry, InputValueError, InputValueResult, InputValueType, Type, Value};
-use async_graphql_parser::UploadValue;
+use async_graphql_parser::{UploadContent, UploadValue};
+use futures::io::{AllowStdIo, AsyncRead};
 use std::borrow::Cow;
-use std::io::Read;
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
 
 /// Uploaded file
 ///
@@ -59,7 +62,41 @@ impl Upload {
     ///
     /// **Note**: this is a *synchronous/blocking* reader.
     pub fn into_read(self) -> impl Read + Sync + Send + 'static {
-        self.0.content
+        UploadReader::new(self.0.content)
+    }
+
+    /// Convert to an `AsyncRead`.
+    ///
+    /// The uploaded file is stored in a temporary file while the request is received, unless it is
+    /// smaller than `IntoQueryBuilderOpts::file_spill_threshold`, so it can be copied to other
+    /// storage without holding the whole content in memory.
+    ///
+    /// **Note**: reading from the temporary file is still blocking.
+    pub fn into_async_read(self) -> impl AsyncRead + Sync + Send + Unpin + 'static {
+        AllowStdIo::new(UploadReader::new(self.0.content))
+    }
+}
+
+enum UploadReader {
+    Memory(Cursor<Arc<[u8]>>),
+    File(File),
+}
+
+impl UploadReader {
+    fn new(content: UploadContent) -> Self {
+        match content {
+            UploadContent::Memory(data) => UploadReader::Memory(Cursor::new(data)),
+            UploadContent::File(file) => UploadReader::File(file),
+        }
+    }
+}
+
+impl Read for UploadReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            UploadReader::Memory(cursor) => cursor.read(buf),
+            UploadReader::File(file) => file.read(buf),
+        }
     }
 }
 
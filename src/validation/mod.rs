@@ -9,13 +9,15 @@ mod test_harness;
 
 use crate::parser::query::Document;
 use crate::registry::Registry;
-use crate::{CacheControl, Error, Result};
+use crate::{CacheControl, Error, Result, Variables, VisibleContext};
+use std::collections::HashSet;
 use visitor::{visit, VisitorContext, VisitorNil};
 
 pub struct CheckResult {
     pub cache_control: CacheControl,
     pub complexity: usize,
     pub depth: usize,
+    pub secret_variables: HashSet<String>,
 }
 
 /// Validation mode
@@ -31,12 +33,15 @@ pub enum ValidationMode {
 pub fn check_rules(
     registry: &Registry,
     doc: &Document,
+    variables: Option<&Variables>,
+    visible: Option<VisibleContext<'_>>,
     mode: ValidationMode,
 ) -> Result<CheckResult> {
-    let mut ctx = VisitorContext::new(registry, doc);
+    let mut ctx = VisitorContext::new(registry, doc, variables, visible);
     let mut cache_control = CacheControl::default();
     let mut complexity = 0;
     let mut depth = 0;
+    let mut secret_variables = HashSet::new();
 
     match mode {
         ValidationMode::Strict => {
@@ -65,26 +70,30 @@ pub fn check_rules(
                 .with(rules::KnownDirectives::default())
                 .with(rules::OverlappingFieldsCanBeMerged)
                 .with(rules::UploadFile)
+                .with(rules::VisibleItems)
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                 })
                 .with(visitors::ComplexityCalculate {
                     complexity: &mut complexity,
                 })
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::DepthCalculate::new(&mut depth))
+                .with(visitors::SecretVariables::new(&mut secret_variables));
             visit(&mut visitor, &mut ctx, doc);
         }
         ValidationMode::Fast => {
             let mut visitor = VisitorNil
                 .with(rules::NoFragmentCycles::default())
                 .with(rules::UploadFile)
+                .with(rules::VisibleItems)
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                 })
                 .with(visitors::ComplexityCalculate {
                     complexity: &mut complexity,
                 })
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::DepthCalculate::new(&mut depth))
+                .with(visitors::SecretVariables::new(&mut secret_variables));
             visit(&mut visitor, &mut ctx, doc);
         }
     }
@@ -96,5 +105,6 @@ pub fn check_rules(
         cache_control,
         complexity,
         depth: depth as usize,
+        secret_variables,
     })
 }
@@ -8,7 +8,7 @@ use indexmap::map::IndexMap;
 
 #[derive(Default)]
 pub struct ArgumentsOfCorrectType<'a> {
-    current_args: Option<&'a IndexMap<&'static str, MetaInputValue>>,
+    current_args: Option<&'a IndexMap<String, MetaInputValue>>,
 }
 
 impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
@@ -58,7 +58,7 @@ impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
                 value,
                 QueryPathNode {
                     parent: None,
-                    segment: QueryPathSegment::Name(arg.name),
+                    segment: QueryPathSegment::Name(&arg.name),
                 },
             ) {
                 ctx.report_error(
@@ -22,6 +22,7 @@ mod unique_variable_names;
 mod upload_file;
 mod variables_are_input_types;
 mod variables_in_allowed_position;
+mod visible_items;
 
 pub use arguments_of_correct_type::ArgumentsOfCorrectType;
 pub use default_values_of_correct_type::DefaultValuesOfCorrectType;
@@ -47,3 +48,4 @@ pub use unique_variable_names::UniqueVariableNames;
 pub use upload_file::UploadFile;
 pub use variables_are_input_types::VariablesAreInputTypes;
 pub use variables_in_allowed_position::VariableInAllowedPosition;
+pub use visible_items::VisibleItems;
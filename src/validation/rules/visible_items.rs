@@ -0,0 +1,212 @@
+use crate::context::QueryPathNode;
+use crate::parser::query::{Field, FragmentDefinition, InlineFragment, VariableDefinition};
+use crate::registry::{MetaType, MetaTypeName};
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::{Pos, Positioned, QueryPathSegment, Value};
+
+/// Hidden types, fields, arguments, enum values and input fields are reported as if they did not
+/// exist.
+#[derive(Default)]
+pub struct VisibleItems;
+
+impl<'a> Visitor<'a> for VisibleItems {
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_definition: &'a Positioned<FragmentDefinition>,
+    ) {
+        check_current_type(ctx, fragment_definition.position());
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        if inline_fragment.type_condition.is_some() {
+            check_current_type(ctx, inline_fragment.position());
+        }
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        let var_type = variable_definition.var_type.to_string();
+        if let Some(ty) = ctx.registry.concrete_type_by_name(&var_type) {
+            if !ctx.is_type_visible(ty) {
+                ctx.report_error(
+                    vec![variable_definition.position()],
+                    format!(r#"Unknown type "{}""#, ty.name()),
+                );
+                return;
+            }
+        }
+
+        let value = ctx
+            .variables
+            .and_then(|variables| variables.get(variable_definition.name.as_str()))
+            .or_else(|| {
+                variable_definition
+                    .default_value
+                    .as_ref()
+                    .map(|value| &value.node)
+            });
+        if let Some(value) = value {
+            if let Some(reason) = check_input_value(
+                ctx,
+                &var_type,
+                value,
+                QueryPathNode {
+                    parent: None,
+                    segment: QueryPathSegment::Name(&variable_definition.name),
+                },
+            ) {
+                ctx.report_error(
+                    vec![variable_definition.position()],
+                    format!("Invalid value for variable {}", reason),
+                );
+            }
+        }
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let parent_type = match ctx.parent_type() {
+            Some(parent_type) => parent_type,
+            None => return,
+        };
+        let schema_field = match parent_type.field_by_name(&field.name) {
+            Some(schema_field) => schema_field,
+            None => return,
+        };
+
+        let type_visible = ctx
+            .registry
+            .concrete_type_by_name(&schema_field.ty)
+            .map(|ty| ctx.is_type_visible(ty))
+            .unwrap_or(true);
+        if !ctx.is_visible(&schema_field.visible) || !type_visible {
+            ctx.report_error(
+                vec![field.position()],
+                format!(
+                    "Unknown field \"{}\" on type \"{}\".",
+                    field.name,
+                    parent_type.name()
+                ),
+            );
+            return;
+        }
+
+        for (name, value) in &field.arguments {
+            let arg = match schema_field.args.get(name.as_str()) {
+                Some(arg) => arg,
+                None => continue,
+            };
+            let type_visible = ctx
+                .registry
+                .concrete_type_by_name(&arg.ty)
+                .map(|ty| ctx.is_type_visible(ty))
+                .unwrap_or(true);
+            if !ctx.is_visible(&arg.visible) || !type_visible {
+                ctx.report_error(
+                    vec![name.position()],
+                    format!(
+                        "Unknown argument \"{}\" on field \"{}\" of type \"{}\".",
+                        name,
+                        field.name,
+                        parent_type.name()
+                    ),
+                );
+            } else if let Some(reason) = check_input_value(
+                ctx,
+                &arg.ty,
+                value,
+                QueryPathNode {
+                    parent: None,
+                    segment: QueryPathSegment::Name(&arg.name),
+                },
+            ) {
+                ctx.report_error(
+                    vec![name.position()],
+                    format!("Invalid value for argument {}", reason),
+                );
+            }
+        }
+    }
+}
+
+fn check_current_type(ctx: &mut VisitorContext<'_>, pos: Pos) {
+    if let Some(ty) = ctx.current_type() {
+        if !ctx.is_type_visible(ty) {
+            ctx.report_error(vec![pos], format!(r#"Unknown type "{}""#, ty.name()));
+        }
+    }
+}
+
+/// Returns the reason if the value uses a hidden enum value or input field.
+///
+/// Variables in literal values are checked with the variable definitions.
+fn check_input_value(
+    ctx: &VisitorContext<'_>,
+    type_name: &str,
+    value: &Value,
+    path_node: QueryPathNode,
+) -> Option<String> {
+    match MetaTypeName::create(type_name) {
+        MetaTypeName::NonNull(type_name) => check_input_value(ctx, type_name, value, path_node),
+        MetaTypeName::List(type_name) => match value {
+            Value::List(elems) => elems.iter().enumerate().find_map(|(idx, elem)| {
+                check_input_value(
+                    ctx,
+                    type_name,
+                    elem,
+                    QueryPathNode {
+                        parent: Some(&path_node),
+                        segment: QueryPathSegment::Index(idx),
+                    },
+                )
+            }),
+            _ => check_input_value(ctx, type_name, value, path_node),
+        },
+        MetaTypeName::Named(type_name) => match (ctx.registry.types.get(type_name)?, value) {
+            (MetaType::Enum { enum_values, .. }, Value::Enum(name))
+            | (MetaType::Enum { enum_values, .. }, Value::String(name)) => {
+                match enum_values.get(name.as_str()) {
+                    Some(enum_value) if !ctx.is_visible(&enum_value.visible) => Some(format!(
+                        "\"{}\", enumeration type \"{}\" does not contain the value \"{}\"",
+                        path_node, type_name, name
+                    )),
+                    _ => None,
+                }
+            }
+            (MetaType::InputObject { input_fields, .. }, Value::Object(values)) => {
+                values.iter().find_map(|(name, value)| {
+                    let field = input_fields.get(name.as_str())?;
+                    let type_visible = ctx
+                        .registry
+                        .concrete_type_by_name(&field.ty)
+                        .map(|ty| ctx.is_type_visible(ty))
+                        .unwrap_or(true);
+                    if !ctx.is_visible(&field.visible) || !type_visible {
+                        Some(format!(
+                            "\"{}\", unknown field \"{}\" of type \"{}\"",
+                            path_node, name, type_name
+                        ))
+                    } else {
+                        check_input_value(
+                            ctx,
+                            &field.ty,
+                            value,
+                            QueryPathNode {
+                                parent: Some(&path_node),
+                                segment: QueryPathSegment::Name(&field.name),
+                            },
+                        )
+                    }
+                })
+            }
+            _ => None,
+        },
+    }
+}
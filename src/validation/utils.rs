@@ -125,22 +125,48 @@ pub fn is_valid_input_value(
                             format!("expected type \"{}\"", type_name),
                         )),
                     },
-                    registry::MetaType::InputObject { input_fields, .. } => match value {
+                    registry::MetaType::InputObject {
+                        input_fields,
+                        oneof,
+                        ..
+                    } => match value {
                         Value::Object(values) => {
+                            if *oneof {
+                                if values.len() != 1 {
+                                    return Some(valid_error(
+                                        &path_node,
+                                        format!(
+                                            "oneOf input object \"{}\" requires exactly one field",
+                                            ty.name()
+                                        ),
+                                    ));
+                                }
+                                if let Some((name, Value::Null)) = values.iter().next() {
+                                    return Some(valid_error(
+                                        &path_node,
+                                        format!(
+                                            "field \"{}\" of oneOf input object \"{}\" must be non-null",
+                                            name,
+                                            ty.name()
+                                        ),
+                                    ));
+                                }
+                            }
+
                             let mut input_names = values
                                 .keys()
                                 .map(|name| name.as_ref())
                                 .collect::<HashSet<_>>();
 
                             for field in input_fields.values() {
-                                input_names.remove(field.name);
-                                if let Some(value) = values.get(field.name) {
+                                input_names.remove(field.name.as_str());
+                                if let Some(value) = values.get(field.name.as_str()) {
                                     if let Some(validator) = &field.validator {
                                         if let Some(reason) = validator.is_valid(value) {
                                             return Some(valid_error(
                                                 &QueryPathNode {
                                                     parent: Some(&path_node),
-                                                    segment: QueryPathSegment::Name(field.name),
+                                                    segment: QueryPathSegment::Name(&field.name),
                                                 },
                                                 reason,
                                             ));
@@ -153,7 +179,7 @@ pub fn is_valid_input_value(
                                         value,
                                         QueryPathNode {
                                             parent: Some(&path_node),
-                                            segment: QueryPathSegment::Name(field.name),
+                                            segment: QueryPathSegment::Name(&field.name),
                                         },
                                     ) {
                                         return Some(reason);
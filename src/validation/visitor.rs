@@ -3,22 +3,30 @@ use crate::parser::query::{
     Definition, Directive, Document, Field, FragmentDefinition, FragmentSpread, InlineFragment,
     OperationDefinition, Selection, SelectionSet, TypeCondition, VariableDefinition,
 };
-use crate::registry::{self, MetaType, MetaTypeName};
-use crate::{Pos, Positioned, Value};
+use crate::registry::{self, MetaType, MetaTypeName, MetaVisibleFn};
+use crate::{Pos, Positioned, Value, Variables, VisibleContext};
 use std::collections::HashMap;
 
 pub struct VisitorContext<'a> {
     pub registry: &'a registry::Registry,
+    pub variables: Option<&'a Variables>,
     pub errors: Vec<RuleError>,
     type_stack: Vec<Option<&'a registry::MetaType>>,
     input_type: Vec<Option<MetaTypeName<'a>>>,
     fragments: HashMap<&'a str, &'a Positioned<FragmentDefinition>>,
+    visible: Option<VisibleContext<'a>>,
 }
 
 impl<'a> VisitorContext<'a> {
-    pub fn new(registry: &'a registry::Registry, doc: &'a Document) -> Self {
+    pub fn new(
+        registry: &'a registry::Registry,
+        doc: &'a Document,
+        variables: Option<&'a Variables>,
+        visible: Option<VisibleContext<'a>>,
+    ) -> Self {
         Self {
             registry,
+            variables,
             errors: Default::default(),
             type_stack: Default::default(),
             input_type: Default::default(),
@@ -30,6 +38,7 @@ impl<'a> VisitorContext<'a> {
                     _ => None,
                 })
                 .collect(),
+            visible,
         }
     }
 
@@ -86,6 +95,21 @@ impl<'a> VisitorContext<'a> {
     pub fn fragment(&self, name: &str) -> Option<&'a Positioned<FragmentDefinition>> {
         self.fragments.get(name).copied()
     }
+
+    /// Everything is visible if the query is validated without the data of a request.
+    pub fn is_visible(&self, visible: &Option<MetaVisibleFn>) -> bool {
+        match &self.visible {
+            Some(ctx) => registry::is_visible(ctx, visible),
+            None => true,
+        }
+    }
+
+    pub fn is_type_visible(&self, ty: &MetaType) -> bool {
+        match &self.visible {
+            Some(ctx) => ty.is_visible(ctx),
+            None => true,
+        }
+    }
 }
 
 pub trait Visitor<'a> {
@@ -0,0 +1,61 @@
+use crate::parser::query::Field;
+use crate::registry::MetaInputValue;
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::{Positioned, Value};
+use indexmap::map::IndexMap;
+use std::collections::HashSet;
+
+pub struct SecretVariables<'a, 'b> {
+    current_args: Option<&'a IndexMap<String, MetaInputValue>>,
+    variables: &'b mut HashSet<String>,
+}
+
+impl<'a, 'b> SecretVariables<'a, 'b> {
+    pub fn new(variables: &'b mut HashSet<String>) -> Self {
+        Self {
+            current_args: None,
+            variables,
+        }
+    }
+}
+
+impl<'a, 'b> Visitor<'a> for SecretVariables<'a, 'b> {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        self.current_args = ctx
+            .parent_type()
+            .and_then(|p| p.field_by_name(&field.name))
+            .map(|f| &f.args);
+    }
+
+    fn exit_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {
+        self.current_args = None;
+    }
+
+    fn enter_argument(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<String>,
+        value: &'a Positioned<Value>,
+    ) {
+        if let Some(arg) = self.current_args.and_then(|args| args.get(name.as_str())) {
+            if arg.is_secret {
+                collect_variables(&value.node, self.variables);
+            }
+        }
+    }
+}
+
+fn collect_variables(value: &Value, variables: &mut HashSet<String>) {
+    match value {
+        Value::Variable(name) => {
+            variables.insert(name.clone());
+        }
+        Value::List(items) => items
+            .iter()
+            .for_each(|item| collect_variables(item, variables)),
+        Value::Object(obj) => obj
+            .values()
+            .for_each(|item| collect_variables(item, variables)),
+        _ => {}
+    }
+}
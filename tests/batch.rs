@@ -0,0 +1,103 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_batch_request() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self, n: i32) -> i32 {
+            n
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let body = serde_json::to_vec(&serde_json::json!([
+        { "query": "{ value(n: 1) }" },
+        { "query": "query ($n: Int!) { value(n: $n) }", "variables": { "n": 2 } },
+    ]))
+    .unwrap();
+    let builder = (Some("application/json"), futures::io::Cursor::new(body))
+        .into_batch_query_builder()
+        .await
+        .unwrap();
+    match builder.execute(&schema).await {
+        BatchQueryResponse::Batch(responses) => {
+            let data = responses
+                .into_iter()
+                .map(|resp| resp.unwrap().data)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                data,
+                vec![
+                    serde_json::json!({ "value": 1 }),
+                    serde_json::json!({ "value": 2 }),
+                ]
+            );
+        }
+        BatchQueryResponse::Single(_) => panic!("expected a batch response"),
+    }
+
+    let body = serde_json::to_vec(&serde_json::json!({ "query": "{ value(n: 3) }" })).unwrap();
+    let builder = (Some("application/json"), futures::io::Cursor::new(body))
+        .into_batch_query_builder()
+        .await
+        .unwrap();
+    match builder.execute(&schema).await {
+        BatchQueryResponse::Single(resp) => {
+            assert_eq!(resp.unwrap().data, serde_json::json!({ "value": 3 }))
+        }
+        BatchQueryResponse::Batch(_) => panic!("expected a single response"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_batch_request_errors() {
+    async fn receive(
+        body: serde_json::Value,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        let body = serde_json::to_vec(&body).unwrap();
+        (Some("application/json"), futures::io::Cursor::new(body))
+            .into_batch_query_builder_opts(opts)
+            .await
+    }
+
+    let opts = IntoQueryBuilderOpts::default();
+    let err = receive(serde_json::json!({ "querry": "{ value }" }), &opts)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "Invalid request: missing field `query`");
+
+    let err = receive(
+        serde_json::json!([{ "query": "{ value }" }, { "query": 1 }]),
+        &opts,
+    )
+    .await
+    .err()
+    .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Invalid request: invalid type: integer `1`, expected a string"
+    );
+
+    let opts = IntoQueryBuilderOpts {
+        max_batch_size: Some(2),
+        ..IntoQueryBuilderOpts::default()
+    };
+    assert!(receive(
+        serde_json::json!([{ "query": "{ a }" }, { "query": "{ b }" }]),
+        &opts
+    )
+    .await
+    .is_ok());
+    assert!(matches!(
+        receive(
+            serde_json::json!([{ "query": "{ a }" }, { "query": "{ b }" }, { "query": "{ c }" }]),
+            &opts
+        )
+        .await,
+        Err(ParseRequestError::BatchTooLarge)
+    ));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "bigdecimal")]
+
+use async_graphql::*;
+use bigdecimal::BigDecimal;
+
+#[async_std::test]
+pub async fn test_bigdecimal() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn total(&self, a: BigDecimal, b: BigDecimal) -> BigDecimal {
+            a + b
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    a: total(a: "0.1", b: "0.2")
+                    b: total(a: "123456789012345678901234567890.000000000000000000001", b: 1)
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": "0.3",
+            "b": "123456789012345678901234567891.000000000000000000001",
+        })
+    );
+    assert!(schema
+        .execute(r#"{ total(a: 0.1, b: "0.2") }"#)
+        .await
+        .is_err());
+    assert!(schema
+        .execute(r#"{ total(a: "abc", b: "0.2") }"#)
+        .await
+        .is_err());
+
+    match QueryBuilder::new("query($a: BigDecimal!) { total(a: $a, b: 1) }")
+        .variables(Variables::parse_from_json(serde_json::json!({ "a": "1.2.3" })).unwrap())
+        .execute(&schema)
+        .await
+        .err()
+        .unwrap()
+    {
+        Error::Query {
+            err: QueryError::ParseInputValue { reason },
+            ..
+        } => assert!(reason.starts_with(r#"Invalid BigDecimal "1.2.3": "#)),
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "chrono")]
+
+use async_graphql::*;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+#[async_std::test]
+pub async fn test_chrono_datetime() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn utc(&self) -> DateTime<Utc> {
+            Utc.ymd(2020, 1, 31).and_hms(13, 45, 0)
+        }
+
+        async fn fixed_offset(&self, value: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ utc fixedOffset(value: "2020-01-31T13:45:00+08:00") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "utc": "2020-01-31T13:45:00+00:00",
+            "fixedOffset": "2020-01-31T13:45:00+08:00",
+        })
+    );
+    assert!(schema
+        .execute(r#"{ fixedOffset(value: "2020-01-31 13:45") }"#)
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_chrono_naive() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn date(&self, value: NaiveDate) -> NaiveDate {
+            value
+        }
+
+        async fn time(&self, value: NaiveTime) -> NaiveTime {
+            value
+        }
+
+        async fn date_time(&self, value: NaiveDateTime) -> NaiveDateTime {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    date(value: "2024-01-31")
+                    time(value: "13:45:00")
+                    dateTime(value: "2024-01-31T13:45:00")
+                    fraction: dateTime(value: "2024-01-31T13:45:00.5")
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "date": "2024-01-31",
+            "time": "13:45:00",
+            "dateTime": "2024-01-31T13:45:00",
+            "fraction": "2024-01-31T13:45:00.500",
+        })
+    );
+
+    for (query, message) in &[
+        (
+            r#"{ date(value: "2024-02-30") }"#,
+            r#"Invalid NaiveDate "2024-02-30": input is out of range"#,
+        ),
+        (
+            r#"{ time(value: "13:45") }"#,
+            r#"Invalid NaiveTime "13:45": premature end of input"#,
+        ),
+        (
+            r#"{ dateTime(value: "2024-01-31 13:45:00") }"#,
+            r#"Invalid NaiveDateTime "2024-01-31 13:45:00": input contains invalid characters"#,
+        ),
+    ] {
+        match schema.execute(query).await.err().unwrap() {
+            Error::Query {
+                err: QueryError::ParseInputValue { reason },
+                ..
+            } => assert_eq!(&reason, message),
+            err => panic!("unexpected error: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "chrono-tz")]
+
+use async_graphql::*;
+use chrono_tz::Tz;
+
+#[async_std::test]
+pub async fn test_chrono_tz() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn time_zone(&self, value: Tz) -> Tz {
+            value
+        }
+
+        async fn utc(&self) -> Tz {
+            Tz::UTC
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ timeZone(value: "America/New_York") utc }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "timeZone": "America/New_York", "utc": "UTC" })
+    );
+    assert!(schema.sdl().contains("scalar TimeZone"));
+
+    match schema
+        .execute(r#"{ timeZone(value: "Mars/Olympus_Mons") }"#)
+        .await
+        .err()
+        .unwrap()
+    {
+        Error::Query {
+            err: QueryError::ParseInputValue { reason },
+            ..
+        } => assert_eq!(reason, r#"Invalid time zone "Mars/Olympus_Mons"."#),
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
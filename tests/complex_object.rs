@@ -0,0 +1,51 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_complex_object() {
+    #[SimpleObject(complex)]
+    struct MyObj {
+        a: i32,
+        b: i32,
+    }
+
+    #[ComplexObject]
+    impl MyObj {
+        async fn sum(&self) -> i32 {
+            self.a + self.b
+        }
+
+        async fn multiply(&self, n: i32) -> i32 {
+            (self.a + self.b) * n
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 10, b: 20 }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        obj { a b sum multiply(n: 2) }
+        __type(name: "MyObj") { fields { name } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "obj": { "a": 10, "b": 20, "sum": 30, "multiply": 60 },
+            "__type": {
+                "fields": [
+                    { "name": "a" },
+                    { "name": "b" },
+                    { "name": "sum" },
+                    { "name": "multiply" },
+                ]
+            },
+        })
+    );
+    assert!(schema.execute("{ obj { c } }").await.is_err());
+}
@@ -102,3 +102,96 @@ pub async fn test_default_value_inputobject() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_default_value_inputobject_types() {
+    #[InputObject]
+    struct MyInput {
+        #[field(default = 100)]
+        value1: i64,
+
+        #[field(default = 1.5)]
+        value2: f32,
+
+        #[field(default = 7)]
+        value3: u8,
+
+        #[field(default_with = "vec![1, 2]")]
+        value4: Vec<i32>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput) -> String {
+            format!(
+                "{} {} {} {:?}",
+                input.value1, input.value2, input.value3, input.value4
+            )
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ value(input: {}) }").await.unwrap().data,
+        serde_json::json!({ "value": "100 1.5 7 [1, 2]" })
+    );
+
+    let query = r#"{ __type(name: "MyInput") { inputFields { name defaultValue } } }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "__type": {
+                "inputFields": [
+                    { "name": "value1", "defaultValue": "\"100\"" },
+                    { "name": "value2", "defaultValue": "1.5" },
+                    { "name": "value3", "defaultValue": "7" },
+                    { "name": "value4", "defaultValue": "[1, 2]" },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_default_value_arg_expression() {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    const DEFAULT_LIMIT: i32 = 20;
+    static CALLS: AtomicI32 = AtomicI32::new(0);
+
+    fn next_value() -> i32 {
+        CALLS.fetch_add(1, Ordering::SeqCst) + 100
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn limit(&self, #[arg(default_with = "DEFAULT_LIMIT")] limit: i32) -> i32 {
+            limit
+        }
+
+        async fn value(&self, #[arg(default_with = "next_value()")] value: i32) -> i32 {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let calls = CALLS.load(Ordering::SeqCst);
+
+    assert_eq!(
+        schema
+            .execute("{ limit value(value: 1) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "limit": 20, "value": 1 })
+    );
+    assert_eq!(CALLS.load(Ordering::SeqCst), calls);
+
+    let data = schema.execute("{ value }").await.unwrap().data;
+    assert_eq!(CALLS.load(Ordering::SeqCst), calls + 1);
+    assert_eq!(data, serde_json::json!({ "value": 100 + calls }));
+}
@@ -0,0 +1,99 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_doc_options() {
+    /// A user
+    ///
+    /// INTERNAL: stored in the `users` table.
+    #[SimpleObject(doc(hide_prefix = "INTERNAL:"))]
+    struct User {
+        /// The name of the user
+        /// INTERNAL: not unique.
+        name: String,
+    }
+
+    /// The role of a user
+    ///
+    /// Only in rustdoc.
+    #[Enum(doc(first_line))]
+    enum Role {
+        /// Administrator
+        /// Only in rustdoc.
+        Admin,
+    }
+
+    /// A filter
+    /// of users
+    /// only in rustdoc
+    #[InputObject(doc(max_lines = 2))]
+    struct Filter {
+        /// Role
+        /// of the user
+        /// only in rustdoc
+        role: Role,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self, _filter: Filter) -> User {
+            User {
+                name: "a".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        user: __type(name: "User") { description fields { description } }
+        role: __type(name: "Role") { description enumValues { description } }
+        filter: __type(name: "Filter") { description inputFields { description } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "user": {
+                "description": "A user",
+                "fields": [{ "description": "The name of the user" }],
+            },
+            "role": {
+                "description": "The role of a user",
+                "enumValues": [{ "description": "Administrator" }],
+            },
+            "filter": {
+                "description": "A filter\nof users",
+                "inputFields": [{ "description": "Role\nof the user" }],
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_use_type_description() {
+    /// The query root
+    ///
+    /// More details.
+    #[derive(Description)]
+    struct Query;
+
+    /// Not used
+    #[Object(use_type_description)]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Query") { description } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "description": "The query root\n\nMore details." }
+        })
+    );
+}
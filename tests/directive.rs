@@ -61,3 +61,85 @@ pub async fn test_directive_include() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_custom_directive() {
+    use async_graphql::directive::{CustomDirective, DirectiveArgs, DirectiveContext};
+
+    struct Uppercase;
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Uppercase {
+        fn name(&self) -> &'static str {
+            "uppercase"
+        }
+
+        async fn after_resolve(
+            &self,
+            _ctx: &DirectiveContext<'_>,
+            value: serde_json::Value,
+        ) -> FieldResult<serde_json::Value> {
+            Ok(match value {
+                serde_json::Value::String(s) => s.to_uppercase().into(),
+                value => value,
+            })
+        }
+    }
+
+    struct Masked;
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Masked {
+        fn name(&self) -> &'static str {
+            "masked"
+        }
+
+        fn args(&self, args: &mut DirectiveArgs<'_>) {
+            args.arg::<String>("with", None);
+        }
+
+        async fn before_resolve(
+            &self,
+            ctx: &DirectiveContext<'_>,
+        ) -> FieldResult<Option<serde_json::Value>> {
+            Ok(Some(ctx.directive_param_value::<String>("with")?.into()))
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        pub async fn value(&self) -> &str {
+            "abc"
+        }
+
+        pub async fn password(&self) -> FieldResult<&str> {
+            Err("should not be resolved".into())
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .directive(Uppercase)
+        .directive(Masked)
+        .finish();
+
+    let resp = schema
+        .execute(r#"{ value @uppercase password @masked(with: "***") @uppercase }"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "value": "ABC",
+            "password": "***",
+        })
+    );
+
+    assert!(schema.execute(r#"{ password @masked }"#).await.is_err());
+    assert!(schema
+        .execute(r#"{ password @masked(with: 1) }"#)
+        .await
+        .is_err());
+    assert!(schema.execute(r#"{ value @unknown }"#).await.is_err());
+}
@@ -0,0 +1,249 @@
+use async_graphql::dynamic::*;
+use async_graphql::{QueryBuilder, Variables};
+use futures::FutureExt;
+use std::sync::Mutex;
+
+struct Book {
+    id: i32,
+    title: String,
+}
+
+fn schema() -> Schema {
+    let book = Object::new("Book")
+        .field(Field::new("id", "Int!", |ctx| {
+            async move {
+                Ok(FieldValue::value(
+                    ctx.parent.downcast_ref::<Book>().unwrap().id,
+                ))
+            }
+            .boxed()
+        }))
+        .field(Field::new("title", "String!", |ctx| {
+            async move {
+                let book = ctx.parent.downcast_ref::<Book>().unwrap();
+                Ok(FieldValue::value(book.title.clone()))
+            }
+            .boxed()
+        }));
+
+    let query = Object::new("Query")
+        .field(Field::new("books", "[Book!]!", |ctx| {
+            async move {
+                let books = ctx.data::<Mutex<Vec<(i32, String)>>>().lock().unwrap();
+                Ok(FieldValue::list(books.iter().map(|(id, title)| {
+                    FieldValue::owned_any(Book {
+                        id: *id,
+                        title: title.clone(),
+                    })
+                })))
+            }
+            .boxed()
+        }))
+        .field(
+            Field::new("book", "Book", |ctx| {
+                async move {
+                    let id: i32 = ctx.arg("id")?;
+                    let books = ctx.data::<Mutex<Vec<(i32, String)>>>().lock().unwrap();
+                    Ok(match books.iter().find(|(book_id, _)| *book_id == id) {
+                        Some((id, title)) => FieldValue::owned_any(Book {
+                            id: *id,
+                            title: title.clone(),
+                        }),
+                        None => FieldValue::Null,
+                    })
+                }
+                .boxed()
+            })
+            .argument(InputValue::new("id", "Int!")),
+        )
+        .field(Field::new("error", "String", |_| {
+            async move { Err("my error".into()) }.boxed()
+        }));
+
+    let mutation = Object::new("Mutation").field(
+        Field::new("addBook", "Int!", |ctx| {
+            async move {
+                let title: String = ctx.arg("title")?;
+                let mut books = ctx.data::<Mutex<Vec<(i32, String)>>>().lock().unwrap();
+                let id = books.len() as i32 + 1;
+                books.push((id, title));
+                Ok(FieldValue::value(id))
+            }
+            .boxed()
+        })
+        .argument(InputValue::new("title", "String!")),
+    );
+
+    Schema::build("Query", Some("Mutation"))
+        .register(book)
+        .register(query)
+        .register(mutation)
+        .data(Mutex::new(vec![(1, "a".to_string())]))
+        .finish()
+        .unwrap()
+}
+
+#[async_std::test]
+pub async fn test_dynamic_schema() {
+    let schema = schema();
+
+    assert_eq!(
+        schema
+            .execute(r#"mutation { a: addBook(title: "b") b: addBook(title: "c") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": 2, "b": 3 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    books { __typename id ...BookTitle }
+                    book(id: 2) { ... on Book { title } }
+                    notFound: book(id: 10) { id }
+                }
+
+                fragment BookTitle on Book { title }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "books": [
+                { "__typename": "Book", "id": 1, "title": "a" },
+                { "__typename": "Book", "id": 2, "title": "b" },
+                { "__typename": "Book", "id": 3, "title": "c" },
+            ],
+            "book": { "title": "b" },
+            "notFound": null,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute_query(
+                QueryBuilder::new("query($id: Int!) { book(id: $id) { title } }")
+                    .variables(Variables::parse_from_json(serde_json::json!({ "id": 3 })).unwrap())
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "book": { "title": "c" } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_dynamic_schema_errors() {
+    let schema = schema();
+
+    assert!(schema.execute("{ books { name } }").await.is_err());
+    assert!(schema.execute("{ book { id } }").await.is_err());
+    assert!(schema.execute("{ error }").await.is_err());
+    assert!(schema
+        .execute("subscription { books { id } }")
+        .await
+        .is_err());
+
+    let err = Schema::build("Query", None)
+        .register(Object::new("Query").field(Field::new("a", "Unknown", |_| {
+            async move { Ok(FieldValue::Null) }.boxed()
+        })))
+        .finish()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "The type \"Unknown\" of the field \"Query.a\" is not defined."
+    );
+
+    let err = Schema::build("Query", None)
+        .register(Object::new("Query"))
+        .register(Object::new("String"))
+        .finish()
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "The type \"String\" is already defined.");
+
+    let err = Schema::build("Query", None).finish().err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "The root object \"Query\" is not registered."
+    );
+}
+
+#[async_std::test]
+pub async fn test_dynamic_schema_scalar_values() {
+    fn value_field(name: &str, ty: &str, value: serde_json::Value) -> Field {
+        Field::new(name, ty, move |_| {
+            let value = value.clone();
+            async move { Ok(FieldValue::value(value)) }.boxed()
+        })
+    }
+
+    let schema = Schema::build("Query", None)
+        .register(
+            Object::new("Query")
+                .field(value_field("int", "Int", serde_json::json!(10)))
+                .field(value_field("id", "ID", serde_json::json!(10)))
+                .field(value_field("stringAsInt", "Int", serde_json::json!("10")))
+                .field(value_field("bigInt", "Int", serde_json::json!(1u64 << 40)))
+                .field(value_field("intAsString", "String", serde_json::json!(10)))
+                .field(value_field(
+                    "floatAsBoolean",
+                    "Boolean",
+                    serde_json::json!(1.5),
+                )),
+        )
+        .finish()
+        .unwrap();
+
+    assert_eq!(
+        schema.execute("{ int id }").await.unwrap().data,
+        serde_json::json!({ "int": 10, "id": "10" })
+    );
+
+    for (field, ty) in &[
+        ("stringAsInt", "Int"),
+        ("bigInt", "Int"),
+        ("intAsString", "String"),
+        ("floatAsBoolean", "Boolean"),
+    ] {
+        let err = schema
+            .execute(&format!("{{ {} }}", field))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Query error: Failed to resolve field: Expected a value of type \"{}\".",
+                ty
+            )
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_dynamic_schema_introspection() {
+    let schema = schema();
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Book") { name fields { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "name": "Book",
+                "fields": [
+                    { "name": "id" },
+                    { "name": "title" },
+                ],
+            }
+        })
+    );
+    assert!(schema.sdl().contains("type Book {"));
+}
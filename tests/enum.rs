@@ -72,3 +72,97 @@ pub async fn test_enum_derive_and_item_attributes() {
         TestStruct { value: Test::Real }
     );
 }
+
+mod remote {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub enum RemoteEnum {
+        A,
+        B,
+    }
+}
+
+#[async_std::test]
+pub async fn test_remote_enum() {
+    #[Enum(remote = "remote::RemoteEnum")]
+    enum LocalEnum {
+        A,
+        B,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        async fn value(&self, input: LocalEnum) -> LocalEnum {
+            let remote: remote::RemoteEnum = input.into();
+            remote.into()
+        }
+    }
+
+    assert_eq!(
+        remote::RemoteEnum::from(LocalEnum::B),
+        remote::RemoteEnum::B
+    );
+    assert!(LocalEnum::from(remote::RemoteEnum::A) == LocalEnum::A);
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ value(input: B) }").await.unwrap().data,
+        serde_json::json!({ "value": "B" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_enum_custom_value() {
+    #[Enum]
+    enum Role {
+        #[item(value = "adm")]
+        Admin,
+        Guest,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn role(&self, role: Role) -> Role {
+            role
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ a: role(role: ADMIN) b: role(role: GUEST) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": "adm", "b": "GUEST" })
+    );
+    assert!(schema.execute("{ role(role: adm) }").await.is_err());
+
+    for value in &["adm", "ADMIN"] {
+        assert_eq!(
+            QueryBuilder::new("query($role: Role!) { role(role: $role) }")
+                .variables(
+                    Variables::parse_from_json(serde_json::json!({ "role": value })).unwrap()
+                )
+                .execute(&schema)
+                .await
+                .unwrap()
+                .data,
+            serde_json::json!({ "role": "adm" })
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Role") { enumValues { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "enumValues": [{ "name": "ADMIN" }, { "name": "GUEST" }] }
+        })
+    );
+}
@@ -0,0 +1,116 @@
+use async_graphql::*;
+
+mod shared {
+    use async_graphql::*;
+
+    #[SimpleObject]
+    pub struct User {
+        pub id: i32,
+    }
+
+    pub struct Post {
+        pub title: String,
+    }
+
+    #[Object]
+    impl Post {
+        async fn title(&self) -> &str {
+            &self.title
+        }
+    }
+}
+
+struct UserExtension {
+    id: i32,
+}
+
+#[Object]
+impl UserExtension {
+    async fn avatar_url(&self, #[arg(default = 64)] size: i32) -> String {
+        format!("https://example.com/{}.png?s={}", self.id, size)
+    }
+}
+
+impl ObjectExtension for UserExtension {
+    type Target = shared::User;
+
+    fn from_target(user: &shared::User) -> Self {
+        UserExtension { id: user.id }
+    }
+}
+
+struct PostExtension(i32);
+
+#[Object]
+impl PostExtension {
+    async fn title_length(&self) -> i32 {
+        self.0
+    }
+}
+
+impl ObjectExtension for PostExtension {
+    type Target = shared::Post;
+
+    fn from_target(post: &shared::Post) -> Self {
+        PostExtension(post.title.len() as i32)
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self) -> shared::User {
+        shared::User { id: 1 }
+    }
+
+    async fn post(&self) -> shared::Post {
+        shared::Post {
+            title: "hello".to_string(),
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_extend_type() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extend_type::<UserExtension>()
+        .extend_type::<PostExtension>()
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(
+                "{ user { id avatarUrl big: avatarUrl(size: 128) } post { title titleLength } }"
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "user": {
+                "id": 1,
+                "avatarUrl": "https://example.com/1.png?s=64",
+                "big": "https://example.com/1.png?s=128",
+            },
+            "post": { "title": "hello", "titleLength": 5 },
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "User") { fields { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "fields": [{ "name": "id" }, { "name": "avatarUrl" }] }
+        })
+    );
+    assert!(!schema.sdl().contains("UserExtension"));
+}
+
+#[async_std::test]
+pub async fn test_extend_type_not_registered() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(schema.execute("{ user { avatarUrl } }").await.is_err());
+}
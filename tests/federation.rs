@@ -87,3 +87,52 @@ pub async fn test_federation() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_federation_v2_directives() {
+    #[SimpleObject(shareable, tag = "public")]
+    struct Price {
+        #[field(inaccessible)]
+        currency: String,
+        #[field(override_from = "legacy", tag = "amount", tag = "money")]
+        amount: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn price(&self) -> Price {
+            Price {
+                currency: "USD".to_string(),
+                amount: 10,
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation_v2()
+        .finish();
+    let sdl = schema.execute("{ _service { sdl } }").await.unwrap().data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    assert!(sdl.starts_with(
+        r#"extend schema @link(url: "https://specs.apollo.dev/federation/v2.0", import: ["@key", "@requires", "@provides", "@external", "@shareable", "@override", "@inaccessible", "@tag"])"#
+    ));
+    assert!(sdl.contains(r#"type Price @shareable @tag(name: "public") {"#));
+    assert!(sdl.contains("currency: String! @inaccessible"));
+    assert!(sdl.contains(
+        r#"amount: Int! @override(from: "legacy") @tag(name: "amount") @tag(name: "money")"#
+    ));
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+    let sdl = schema.execute("{ _service { sdl } }").await.unwrap().data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(!sdl.contains("@link"));
+}
@@ -0,0 +1,62 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_flatten() {
+    #[SimpleObject]
+    #[derive(Clone)]
+    struct Timestamps {
+        created_at: i32,
+        updated_at: i32,
+    }
+
+    #[SimpleObject]
+    struct User {
+        name: String,
+        #[field(flatten)]
+        timestamps: Timestamps,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            User {
+                name: "alice".to_string(),
+                timestamps: Timestamps {
+                    created_at: 1,
+                    updated_at: 2,
+                },
+            }
+        }
+
+        async fn timestamps(&self) -> Timestamps {
+            Timestamps {
+                created_at: 3,
+                updated_at: 4,
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        user { name createdAt updatedAt }
+        timestamps { createdAt }
+        __type(name: "User") { fields { name } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "user": { "name": "alice", "createdAt": 1, "updatedAt": 2 },
+            "timestamps": { "createdAt": 3 },
+            "__type": {
+                "fields": [
+                    { "name": "name" },
+                    { "name": "createdAt" },
+                    { "name": "updatedAt" },
+                ]
+            },
+        })
+    );
+    assert!(schema.execute("{ user { timestamps } }").await.is_err());
+}
@@ -0,0 +1,231 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_generic_simple_object() {
+    #[SimpleObject]
+    #[derive(Clone)]
+    struct User {
+        name: String,
+    }
+
+    #[SimpleObject]
+    #[derive(Clone)]
+    struct Post {
+        title: String,
+    }
+
+    #[SimpleObject(
+        concrete(name = "UserEdge", params(User)),
+        concrete(name = "PostEdge", params(Post))
+    )]
+    struct Edge<T: OutputValueType + Clone + Send + Sync + 'static> {
+        cursor: String,
+        node: T,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> Edge<User> {
+            Edge {
+                cursor: "1".to_string(),
+                node: User {
+                    name: "alice".to_string(),
+                },
+            }
+        }
+
+        async fn post(&self) -> Edge<Post> {
+            Edge {
+                cursor: "2".to_string(),
+                node: Post {
+                    title: "hello".to_string(),
+                },
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        user { __typename cursor node { name } }
+        post { __typename cursor node { title } }
+        userEdge: __type(name: "UserEdge") { fields { name } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "user": {
+                "__typename": "UserEdge",
+                "cursor": "1",
+                "node": { "name": "alice" },
+            },
+            "post": {
+                "__typename": "PostEdge",
+                "cursor": "2",
+                "node": { "title": "hello" },
+            },
+            "userEdge": {
+                "fields": [{ "name": "cursor" }, { "name": "node" }],
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_generic_object() {
+    struct Wrapper<T>(T);
+
+    #[Object(
+        concrete(name = "IntWrapper", params(i32)),
+        concrete(name = "StringWrapper", params(String))
+    )]
+    impl<T: OutputValueType + Send + Sync + 'static> Wrapper<T> {
+        async fn value(&self) -> &T {
+            &self.0
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn int(&self) -> Wrapper<i32> {
+            Wrapper(10)
+        }
+
+        async fn string(&self) -> Wrapper<String> {
+            Wrapper("abc".to_string())
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{ int { __typename value } string { __typename value } }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "int": { "__typename": "IntWrapper", "value": 10 },
+            "string": { "__typename": "StringWrapper", "value": "abc" },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_generic_union() {
+    struct Wrapper<T>(T);
+
+    #[Object(
+        concrete(name = "IntWrapper", params(i32)),
+        concrete(name = "StringWrapper", params(String))
+    )]
+    impl<T: OutputValueType + Send + Sync + 'static> Wrapper<T> {
+        async fn value(&self) -> &T {
+            &self.0
+        }
+    }
+
+    #[SimpleObject]
+    struct Missing {
+        reason: String,
+    }
+
+    #[Union(
+        concrete(name = "IntResult", params(i32)),
+        concrete(name = "StringResult", params(String))
+    )]
+    enum WrapperResult<T: OutputValueType + Send + Sync + 'static> {
+        Wrapper(Wrapper<T>),
+        Missing(Missing),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn int(&self) -> WrapperResult<i32> {
+            Wrapper(10).into()
+        }
+
+        async fn string(&self) -> WrapperResult<String> {
+            Missing {
+                reason: "not found".to_string(),
+            }
+            .into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        int { __typename ... on IntWrapper { value } }
+        string { __typename ... on Missing { reason } }
+        stringResult: __type(name: "StringResult") { kind possibleTypes { name } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "int": { "__typename": "IntWrapper", "value": 10 },
+            "string": { "__typename": "Missing", "reason": "not found" },
+            "stringResult": {
+                "kind": "UNION",
+                "possibleTypes": [{ "name": "StringWrapper" }, { "name": "Missing" }],
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_generic_interface() {
+    struct Wrapper<T>(T);
+
+    #[Object(
+        concrete(name = "IntWrapper", params(i32)),
+        concrete(name = "StringWrapper", params(String))
+    )]
+    impl<T: OutputValueType + Send + Sync + 'static> Wrapper<T> {
+        async fn value(&self) -> &T {
+            &self.0
+        }
+    }
+
+    #[Interface(
+        concrete(name = "IntValue", params(i32)),
+        concrete(name = "StringValue", params(String)),
+        field(name = "value", type = "&T")
+    )]
+    enum Value<T: OutputValueType + Send + Sync + 'static> {
+        Wrapper(Wrapper<T>),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn int(&self) -> Value<i32> {
+            Wrapper(10).into()
+        }
+
+        async fn string(&self) -> Value<String> {
+            Wrapper("abc".to_string()).into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        int { __typename value }
+        string { __typename ... on StringValue { value } }
+        intWrapper: __type(name: "IntWrapper") { interfaces { name } }
+        stringValue: __type(name: "StringValue") { kind possibleTypes { name } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "int": { "__typename": "IntWrapper", "value": 10 },
+            "string": { "__typename": "StringWrapper", "value": "abc" },
+            "intWrapper": { "interfaces": [{ "name": "IntValue" }] },
+            "stringValue": {
+                "kind": "INTERFACE",
+                "possibleTypes": [{ "name": "StringWrapper" }],
+            },
+        })
+    );
+}
@@ -0,0 +1,150 @@
+use async_graphql::http::{GQLBatchResponse, GQLResponse};
+use async_graphql::*;
+use bytes::Bytes;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[field(cache_control(max_age = 60))]
+    async fn value(&self, n: i32) -> i32 {
+        n
+    }
+}
+
+fn body(data: &str) -> impl futures::Stream<Item = std::io::Result<Bytes>> + Unpin {
+    futures::stream::iter(vec![Ok(Bytes::from(data.to_string()))])
+}
+
+#[async_std::test]
+pub async fn test_http_request() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body(body(r#"{"query": "{ value(n: 10) }"}"#))
+        .unwrap();
+    let builder = req.into_query_builder().await.unwrap();
+    let resp: ::http::Response<String> = GQLResponse(builder.execute(&schema).await).into();
+    assert_eq!(resp.headers()["content-type"], "application/json");
+    assert_eq!(resp.headers()["cache-control"], "max-age=60");
+    assert_eq!(resp.body(), r#"{"data":{"value":10}}"#);
+
+    let req = ::http::Request::get("/?query=%7B%20value(n%3A%2020)%20%7D")
+        .body(body(""))
+        .unwrap();
+    let builder = req.into_query_builder().await.unwrap();
+    let resp: ::http::Response<String> = GQLResponse(builder.execute(&schema).await).into();
+    assert_eq!(resp.body(), r#"{"data":{"value":20}}"#);
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body(body(
+            r#"[{"query": "{ value(n: 1) }"}, {"query": "{ value(n: 2) }"}]"#,
+        ))
+        .unwrap();
+    let builder = req.into_batch_query_builder().await.unwrap();
+    let resp: ::http::Response<String> = GQLBatchResponse(builder.execute(&schema).await).into();
+    assert_eq!(
+        resp.body(),
+        r#"[{"data":{"value":1}},{"data":{"value":2}}]"#
+    );
+}
+
+#[async_std::test]
+pub async fn test_receive_request() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let builder = http::receive_request(
+        Some("application/json"),
+        http::StreamBody::new(body(r#"{"query": "{ value(n: 10) }"}"#)),
+        &Default::default(),
+    )
+    .await
+    .unwrap();
+    let resp = builder.execute(&schema).await.unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 10 }));
+
+    let builder = http::parse_query_string("query=%7B%20value(n%3A%2020)%20%7D")
+        .unwrap()
+        .into_query_builder()
+        .await
+        .unwrap();
+    let resp = builder.execute(&schema).await.unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 20 }));
+
+    assert!(http::parse_query_string("").is_err());
+}
+
+#[cfg(feature = "cbor")]
+#[async_std::test]
+pub async fn test_receive_cbor_request() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let data = serde_cbor::to_vec(&serde_json::json!({
+        "query": "query($n: Int!) { value(n: $n) }",
+        "variables": { "n": 10 },
+    }))
+    .unwrap();
+    let builder = http::receive_request(
+        Some("application/cbor"),
+        futures::io::Cursor::new(data),
+        &Default::default(),
+    )
+    .await
+    .unwrap();
+    let resp = builder.execute(&schema).await.unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 10 }));
+
+    let data = serde_cbor::to_vec(&serde_json::json!([
+        { "query": "{ value(n: 1) }" },
+        { "query": "{ value(n: 2) }" },
+    ]))
+    .unwrap();
+    let builder = http::receive_batch_request(
+        Some("application/cbor"),
+        futures::io::Cursor::new(data),
+        &Default::default(),
+    )
+    .await
+    .unwrap();
+    assert!(matches!(builder, BatchQueryBuilder::Batch(_)));
+
+    assert!(matches!(
+        http::receive_request(
+            Some("application/cbor"),
+            futures::io::Cursor::new(b"{}".to_vec()),
+            &Default::default(),
+        )
+        .await,
+        Err(ParseRequestError::InvalidCborRequest(_))
+    ));
+}
+
+#[async_std::test]
+pub async fn test_execute_http_request() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body(Bytes::from(r#"{"query": "{ value(n: 10) }"}"#))
+        .unwrap();
+    let resp = http::execute_http_request(&schema, req).await;
+    assert_eq!(resp.status(), ::http::StatusCode::OK);
+    assert_eq!(resp.headers()["content-type"], "application/json");
+    assert_eq!(resp.body(), r#"{"data":{"value":10}}"#);
+
+    let req = ::http::Request::get("/?query=%7B%20value(n%3A%2020)%20%7D")
+        .body(Vec::new())
+        .unwrap();
+    let resp = http::execute_http_request(&schema, req).await;
+    assert_eq!(resp.body(), r#"{"data":{"value":20}}"#);
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body("not json")
+        .unwrap();
+    let resp = http::execute_http_request(&schema, req).await;
+    assert_eq!(resp.status(), ::http::StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()["cache-control"], "no-store");
+}
@@ -105,3 +105,103 @@ pub async fn test_inputobject_derive_and_item_attributes() {
         MyInputObject { real: 100 }
     );
 }
+
+#[async_std::test]
+pub async fn test_both_input_and_output() {
+    /// A point
+    #[SimpleObject(input_name = "PointInput")]
+    #[derive(Clone)]
+    struct Point {
+        x: i32,
+        #[field(name = "posY")]
+        y: i32,
+        tags: Option<Vec<String>>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn mirror(&self, point: Point, points: Option<Vec<Point>>) -> Vec<Point> {
+            let mut res = vec![Point {
+                x: point.y,
+                y: point.x,
+                tags: point.tags,
+            }];
+            res.extend(points.unwrap_or_default());
+            res
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    mirror(point: {x: 1, posY: 2, tags: ["a"]}, points: [{x: 3, posY: 4}]) {
+                        x posY tags
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "mirror": [
+                { "x": 2, "posY": 1, "tags": ["a"] },
+                { "x": 3, "posY": 4, "tags": null },
+            ]
+        })
+    );
+
+    assert_eq!(
+        QueryBuilder::new("query($p: PointInput!) { mirror(point: $p) { x } }")
+            .variables(
+                Variables::parse_from_json(serde_json::json!({ "p": { "x": 5, "posY": 6 } }))
+                    .unwrap()
+            )
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "mirror": [{ "x": 6 }] })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    output: __type(name: "Point") { kind description }
+                    input: __type(name: "PointInput") {
+                        kind description inputFields { name type { kind ofType { name } } }
+                    }
+                    query: __type(name: "Query") {
+                        fields { args { name type { kind name ofType { name } } } }
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "output": { "kind": "OBJECT", "description": "A point" },
+            "input": {
+                "kind": "INPUT_OBJECT",
+                "description": "A point",
+                "inputFields": [
+                    { "name": "x", "type": { "kind": "NON_NULL", "ofType": { "name": "Int" } } },
+                    { "name": "posY", "type": { "kind": "NON_NULL", "ofType": { "name": "Int" } } },
+                    { "name": "tags", "type": { "kind": "LIST", "ofType": { "name": null } } },
+                ]
+            },
+            "query": {
+                "fields": [{
+                    "args": [
+                        { "name": "point", "type": { "kind": "NON_NULL", "name": null, "ofType": { "name": "PointInput" } } },
+                        { "name": "points", "type": { "kind": "LIST", "name": null, "ofType": { "name": null } } },
+                    ]
+                }]
+            },
+        })
+    );
+}
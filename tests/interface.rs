@@ -324,3 +324,520 @@ pub async fn test_interface_field_method() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_interface_implements_interface() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn id(&self) -> i32 {
+            1
+        }
+
+        async fn url(&self) -> &str {
+            "abc"
+        }
+    }
+
+    #[Interface(field(name = "id", type = "i32"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    #[Interface(
+        implements = "Node",
+        field(name = "id", type = "i32"),
+        field(name = "url", type = "&str")
+    )]
+    enum Resource {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn resource(&self) -> Resource {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        resource {
+            url
+            ... on Node { id }
+        }
+        node: __type(name: "Node") { possibleTypes { name } }
+        resourceType: __type(name: "Resource") { interfaces { name } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "resource": {
+                "url": "abc",
+                "id": 1,
+            },
+            "node": { "possibleTypes": [{ "name": "MyObj" }] },
+            "resourceType": { "interfaces": [{ "name": "Node" }] },
+        })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+    let sdl = schema.execute("{ _service { sdl } }").await.unwrap().data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(sdl.contains("interface Resource implements Node {"));
+}
+
+#[test]
+#[should_panic(
+    expected = "Interface \"Resource\" implements \"Node\", but does not define the field \"id\"."
+)]
+pub fn test_interface_implements_missing_field() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn id(&self) -> i32 {
+            1
+        }
+    }
+
+    #[Interface(field(name = "id", type = "i32"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    #[Interface(implements = "Node")]
+    enum Resource {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn resource(&self) -> Resource {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "Type \"MyObj\" implements \"Resource\", so it must also implement \"Node\"."
+)]
+pub fn test_interface_implements_missing_possible_type() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn id(&self) -> i32 {
+            1
+        }
+    }
+
+    #[Interface(field(name = "id", type = "i32"))]
+    enum Node {
+        #[allow(dead_code)]
+        Other(MyObj2),
+    }
+
+    struct MyObj2;
+
+    #[Object]
+    impl MyObj2 {
+        async fn id(&self) -> i32 {
+            2
+        }
+    }
+
+    #[Interface(implements = "Node", field(name = "id", type = "i32"))]
+    enum Resource {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn resource(&self) -> Resource {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "Interface \"Resource\" implements \"Node\", so it must also implement \"Entity\"."
+)]
+pub fn test_interface_implements_transitive() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn id(&self) -> i32 {
+            1
+        }
+    }
+
+    #[Interface(field(name = "id", type = "i32"))]
+    enum Entity {
+        MyObj(MyObj),
+    }
+
+    #[Interface(implements = "Entity", field(name = "id", type = "i32"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    #[Interface(implements = "Node", field(name = "id", type = "i32"))]
+    enum Resource {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn resource(&self) -> Resource {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_interface_field_arguments() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn add(&self, a: i32, b: i32, c: Option<i32>) -> i32 {
+            a + b + c.unwrap_or_default()
+        }
+    }
+
+    impl MyObj {
+        async fn add_two(&self, _ctx: &Context<'_>, a: i32, b: i32) -> FieldResult<i32> {
+            Ok(a + b)
+        }
+    }
+
+    #[Interface(field(
+        name = "add",
+        method = "add_two",
+        type = "i32",
+        arg(name = "a", type = "i32"),
+        arg(name = "b", type = "i32")
+    ))]
+    enum Calc {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn calc(&self) -> Calc {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ calc { add(a: 1, b: 2) ... on MyObj { c: add(a: 1, b: 2, c: 3) } } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "calc": { "add": 3, "c": 6 } })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Field \"MyObj.add\" does not define the argument \"b\" of the interface \"Calc\"."
+)]
+pub fn test_interface_field_missing_argument() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn add(&self, a: i32, c: i32) -> i32 {
+            a + c
+        }
+    }
+
+    #[Interface(field(
+        name = "add",
+        type = "i32",
+        arg(name = "a", type = "i32"),
+        arg(name = "b", type = "i32")
+    ))]
+    enum Calc {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn calc(&self) -> Calc {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "Field \"MyObj.add\" has the argument \"b\" of type \"String!\", but the interface \"Calc\" defines it as \"Int!\"."
+)]
+pub fn test_interface_field_argument_type() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn add(&self, a: i32, b: String) -> i32 {
+            a + b.len() as i32
+        }
+    }
+
+    impl MyObj {
+        async fn add_two(&self, _ctx: &Context<'_>, a: i32, b: i32) -> FieldResult<i32> {
+            Ok(a + b)
+        }
+    }
+
+    #[Interface(field(
+        name = "add",
+        method = "add_two",
+        type = "i32",
+        arg(name = "a", type = "i32"),
+        arg(name = "b", type = "i32")
+    ))]
+    enum Calc {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn calc(&self) -> Calc {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "Field \"MyObj.add\" has the required argument \"c\", which is not defined by the interface \"Calc\"."
+)]
+pub fn test_interface_field_extra_required_argument() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn add(&self, a: i32, c: i32) -> i32 {
+            a + c
+        }
+    }
+
+    impl MyObj {
+        async fn add_one(&self, _ctx: &Context<'_>, a: i32) -> FieldResult<i32> {
+            Ok(a + 1)
+        }
+    }
+
+    #[Interface(field(
+        name = "add",
+        method = "add_one",
+        type = "i32",
+        arg(name = "a", type = "i32")
+    ))]
+    enum Calc {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn calc(&self) -> Calc {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_interface_field_default_impl() {
+    #[SimpleObject]
+    struct User {
+        name: String,
+    }
+
+    struct Robot;
+
+    #[Object]
+    impl Robot {
+        async fn name(&self) -> String {
+            "robot".to_string()
+        }
+
+        async fn greeting(&self) -> String {
+            "beep".to_string()
+        }
+    }
+
+    #[Interface(
+        field(name = "name", type = "String"),
+        field(name = "greeting", type = "String", default_impl)
+    )]
+    enum Named {
+        User(User),
+        Robot(Robot),
+    }
+
+    impl Named {
+        async fn greeting(&self, ctx: &Context<'_>) -> FieldResult<String> {
+            Ok(format!("hello {}", self.name(ctx).await?))
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn named(&self) -> Vec<Named> {
+            vec![
+                User {
+                    name: "alice".to_string(),
+                }
+                .into(),
+                Robot.into(),
+            ]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ named { name greeting } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "named": [
+                { "name": "alice", "greeting": "hello alice" },
+                { "name": "robot", "greeting": "beep" },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_interface_trait_object() {
+    trait Animal: DynObjectType {}
+
+    #[Interface(field(name = "name", type = "String"))]
+    enum AnimalInterface {}
+
+    dyn_interface!(Animal, AnimalInterface);
+
+    struct Dog;
+
+    #[Object]
+    impl Dog {
+        async fn name(&self) -> String {
+            "dog".to_string()
+        }
+
+        async fn bark(&self) -> bool {
+            true
+        }
+    }
+
+    impl Animal for Dog {}
+
+    struct Cat;
+
+    #[Object]
+    impl Cat {
+        async fn name(&self) -> String {
+            "cat".to_string()
+        }
+
+        async fn lives(&self) -> i32 {
+            9
+        }
+    }
+
+    impl Animal for Cat {}
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn animals(&self) -> Vec<Box<dyn Animal>> {
+            vec![Box::new(Dog) as Box<dyn Animal>, Box::new(Cat)]
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_implementor::<dyn Animal, Dog>()
+        .register_implementor::<dyn Animal, Cat>()
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    animals {
+                        __typename
+                        name
+                        ... on Dog { bark }
+                        ... on Cat { lives }
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "animals": [
+                { "__typename": "Dog", "name": "dog", "bark": true },
+                { "__typename": "Cat", "name": "cat", "lives": 9 },
+            ]
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "AnimalInterface") { possibleTypes { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "possibleTypes": [{ "name": "Dog" }, { "name": "Cat" }]
+            }
+        })
+    );
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "uuid")]
+
+use async_graphql::*;
+use futures::{FutureExt, Stream};
+use std::time::Duration;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {}
+
+struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    async fn values(&self, start: i32, end: i32) -> impl Stream<Item = i32> {
+        futures::stream::iter(start..end)
+    }
+
+    async fn events(&self) -> impl Stream<Item = i32> {
+        SimpleBroker::<i32>::subscribe()
+    }
+}
+
+#[async_std::test]
+pub async fn test_long_poll() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let subscriptions = LongPollSubscriptions::new(Duration::from_secs(60));
+
+    let id = subscriptions
+        .subscribe(
+            &schema,
+            QueryBuilder::new("subscription { values(start: 10, end: 13) }"),
+        )
+        .await
+        .unwrap();
+    let resp = subscriptions
+        .poll(&id, Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.events,
+        vec![
+            serde_json::json!({ "data": { "values": 10 } }),
+            serde_json::json!({ "data": { "values": 11 } }),
+            serde_json::json!({ "data": { "values": 12 } }),
+        ]
+    );
+    assert!(resp.complete);
+    assert!(subscriptions
+        .poll(&id, Duration::from_secs(1))
+        .await
+        .is_none());
+}
+
+#[async_std::test]
+pub async fn test_long_poll_wait() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let subscriptions = LongPollSubscriptions::new(Duration::from_secs(60));
+
+    let id = subscriptions
+        .subscribe(&schema, QueryBuilder::new("subscription { events }"))
+        .await
+        .unwrap();
+
+    let resp = subscriptions
+        .poll(&id, Duration::from_millis(50))
+        .await
+        .unwrap();
+    assert!(resp.events.is_empty());
+    assert!(!resp.complete);
+
+    SimpleBroker::publish(1i32);
+    SimpleBroker::publish(2i32);
+    let resp = subscriptions
+        .poll(&id, Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.events,
+        vec![
+            serde_json::json!({ "data": { "events": 1 } }),
+            serde_json::json!({ "data": { "events": 2 } }),
+        ]
+    );
+    assert!(!resp.complete);
+
+    subscriptions.unsubscribe(&id);
+    assert!(subscriptions
+        .poll(&id, Duration::from_millis(50))
+        .await
+        .is_none());
+}
+
+#[async_std::test]
+pub async fn test_long_poll_ttl() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let subscriptions = LongPollSubscriptions::new(Duration::from_millis(50));
+
+    let id = subscriptions
+        .subscribe(
+            &schema,
+            QueryBuilder::new("subscription { values(start: 0, end: 1) }"),
+        )
+        .await
+        .unwrap();
+    async_std::task::sleep(Duration::from_millis(100)).await;
+    assert!(subscriptions
+        .poll(&id, Duration::from_millis(50))
+        .await
+        .is_none());
+}
+
+#[async_std::test]
+pub async fn test_long_poll_dropped() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let subscriptions = LongPollSubscriptions::new(Duration::from_secs(60));
+
+    let id = subscriptions
+        .subscribe(&schema, QueryBuilder::new("subscription { events }"))
+        .await
+        .unwrap();
+
+    // The client disconnects while the poll is waiting.
+    assert!(subscriptions
+        .poll(&id, Duration::from_secs(1))
+        .now_or_never()
+        .is_none());
+
+    let resp = subscriptions
+        .poll(&id, Duration::from_millis(50))
+        .await
+        .unwrap();
+    assert!(resp.events.is_empty());
+    assert!(!resp.complete);
+}
+
+#[async_std::test]
+pub async fn test_long_poll_unsubscribe_while_polling() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let subscriptions = LongPollSubscriptions::new(Duration::from_secs(60));
+
+    let id = subscriptions
+        .subscribe(&schema, QueryBuilder::new("subscription { events }"))
+        .await
+        .unwrap();
+
+    let (resp, _) =
+        futures::future::join(subscriptions.poll(&id, Duration::from_millis(200)), async {
+            async_std::task::sleep(Duration::from_millis(50)).await;
+            subscriptions.unsubscribe(&id);
+        })
+        .await;
+    assert!(resp.unwrap().events.is_empty());
+    assert!(subscriptions
+        .poll(&id, Duration::from_millis(50))
+        .await
+        .is_none());
+}
@@ -0,0 +1,158 @@
+use async_graphql::*;
+
+#[SimpleObject]
+struct User {
+    name: String,
+}
+
+#[derive(Default)]
+struct UserQuery;
+
+#[Object(cache_control(max_age = 60))]
+impl UserQuery {
+    async fn user(&self) -> User {
+        User {
+            name: "alice".to_string(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct PostQuery;
+
+#[Object(cache_control(max_age = 30))]
+impl PostQuery {
+    async fn post(&self) -> &str {
+        "hello"
+    }
+}
+
+#[MergedObject(name = "Query")]
+#[derive(Default)]
+struct QueryRoot(UserQuery, PostQuery);
+
+#[async_std::test]
+pub async fn test_merged_object() {
+    let schema = Schema::new(QueryRoot::default(), EmptyMutation, EmptySubscription);
+
+    let resp = schema
+        .execute("{ __typename user { name } post }")
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "__typename": "Query",
+            "user": { "name": "alice" },
+            "post": "hello",
+        })
+    );
+    assert_eq!(
+        resp.cache_control,
+        CacheControl {
+            public: true,
+            max_age: 30
+        }
+    );
+
+    let resp = schema
+        .execute(r#"{ __type(name: "Query") { fields { name } } userQuery: __type(name: "UserQuery") { name } }"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "__type": { "fields": [{ "name": "user" }, { "name": "post" }] },
+            "userQuery": null,
+        })
+    );
+
+    assert!(schema.execute("{ comment }").await.is_err());
+}
+
+#[async_std::test]
+#[should_panic(
+    expected = "Field \"post\" of the merged object \"Query\" is defined by more than one object."
+)]
+pub async fn test_merged_object_conflict() {
+    #[MergedObject(name = "Query")]
+    struct QueryRoot(PostQuery, PostQuery);
+
+    Schema::new(
+        QueryRoot(PostQuery, PostQuery),
+        EmptyMutation,
+        EmptySubscription,
+    );
+}
+
+#[derive(Default)]
+struct UserSubscription;
+
+#[Subscription]
+impl UserSubscription {
+    async fn users(&self) -> impl futures::Stream<Item = i32> {
+        futures::stream::iter(0..2)
+    }
+}
+
+#[derive(Default)]
+struct PostSubscription;
+
+#[Subscription]
+impl PostSubscription {
+    async fn posts(&self) -> impl futures::Stream<Item = i32> {
+        futures::stream::iter(10..12)
+    }
+}
+
+#[MergedSubscription(name = "Subscription")]
+#[derive(Default)]
+struct SubscriptionRoot(UserSubscription, PostSubscription);
+
+#[async_std::test]
+pub async fn test_merged_subscription() {
+    use futures::StreamExt;
+
+    let schema = Schema::new(
+        QueryRoot::default(),
+        EmptyMutation,
+        SubscriptionRoot::default(),
+    );
+
+    let stream = schema
+        .create_subscription_stream("subscription { users }", None, Default::default(), None)
+        .await
+        .unwrap();
+    let values = stream.map(|res| res.unwrap()).collect::<Vec<_>>().await;
+    assert_eq!(
+        values,
+        vec![
+            serde_json::json!({ "users": 0 }),
+            serde_json::json!({ "users": 1 }),
+        ]
+    );
+
+    let stream = schema
+        .create_subscription_stream("subscription { posts }", None, Default::default(), None)
+        .await
+        .unwrap();
+    let values = stream.map(|res| res.unwrap()).collect::<Vec<_>>().await;
+    assert_eq!(
+        values,
+        vec![
+            serde_json::json!({ "posts": 10 }),
+            serde_json::json!({ "posts": 11 }),
+        ]
+    );
+
+    let resp = schema
+        .execute(r#"{ __type(name: "Subscription") { fields { name } } }"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "__type": { "fields": [{ "name": "users" }, { "name": "posts" }] },
+        })
+    );
+}
@@ -0,0 +1,76 @@
+use async_graphql::*;
+
+#[derive(NewType, Debug, PartialEq)]
+struct Weight(f64);
+
+/// A positive number
+#[derive(NewType)]
+#[graphql(name = "Positive", validator = "check_positive")]
+struct Positive(i32);
+
+fn check_positive(n: &i32) -> std::result::Result<(), String> {
+    if *n > 0 {
+        Ok(())
+    } else {
+        Err(format!("{} is not positive", n))
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn weight(&self, w: Weight) -> Weight {
+        Weight(w.0 * 2.0)
+    }
+
+    async fn positive(&self, n: Positive) -> Positive {
+        Positive(n.0 + 1)
+    }
+}
+
+#[async_std::test]
+pub async fn test_newtype() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ weight(w: 1.5) positive(n: 2) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "weight": 3.0, "positive": 3 })
+    );
+
+    assert!(schema.execute("{ positive(n: 0) }").await.is_err());
+    assert!(schema.execute(r#"{ positive(n: "a") }"#).await.is_err());
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    __type(name: "Positive") { kind description }
+                    query: __type(name: "Query") {
+                        fields { type { ofType { name } } args { type { ofType { name } } } }
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "kind": "SCALAR", "description": "A positive number" },
+            "query": {
+                "fields": [
+                    {
+                        "type": { "ofType": { "name": "Float" } },
+                        "args": [{ "type": { "ofType": { "name": "Float" } } }],
+                    },
+                    {
+                        "type": { "ofType": { "name": "Positive" } },
+                        "args": [{ "type": { "ofType": { "name": "Positive" } } }],
+                    },
+                ]
+            },
+        })
+    );
+}
@@ -0,0 +1,119 @@
+use async_graphql::*;
+
+#[OneofObject]
+enum MyFilter {
+    Id(i32),
+    Name(String),
+    #[field(name = "name_prefix")]
+    Prefix(String),
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn find(&self, filter: MyFilter) -> String {
+        match filter {
+            MyFilter::Id(id) => format!("id:{}", id),
+            MyFilter::Name(name) => format!("name:{}", name),
+            MyFilter::Prefix(prefix) => format!("prefix:{}", prefix),
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_oneof_object() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    a: find(filter: {id: 10})
+                    b: find(filter: {name: "abc"})
+                    c: find(filter: {name_prefix: "ab"})
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": "id:10",
+            "b": "name:abc",
+            "c": "prefix:ab",
+        })
+    );
+
+    assert_eq!(
+        QueryBuilder::new("query($filter: MyFilter!) { find(filter: $filter) }")
+            .variables(
+                Variables::parse_from_json(serde_json::json!({
+                    "filter": { "name": "abc" }
+                }))
+                .unwrap()
+            )
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "find": "name:abc" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_oneof_object_invalid() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert!(schema.execute("{ find(filter: {}) }").await.is_err());
+    assert!(schema
+        .execute(r#"{ find(filter: {id: 10, name: "abc"}) }"#)
+        .await
+        .is_err());
+    assert!(schema
+        .execute("{ find(filter: {id: null}) }")
+        .await
+        .is_err());
+
+    assert!(
+        QueryBuilder::new("query($filter: MyFilter!) { find(filter: $filter) }")
+            .variables(
+                Variables::parse_from_json(serde_json::json!({
+                    "filter": { "id": 10, "name": "abc" }
+                }))
+                .unwrap()
+            )
+            .execute(&schema)
+            .await
+            .is_err()
+    );
+}
+
+#[async_std::test]
+pub async fn test_oneof_object_introspection() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    __type(name: "MyFilter") {
+                        isOneOf
+                        inputFields { name type { kind name } }
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "isOneOf": true,
+                "inputFields": [
+                    { "name": "id", "type": { "kind": "SCALAR", "name": "Int" } },
+                    { "name": "name", "type": { "kind": "SCALAR", "name": "String" } },
+                    { "name": "name_prefix", "type": { "kind": "SCALAR", "name": "String" } },
+                ],
+            }
+        })
+    );
+}
@@ -0,0 +1,108 @@
+use async_graphql::page::Page;
+use async_graphql::*;
+
+#[SimpleObject]
+struct Item {
+    value: i32,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn items(&self, offset: Option<i32>, limit: Option<i32>) -> FieldResult<Page<Item>> {
+        page::query(offset, limit, 5, |offset, limit| async move {
+            let items = (offset..(offset + limit).min(12))
+                .map(|n| Item { value: n as i32 })
+                .collect();
+            Ok(Page::new(items, offset, 12))
+        })
+        .await
+    }
+}
+
+#[async_std::test]
+pub async fn test_page() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("{ items { items { value } totalCount pageInfo { hasNext hasPrev } } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "items": {
+                "items": [
+                    { "value": 0 },
+                    { "value": 1 },
+                    { "value": 2 },
+                    { "value": 3 },
+                    { "value": 4 },
+                ],
+                "totalCount": 12,
+                "pageInfo": { "hasNext": true, "hasPrev": false },
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                "{ items(offset: 5, limit: 2) { items { value } pageInfo { hasNext hasPrev } } }"
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "items": {
+                "items": [{ "value": 5 }, { "value": 6 }],
+                "pageInfo": { "hasNext": true, "hasPrev": true },
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ items(offset: 10) { items { value } pageInfo { hasNext } } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "items": {
+                "items": [{ "value": 10 }, { "value": 11 }],
+                "pageInfo": { "hasNext": false },
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "ItemPage") { fields { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "fields": [
+                    { "name": "items" },
+                    { "name": "totalCount" },
+                    { "name": "pageInfo" },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_page_invalid_args() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    for query in &[
+        "{ items(offset: -1) { totalCount } }",
+        "{ items(limit: -1) { totalCount } }",
+        "{ items(limit: 6) { totalCount } }",
+    ] {
+        assert!(schema.execute(query).await.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_recursive_object() {
+    #[SimpleObject]
+    struct Comment {
+        text: String,
+        #[field(ref)]
+        replies: Vec<Comment>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn comment(&self) -> Comment {
+            Comment {
+                text: "a".to_string(),
+                replies: vec![Comment {
+                    text: "b".to_string(),
+                    replies: vec![Comment {
+                        text: "c".to_string(),
+                        replies: Vec::new(),
+                    }],
+                }],
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{ comment { text replies { text replies { text replies { text } } } } }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "comment": {
+                "text": "a",
+                "replies": [{
+                    "text": "b",
+                    "replies": [{ "text": "c", "replies": [] }],
+                }],
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_mutually_recursive_objects() {
+    struct Author(i32);
+
+    #[Object]
+    impl Author {
+        async fn id(&self) -> i32 {
+            self.0
+        }
+
+        async fn books(&self) -> Vec<Book> {
+            vec![Book(self.0 * 10)]
+        }
+    }
+
+    struct Book(i32);
+
+    #[Object]
+    impl Book {
+        async fn id(&self) -> i32 {
+            self.0
+        }
+
+        async fn author(&self) -> Author {
+            Author(self.0 / 10)
+        }
+    }
+
+    #[Interface(field(name = "id", type = "i32"))]
+    enum Node {
+        Author(Author),
+        Book(Book),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn node(&self) -> Node {
+            Author(1).into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        node { ... on Author { id books { id author { id } } } }
+        __type(name: "Book") { fields { name type { kind name ofType { name } } } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "node": { "id": 1, "books": [{ "id": 10, "author": { "id": 1 } }] },
+            "__type": {
+                "fields": [
+                    { "name": "id", "type": { "kind": "NON_NULL", "name": null, "ofType": { "name": "Int" } } },
+                    { "name": "author", "type": { "kind": "NON_NULL", "name": null, "ofType": { "name": "Author" } } },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_recursive_input_object() {
+    #[InputObject]
+    struct Filter {
+        value: i32,
+        not: Option<Box<Filter>>,
+        and: Option<Vec<Filter>>,
+    }
+
+    fn eval(filter: &Filter, n: i32) -> bool {
+        let mut res = filter.value == n;
+        if let Some(not) = &filter.not {
+            res = res && !eval(not, n);
+        }
+        if let Some(and) = &filter.and {
+            res = res && and.iter().all(|filter| eval(filter, n));
+        }
+        res
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn test(&self, filter: Filter) -> bool {
+            eval(&filter, 1)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{
+        a: test(filter: { value: 1, and: [{ value: 1 }] })
+        b: test(filter: { value: 1, not: { value: 1 } })
+    }";
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({ "a": true, "b": false })
+    );
+}
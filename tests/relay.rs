@@ -0,0 +1,103 @@
+use async_graphql::relay::{GlobalId, NodeQuery, NodeType};
+use async_graphql::*;
+
+struct User(i32);
+
+#[Object]
+impl User {
+    async fn id(&self) -> ID {
+        GlobalId::new("User", self.0).encode()
+    }
+
+    async fn name(&self) -> String {
+        format!("user{}", self.0)
+    }
+}
+
+struct Post(String);
+
+#[Object]
+impl Post {
+    async fn id(&self) -> ID {
+        GlobalId::new("Post", &self.0).encode()
+    }
+
+    async fn title(&self) -> &str {
+        &self.0
+    }
+}
+
+#[Interface(field(name = "id", type = "ID"))]
+enum Node {
+    User(User),
+    Post(Post),
+}
+
+#[async_trait::async_trait]
+impl NodeType for Node {
+    async fn fetch(_ctx: &Context<'_>, id: GlobalId) -> FieldResult<Option<Self>> {
+        Ok(match id.type_name.as_str() {
+            "User" => Some(User(id.id.parse()?).into()),
+            "Post" => Some(Post(id.id).into()),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Default)]
+struct MyQuery;
+
+#[Object]
+impl MyQuery {
+    async fn me(&self) -> User {
+        User(1)
+    }
+}
+
+#[MergedObject(name = "Query")]
+#[derive(Default)]
+struct Query(MyQuery, NodeQuery<Node>);
+
+#[test]
+pub fn test_global_id() {
+    let id = GlobalId::new("User", 10);
+    assert_eq!(id.encode().as_str(), "VXNlcjoxMA==");
+    assert_eq!(GlobalId::decode("VXNlcjoxMA==").unwrap(), id);
+    assert_eq!(
+        GlobalId::decode(&GlobalId::new("Post", "a:b").encode()).unwrap(),
+        GlobalId::new("Post", "a:b")
+    );
+    assert!(GlobalId::decode("VXNlcg==").is_err());
+    assert!(GlobalId::decode("!!!").is_err());
+}
+
+#[async_std::test]
+pub async fn test_node_query() {
+    let schema = Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+
+    let query = format!(
+        r#"{{
+            me {{ id }}
+            user: node(id: "{}") {{ id ... on User {{ name }} }}
+            post: node(id: "{}") {{ ... on Post {{ title }} }}
+            unknown: node(id: "{}") {{ id }}
+        }}"#,
+        GlobalId::new("User", 2).encode().as_str(),
+        GlobalId::new("Post", "hello").encode().as_str(),
+        GlobalId::new("Comment", 1).encode().as_str(),
+    );
+    assert_eq!(
+        schema.execute(&query).await.unwrap().data,
+        serde_json::json!({
+            "me": { "id": "VXNlcjox" },
+            "user": { "id": "VXNlcjoy", "name": "user2" },
+            "post": { "title": "hello" },
+            "unknown": null,
+        })
+    );
+
+    assert!(schema
+        .execute(r#"{ node(id: "!!!") { id } }"#)
+        .await
+        .is_err());
+}
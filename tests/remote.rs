@@ -0,0 +1,254 @@
+use async_graphql::remote::{delegate, Remote, RemoteTransport};
+use async_graphql::*;
+use futures::{Stream, StreamExt};
+use std::sync::{Arc, Mutex};
+
+#[SimpleObject]
+struct Book {
+    id: i32,
+    title: String,
+}
+
+#[derive(Default)]
+struct BookService {
+    requests: Mutex<Vec<serde_json::Value>>,
+}
+
+#[async_trait::async_trait]
+impl RemoteTransport for BookService {
+    async fn send(&self, request: serde_json::Value) -> FieldResult<serde_json::Value> {
+        self.requests.lock().unwrap().push(request.clone());
+        if request["query"].as_str().unwrap().contains("id: 0") {
+            return Ok(serde_json::json!({
+                "data": null,
+                "errors": [
+                    {
+                        "message": "Book not found",
+                        "locations": [{ "line": 1, "column": 12 }],
+                        "path": ["b", "title"],
+                        "extensions": { "code": "NOT_FOUND" },
+                    },
+                    {
+                        "message": "Forbidden",
+                        "path": ["b", "id"],
+                    },
+                ],
+            }));
+        }
+        if request["query"].as_str().unwrap().contains("id: 2") {
+            return Ok(serde_json::json!({
+                "data": {
+                    "b": { "id": 2, "title": null },
+                },
+                "errors": [{
+                    "message": "Forbidden",
+                    "path": ["b", "title"],
+                }],
+            }));
+        }
+        Ok(serde_json::json!({
+            "data": {
+                "b": { "id": 1, "myTitle": "abc" },
+            },
+        }))
+    }
+}
+
+struct Library;
+
+#[Object]
+impl Library {
+    async fn book(&self, ctx: &Context<'_>, _id: i32) -> FieldResult<Remote<Book>> {
+        delegate(ctx, ctx.data::<Arc<BookService>>().as_ref()).await
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn library(&self) -> Library {
+        Library
+    }
+}
+
+#[async_std::test]
+pub async fn test_remote_delegate() {
+    let service = Arc::new(BookService::default());
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(service.clone())
+        .finish();
+
+    let query = r#"query($id: Int!, $skip: Boolean = false) {
+        library {
+            b: book(id: $id) { ...BookFields myTitle: title @skip(if: $skip) }
+        }
+    }
+
+    fragment BookFields on Book { id }"#;
+    let res = QueryBuilder::new(query)
+        .variables(Variables::parse_from_json(serde_json::json!({ "id": 1 })).unwrap())
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data,
+        serde_json::json!({
+            "library": {
+                "b": { "id": 1, "myTitle": "abc" },
+            },
+        })
+    );
+
+    let requests = service.requests.lock().unwrap();
+    assert_eq!(
+        requests[0],
+        serde_json::json!({
+            "query": "query($id: Int!, $skip: Boolean = false) { b: book(id: $id) { ...BookFields myTitle: title @skip(if: $skip) } } fragment BookFields on Book { id }",
+            "variables": { "id": 1 },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_remote_delegate_skips_field_directives() {
+    let service = Arc::new(BookService::default());
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(service.clone())
+        .finish();
+
+    let query = r#"query($id: Int!, $include: Boolean!) {
+        library {
+            b: book(id: $id) @include(if: $include) { id }
+        }
+    }"#;
+    QueryBuilder::new(query)
+        .variables(
+            Variables::parse_from_json(serde_json::json!({ "id": 1, "include": true })).unwrap(),
+        )
+        .execute(&schema)
+        .await
+        .unwrap();
+
+    let requests = service.requests.lock().unwrap();
+    assert_eq!(
+        requests[0],
+        serde_json::json!({
+            "query": "query($id: Int!) { b: book(id: $id) { id } }",
+            "variables": { "id": 1 },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_remote_delegate_error() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Arc::new(BookService::default()))
+        .finish();
+
+    let err = schema
+        .execute("{ library { b: book(id: 0) { id title } } }")
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(http::GQLError(&err)).unwrap(),
+        serde_json::json!([
+            {
+                "message": "Book not found",
+                "locations": [{ "line": 1, "column": 13 }],
+                "path": ["library", "b", "title"],
+                "extensions": { "code": "NOT_FOUND" },
+            },
+            {
+                "message": "Forbidden",
+                "locations": [{ "line": 1, "column": 13 }],
+                "path": ["library", "b", "id"],
+            },
+        ])
+    );
+}
+
+#[async_std::test]
+pub async fn test_remote_delegate_partial_data() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Arc::new(BookService::default()))
+        .finish();
+
+    let res = schema
+        .execute("{ library { b: book(id: 2) { id title } } }")
+        .await
+        .unwrap();
+    assert_eq!(
+        res.errors,
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 13
+            }],
+            path: vec!["library".into(), "b".into(), "title".into()],
+            extensions: None,
+        }]
+    );
+    assert_eq!(
+        serde_json::to_value(http::GQLResponse(Ok(res))).unwrap(),
+        serde_json::json!({
+            "data": {
+                "library": {
+                    "b": { "id": 2, "title": null },
+                },
+            },
+            "errors": [{
+                "message": "Forbidden",
+                "locations": [{ "line": 1, "column": 13 }],
+                "path": ["library", "b", "title"],
+            }],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_remote_delegate_subscription_partial_data() {
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn book(
+            &self,
+            ctx: &Context<'_>,
+            _id: i32,
+        ) -> FieldResult<impl Stream<Item = Remote<Book>>> {
+            let book = delegate(ctx, ctx.data::<Arc<BookService>>().as_ref()).await?;
+            Ok(futures::stream::once(async move { book }))
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(Arc::new(BookService::default()))
+        .finish();
+
+    let mut stream = schema
+        .create_subscription_stream(
+            "subscription { b: book(id: 2) { id title } }",
+            None,
+            Default::default(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        stream.next().await,
+        Some(Err(Error::Remote {
+            errors: vec![ServerError {
+                message: "Forbidden".to_string(),
+                locations: vec![Pos {
+                    line: 1,
+                    column: 16
+                }],
+                path: vec!["b".into(), "title".into()],
+                extensions: None,
+            }]
+        }))
+    );
+}
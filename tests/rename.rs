@@ -0,0 +1,88 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_rename_fields_and_args() {
+    #[SimpleObject(rename_fields = "snake_case")]
+    struct MyObj {
+        created_at: i32,
+        #[field(name = "ID")]
+        id: i32,
+    }
+
+    #[InputObject(rename_fields = "PascalCase")]
+    struct MyInput {
+        first_value: i32,
+    }
+
+    #[Enum(rename_items = "lowercase")]
+    enum MyEnum {
+        ValueA,
+        ValueB,
+    }
+
+    struct Query;
+
+    #[Object(rename_fields = "snake_case", rename_args = "SCREAMING_SNAKE_CASE")]
+    impl Query {
+        async fn my_obj(&self) -> MyObj {
+            MyObj {
+                created_at: 10,
+                id: 1,
+            }
+        }
+
+        async fn add_value(&self, first_value: i32, input: MyInput) -> i32 {
+            first_value + input.first_value
+        }
+
+        async fn enum_value(&self, value: MyEnum) -> MyEnum {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        my_obj { created_at ID }
+        add_value(FIRST_VALUE: 1, INPUT: { FirstValue: 2 })
+        enum_value(VALUE: valueb)
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "my_obj": { "created_at": 10, "ID": 1 },
+            "add_value": 3,
+            "enum_value": "valueb",
+        })
+    );
+}
+
+#[async_std::test]
+#[should_panic(expected = "have the same GraphQL name \"MyObj\".")]
+pub async fn test_type_name_conflict() {
+    #[SimpleObject(name = "MyObj")]
+    struct A {
+        value: i32,
+    }
+
+    #[SimpleObject(name = "MyObj")]
+    struct B {
+        value: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn a(&self) -> A {
+            A { value: 1 }
+        }
+
+        async fn b(&self) -> B {
+            B {
+                value: "abc".to_string(),
+            }
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
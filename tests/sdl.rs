@@ -0,0 +1,113 @@
+use async_graphql::*;
+
+/// A color
+#[Enum]
+enum Color {
+    Red,
+    #[item(deprecation = "Use Red instead")]
+    Orange,
+}
+
+#[InputObject]
+struct MyInput {
+    /// The value
+    #[field(default = 10)]
+    value: i32,
+}
+
+/// My object
+///
+/// With a second line
+#[SimpleObject(directive(tag(name = "public")))]
+struct MyObj {
+    /// A value
+    value: i32,
+    #[field(deprecation = "Not used anymore")]
+    old_value: i32,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn obj(&self) -> MyObj {
+        MyObj {
+            value: 1,
+            old_value: 2,
+        }
+    }
+
+    async fn color(
+        &self,
+        #[arg(desc = "The input")] input: MyInput,
+        #[arg(default = 5)] n: i32,
+    ) -> Color {
+        let _ = input.value + n;
+        Color::Red
+    }
+}
+
+struct Uppercase;
+
+#[async_trait::async_trait]
+impl directive::CustomDirective for Uppercase {
+    fn name(&self) -> &'static str {
+        "uppercase"
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Converts a string to uppercase")
+    }
+
+    fn args(&self, args: &mut directive::DirectiveArgs<'_>) {
+        args.arg::<bool>("enabled", None);
+    }
+}
+
+#[test]
+pub fn test_sdl() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .directive(Uppercase)
+        .finish();
+    assert_eq!(
+        schema.sdl(),
+        r#"schema {
+  query: Query
+}
+
+directive @defer on FIELD
+
+directive @stream on FIELD
+
+"Converts a string to uppercase"
+directive @uppercase(enabled: Boolean!) on FIELD
+
+"A color"
+enum Color {
+  RED
+  ORANGE @deprecated(reason: "Use Red instead")
+}
+
+input MyInput {
+  "The value"
+  value: Int! = 10
+}
+
+"""
+My object
+
+With a second line
+"""
+type MyObj @tag(name: "public") {
+  "A value"
+  value: Int!
+  oldValue: Int! @deprecated(reason: "Not used anymore")
+}
+
+type Query {
+  obj: MyObj!
+  color("The input" input: MyInput!, n: Int! = 5): Color!
+}
+"#
+    );
+}
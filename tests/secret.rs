@@ -0,0 +1,78 @@
+use async_graphql::extensions::Extension;
+use async_graphql::*;
+use std::sync::{Arc, Mutex};
+
+struct RecordVariables(Arc<Mutex<Option<Variables>>>);
+
+impl Extension for RecordVariables {
+    fn variables(&self, variables: &Variables) {
+        *self.0.lock().unwrap() = Some(variables.clone());
+    }
+}
+
+#[async_std::test]
+pub async fn test_secret_argument() {
+    #[InputObject]
+    struct Credentials {
+        username: String,
+        password: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn login(&self, username: String, #[arg(secret)] password: String) -> bool {
+            username == "admin" && password == "123456"
+        }
+
+        async fn login2(&self, #[arg(secret)] credentials: Credentials) -> bool {
+            credentials.username == "admin" && credentials.password == "123456"
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let recorded = Arc::new(Mutex::new(None));
+
+    let query = r#"query($username: String!, $password: String!, $password2: String!) {
+        login(username: $username, password: $password)
+        login2(credentials: { username: "admin", password: $password2 })
+    }"#;
+    let res = QueryBuilder::new(query)
+        .variables(
+            Variables::parse_from_json(serde_json::json!({
+                "username": "admin",
+                "password": "123456",
+                "password2": "123456",
+            }))
+            .unwrap(),
+        )
+        .extension({
+            let recorded = recorded.clone();
+            move || RecordVariables(recorded.clone())
+        })
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(
+        res.data,
+        serde_json::json!({
+            "login": true,
+            "login2": true,
+        })
+    );
+
+    let variables = recorded.lock().unwrap().take().unwrap();
+    assert_eq!(
+        variables.get("username"),
+        Some(&Value::String("admin".to_string()))
+    );
+    assert_eq!(
+        variables.get("password"),
+        Some(&Value::String("***".to_string()))
+    );
+    assert_eq!(
+        variables.get("password2"),
+        Some(&Value::String("***".to_string()))
+    );
+}
@@ -0,0 +1,74 @@
+use async_graphql::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+scalar!(Point);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Shape {
+    Circle { radius: f64 },
+    Polygon(Vec<Point>),
+}
+
+scalar!(Shape, "ShapeScalar", "A shape");
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn point(&self, p: Point) -> Point {
+        Point { x: p.y, y: p.x }
+    }
+
+    async fn shape(&self, shape: Option<Shape>) -> Shape {
+        shape.unwrap_or(Shape::Circle { radius: 1.0 })
+    }
+}
+
+#[async_std::test]
+pub async fn test_serde_scalar() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    point(p: { x: 1, y: 2 })
+                    a: shape(shape: { polygon: [{ x: 1, y: 2 }] })
+                    b: shape
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "point": { "x": 2, "y": 1 },
+            "a": { "polygon": [{ "x": 1, "y": 2 }] },
+            "b": { "circle": { "radius": 1.0 } },
+        })
+    );
+
+    assert!(schema.execute("{ point(p: { x: 1 }) }").await.is_err());
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    point: __type(name: "Point") { kind }
+                    shape: __type(name: "ShapeScalar") { kind description }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "point": { "kind": "SCALAR" },
+            "shape": { "kind": "SCALAR", "description": "A shape" },
+        })
+    );
+}
@@ -0,0 +1,169 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_simple_object_by_ref() {
+    // Not `Clone`, the getters of the fields must return references.
+    #[SimpleObject]
+    struct Item {
+        #[field(ref)]
+        name: String,
+    }
+
+    #[SimpleObject(by_ref)]
+    struct Order {
+        items: Vec<Item>,
+        note: String,
+        #[field(owned)]
+        count: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn order(&self, ctx: &Context<'_>) -> FieldResult<i32> {
+            let order = Order {
+                items: vec![Item {
+                    name: "a".to_string(),
+                }],
+                note: "b".to_string(),
+                count: 1,
+            };
+            let items: &Vec<Item> = order.items(ctx).await?;
+            let note: &String = order.note(ctx).await?;
+            let count: i32 = order.count(ctx).await?;
+            Ok(items.len() as i32 + note.len() as i32 + count)
+        }
+
+        async fn value(&self) -> Order {
+            Order {
+                items: vec![Item {
+                    name: "a".to_string(),
+                }],
+                note: "b".to_string(),
+                count: 1,
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ order value { items { name } note count } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "order": 3,
+            "value": { "items": [{ "name": "a" }], "note": "b", "count": 1 },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_simple_object_with() {
+    #[allow(clippy::ptr_arg)]
+    fn parse_tags(value: &String) -> serde_json::Result<Vec<String>> {
+        serde_json::from_str(value)
+    }
+
+    #[SimpleObject]
+    struct Post {
+        #[field(with = "parse_tags", type = "Vec<String>")]
+        tags: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn valid(&self) -> Post {
+            Post {
+                tags: r#"["a", "b"]"#.to_string(),
+            }
+        }
+
+        async fn invalid(&self) -> Post {
+            Post {
+                tags: "[".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ valid { tags } }").await.unwrap().data,
+        serde_json::json!({ "valid": { "tags": ["a", "b"] } })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Post") { fields { type { kind ofType { kind } } } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "fields": [{ "type": { "kind": "NON_NULL", "ofType": { "kind": "LIST" } } }] }
+        })
+    );
+    assert!(schema.execute("{ invalid { tags } }").await.is_err());
+}
+
+mod model {
+    pub struct User {
+        pub id: i32,
+        pub name: String,
+        pub first_name: String,
+        pub last_name: String,
+    }
+}
+
+#[async_std::test]
+pub async fn test_simple_object_remote() {
+    fn full_name(user: &model::User) -> String {
+        format!("{} {}", user.first_name, user.last_name)
+    }
+
+    #[SimpleObject(remote = "model::User")]
+    struct User {
+        id: i32,
+        name: String,
+        #[field(getter = "full_name")]
+        full_name: String,
+        #[field(skip)]
+        cursor: Option<String>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            model::User {
+                id: 1,
+                name: "jack".to_string(),
+                first_name: "Jack".to_string(),
+                last_name: "Smith".to_string(),
+            }
+            .into()
+        }
+    }
+
+    let user = User::from(model::User {
+        id: 2,
+        name: "tom".to_string(),
+        first_name: "Tom".to_string(),
+        last_name: "Jones".to_string(),
+    });
+    assert_eq!(user.full_name, "Tom Jones");
+    assert_eq!(user.cursor, None);
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ user { id name fullName } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "user": { "id": 1, "name": "jack", "fullName": "Jack Smith" } })
+    );
+}
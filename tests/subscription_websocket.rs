@@ -143,6 +143,149 @@ pub async fn test_subscription_ws_transport_with_token() {
     }
 }
 
+#[async_std::test]
+pub async fn test_subscription_ws_transport_connection_params() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    #[derive(serde_derive::Deserialize)]
+    struct Params {
+        locale: String,
+        tenant: Option<i32>,
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn greeting(&self, ctx: &Context<'_>) -> FieldResult<impl Stream<Item = String>> {
+            let params = ctx.data::<ConnectionParams>();
+            assert_eq!(params.get("locale"), Some(&serde_json::json!("fr")));
+            let params: Params = params.decode()?;
+            Ok(futures::stream::once(async move {
+                format!("{}:{:?}", params.locale, params.tenant)
+            }))
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (mut sink, mut stream) = schema.subscription_connection(WebSocketTransport::default());
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+            "payload": { "locale": "fr", "tenant": 7 }
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { greeting }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "data",
+        "id": "1",
+        "payload": { "data": { "greeting": "fr:Some(7)" } },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_connection_params_without_payload() {
+    struct InitPayload(serde_json::Value);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn payloads(&self, ctx: &Context<'_>) -> impl Stream<Item = String> {
+            let value = format!(
+                "{}:{}",
+                ctx.data::<ConnectionParams>().0,
+                ctx.data::<InitPayload>().0
+            );
+            futures::stream::once(async move { value })
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (mut sink, mut stream) = schema.subscription_connection(WebSocketTransport::new(|value| {
+        let mut data = Data::default();
+        data.insert(InitPayload(value));
+        Ok(data)
+    }));
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { payloads }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "data",
+        "id": "1",
+        "payload": { "data": { "payloads": "{}:{}" } },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+}
+
 #[async_std::test]
 pub async fn test_subscription_ws_transport_error() {
     struct QueryRoot;
@@ -234,3 +377,650 @@ pub async fn test_subscription_ws_transport_error() {
         serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
     );
 }
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_init_error() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    let (mut sink, mut stream) =
+        schema.subscription_connection(WebSocketTransport::new(|_| Err("forbidden".into())));
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+            "payload": { "token": "654321" }
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_error",
+        "payload": { "message": "forbidden" },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+    assert!(stream.next().await.is_none());
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_init_error_without_payload() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    let (mut sink, mut stream) = schema.subscription_connection(WebSocketTransport::new(|value| {
+        if value.get("token").is_none() {
+            return Err("forbidden".into());
+        }
+        Ok(Data::default())
+    }));
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_error",
+        "payload": { "message": "forbidden" },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+    assert!(stream.next().await.is_none());
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_with_connection_data() {
+    struct Token(String);
+    struct Locale(String);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self, ctx: &Context<'_>) -> impl Stream<Item = String> {
+            let value = format!("{}:{}", ctx.data::<Token>().0, ctx.data::<Locale>().0);
+            futures::stream::once(async move { value })
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    let mut conn_data = Data::default();
+    conn_data.insert(Locale("en".to_string()));
+    let (mut sink, mut stream) = schema.subscription_connection(
+        WebSocketTransport::new(|value| {
+            let mut data = Data::default();
+            data.insert(Token(value["token"].as_str().unwrap().to_string()));
+            Ok(data)
+        })
+        .with_data(conn_data),
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+            "payload": { "token": "123456" }
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "data",
+        "id": "1",
+        "payload": { "data": { "values": "123456:en" } },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_repeated_init() {
+    struct Token(String);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self, ctx: &Context<'_>) -> impl Stream<Item = String> {
+            let value = ctx.data::<Token>().0.clone();
+            futures::stream::once(async move { value }).chain(futures::stream::pending())
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    for protocol in &[
+        WebSocketProtocols::SubscriptionsTransportWS,
+        WebSocketProtocols::GraphQLWS,
+    ] {
+        let transport = WebSocketTransport::new(|value| {
+            let mut data = Data::default();
+            data.insert(Token(value["token"].as_str().unwrap().to_string()));
+            Ok(data)
+        })
+        .with_protocol(*protocol);
+        let close_reason = transport.close_reason();
+        let (mut sink, mut stream) = schema.subscription_connection(transport);
+        let start = |id: &str| -> bytes::Bytes {
+            serde_json::to_vec(&serde_json::json!({
+                "type": match protocol {
+                    WebSocketProtocols::SubscriptionsTransportWS => "start",
+                    WebSocketProtocols::GraphQLWS => "subscribe",
+                },
+                "id": id,
+                "payload": {
+                    "query": "subscription { values }"
+                },
+            }))
+            .unwrap()
+            .into()
+        };
+        let init = |token: &str| -> bytes::Bytes {
+            serde_json::to_vec(&serde_json::json!({
+                "type": "connection_init",
+                "payload": { "token": token }
+            }))
+            .unwrap()
+            .into()
+        };
+
+        sink.send(init("123456")).await.unwrap();
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "connection_ack",
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+
+        sink.send(start("1")).await.unwrap();
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": match protocol {
+                WebSocketProtocols::SubscriptionsTransportWS => "data",
+                WebSocketProtocols::GraphQLWS => "next",
+            },
+            "id": "1",
+            "payload": { "data": { "values": "123456" } },
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+
+        sink.send(init("654321")).await.unwrap();
+        if *protocol == WebSocketProtocols::SubscriptionsTransportWS {
+            assert_eq!(
+                Some(serde_json::json!({
+                "type": "connection_error",
+                "payload": { "message": "Too many initialisation requests" },
+                })),
+                serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+            );
+        }
+
+        sink.send(start("2")).await.unwrap();
+        assert!(stream.next().await.is_none());
+        assert_eq!(
+            close_reason.get(),
+            match protocol {
+                WebSocketProtocols::SubscriptionsTransportWS => None,
+                WebSocketProtocols::GraphQLWS => {
+                    Some((4429, "Too many initialisation requests".to_string()))
+                }
+            }
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_start_before_init() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    let (mut sink, mut stream) = schema.subscription_connection(WebSocketTransport::default());
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "error",
+        "id": "1",
+        "payload": [{ "message": "Connection is not initialised" }],
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    let transport = WebSocketTransport::default().with_protocol(WebSocketProtocols::GraphQLWS);
+    let close_reason = transport.close_reason();
+    let (mut sink, mut stream) = schema.subscription_connection(transport);
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "subscribe",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+    assert!(stream.next().await.is_none());
+    assert_eq!(close_reason.get(), Some((4401, "Unauthorized".to_string())));
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_graphql_ws_close_codes() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::pending()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let init = || -> bytes::Bytes {
+        serde_json::to_vec(&serde_json::json!({ "type": "connection_init" }))
+            .unwrap()
+            .into()
+    };
+    let subscribe = |id: &str| -> bytes::Bytes {
+        serde_json::to_vec(&serde_json::json!({
+            "type": "subscribe",
+            "id": id,
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap()
+        .into()
+    };
+
+    // The init function rejects the connection
+    let transport = WebSocketTransport::new(|_| Err("forbidden".into()))
+        .with_protocol(WebSocketProtocols::GraphQLWS);
+    let close_reason = transport.close_reason();
+    let (mut sink, mut stream) = schema.subscription_connection(transport);
+    sink.send(init()).await.unwrap();
+    assert!(stream.next().await.is_none());
+    assert_eq!(close_reason.get(), Some((4403, "Forbidden".to_string())));
+
+    // Duplicate subscriber
+    let transport = WebSocketTransport::default().with_protocol(WebSocketProtocols::GraphQLWS);
+    let close_reason = transport.close_reason();
+    let (mut sink, mut stream) = schema.subscription_connection(transport);
+    sink.send(init()).await.unwrap();
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+    sink.send(subscribe("1")).await.unwrap();
+    sink.send(subscribe("1")).await.unwrap();
+    assert!(stream.next().await.is_none());
+    assert_eq!(
+        close_reason.get(),
+        Some((4409, "Subscriber for 1 already exists".to_string()))
+    );
+
+    // Invalid messages
+    for message in &[
+        serde_json::json!({ "type": "unknown" }),
+        serde_json::json!({ "type": "subscribe", "id": "1" }),
+    ] {
+        let transport = WebSocketTransport::default().with_protocol(WebSocketProtocols::GraphQLWS);
+        let close_reason = transport.close_reason();
+        let (mut sink, mut stream) = schema.subscription_connection(transport);
+        sink.send(init()).await.unwrap();
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "connection_ack",
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+        sink.send(serde_json::to_vec(message).unwrap().into())
+            .await
+            .unwrap();
+        assert!(stream.next().await.is_none());
+        assert_eq!(close_reason.get().map(|(code, _)| code), Some(4400));
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_graphql_ws_protocol() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..3)
+        }
+    }
+
+    assert_eq!(
+        WebSocketProtocols::from_sec_websocket_protocol("graphql-transport-ws, graphql-ws"),
+        WebSocketProtocols::GraphQLWS
+    );
+    assert_eq!(
+        WebSocketProtocols::from_sec_websocket_protocol("graphql-ws"),
+        WebSocketProtocols::SubscriptionsTransportWS
+    );
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (mut sink, mut stream) = schema.subscription_connection(
+        WebSocketTransport::default().with_protocol(WebSocketProtocols::GraphQLWS),
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "ping",
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "pong",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "subscribe",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    for i in 0..3 {
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "next",
+            "id": "1",
+            "payload": { "data": { "values": i } },
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_server() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..3)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (mut client_tx, server_rx) = futures::channel::mpsc::unbounded::<String>();
+    let (server_tx, mut client_rx) = futures::channel::mpsc::unbounded::<String>();
+    let (events_tx, mut events_rx) = futures::channel::mpsc::unbounded();
+    let connect_tx = events_tx.clone();
+
+    let server = async_std::task::spawn(
+        SubscriptionServer::new(schema)
+            .on_connect(move || connect_tx.unbounded_send("connect").unwrap())
+            .on_disconnect(move || events_tx.unbounded_send("disconnect").unwrap())
+            .serve(server_tx, server_rx),
+    );
+    assert_eq!(events_rx.next().await, Some("connect"));
+
+    client_tx
+        .send(serde_json::json!({ "type": "connection_init" }).to_string())
+        .await
+        .unwrap();
+    assert_eq!(
+        serde_json::json!({ "type": "connection_ack" }),
+        serde_json::from_str::<serde_json::Value>(&client_rx.next().await.unwrap()).unwrap()
+    );
+
+    client_tx
+        .send(
+            serde_json::json!({
+                "type": "start",
+                "id": "1",
+                "payload": { "query": "subscription { values }" },
+            })
+            .to_string(),
+        )
+        .await
+        .unwrap();
+    for i in 0..3 {
+        assert_eq!(
+            serde_json::json!({
+                "type": "data",
+                "id": "1",
+                "payload": { "data": { "values": i } },
+            }),
+            serde_json::from_str::<serde_json::Value>(&client_rx.next().await.unwrap()).unwrap()
+        );
+    }
+
+    drop(client_tx);
+    server.await;
+    assert_eq!(events_rx.next().await, Some("disconnect"));
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_complete() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self, count: i32) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..count)
+        }
+    }
+
+    let client = test::TestClient::new(Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot));
+    let mut ws = client.websocket();
+    ws.init(None).await;
+
+    ws.start(
+        "1",
+        "subscription($count: Int!) { values(count: $count) }",
+        Some(serde_json::json!({ "count": 2 })),
+    )
+    .await;
+    ws.assert_data("1", serde_json::json!({ "values": 0 }))
+        .await;
+    ws.assert_data("1", serde_json::json!({ "values": 1 }))
+        .await;
+    ws.assert_complete("1").await;
+
+    ws.start("2", "subscription { unknown }", None).await;
+    let payload = ws.assert_error("2").await;
+    assert!(payload.to_string().contains("unknown"));
+
+    ws.start("3", "subscription { values(count: 0) }", None)
+        .await;
+    ws.assert_complete("3").await;
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_max_query_length() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::pending()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let transport = WebSocketTransport::default().with_max_query_length(10);
+    let close_reason = transport.close_reason();
+    let (mut sink, mut stream) = schema.subscription_connection(transport);
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+    assert!(stream.next().await.is_none());
+    assert_eq!(
+        close_reason.get(),
+        Some((1008, "Query is too long".to_string()))
+    );
+}
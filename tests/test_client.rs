@@ -0,0 +1,78 @@
+use async_graphql::test::TestClient;
+use async_graphql::*;
+use std::io::Read;
+
+struct Token(String);
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn value(&self, n: i32) -> i32 {
+        n
+    }
+
+    async fn token(&self, ctx: &Context<'_>) -> FieldResult<String> {
+        ctx.data_opt::<Token>()
+            .map(|token| token.0.clone())
+            .ok_or_else(|| {
+                "Unauthorized".extend_with(|_| serde_json::json!({ "code": "UNAUTHORIZED" }))
+            })
+    }
+}
+
+struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn upload(&self, file: Upload) -> String {
+        let mut content = String::new();
+        file.into_read().read_to_string(&mut content).unwrap();
+        content
+    }
+}
+
+#[async_std::test]
+pub async fn test_test_client() {
+    let client = TestClient::new(Schema::new(QueryRoot, MutationRoot, EmptySubscription));
+
+    client
+        .query("query($n: Int!) { value(n: $n) }")
+        .variables(serde_json::json!({ "n": 10 }))
+        .execute()
+        .await
+        .assert_data(serde_json::json!({ "value": 10 }));
+
+    client
+        .query("{ token }")
+        .data(Token("123456".to_string()))
+        .execute()
+        .await
+        .assert_data(serde_json::json!({ "token": "123456" }));
+
+    client
+        .query("{ token }")
+        .execute()
+        .await
+        .assert_error_code("UNAUTHORIZED")
+        .assert_error_message("Unauthorized");
+
+    client
+        .query("mutation($file: Upload!) { upload(file: $file) }")
+        .variables(serde_json::json!({ "file": null }))
+        .upload("variables.file", "a.txt", Some("text/plain"), "hello")
+        .execute()
+        .await
+        .assert_data(serde_json::json!({ "upload": "hello" }));
+}
+
+#[async_std::test]
+#[should_panic(expected = "Expected an error with code FORBIDDEN")]
+pub async fn test_test_client_assert_error_code() {
+    let client = TestClient::new(Schema::new(QueryRoot, MutationRoot, EmptySubscription));
+    client
+        .query("{ token }")
+        .execute()
+        .await
+        .assert_error_code("FORBIDDEN");
+}
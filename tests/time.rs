@@ -0,0 +1,52 @@
+#![cfg(feature = "time")]
+
+use async_graphql::*;
+use time::{Date, OffsetDateTime, Time};
+
+#[async_std::test]
+pub async fn test_time_scalars() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn date_time(&self, value: OffsetDateTime) -> OffsetDateTime {
+            value
+        }
+
+        async fn date(&self, value: Date) -> Date {
+            value
+        }
+
+        async fn time(&self, value: Time) -> Time {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    dateTime(value: "2024-01-31T13:45:00+08:00")
+                    date(value: "2024-01-31")
+                    time(value: "13:45:00")
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "dateTime": "2024-01-31T13:45:00+08:00",
+            "date": "2024-01-31",
+            "time": "13:45:00",
+        })
+    );
+
+    for query in &[
+        r#"{ dateTime(value: "2024-01-31 13:45:00") }"#,
+        r#"{ date(value: "2024-02-30") }"#,
+        r#"{ time(value: "25:00:00") }"#,
+    ] {
+        assert!(schema.execute(query).await.is_err());
+    }
+}
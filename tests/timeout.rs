@@ -0,0 +1,38 @@
+use async_graphql::*;
+use std::time::Duration;
+
+#[async_std::test]
+pub async fn test_timeout() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self, delay: u64) -> i32 {
+            async_std::task::sleep(Duration::from_millis(delay)).await;
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let resp = QueryBuilder::new("{ value(delay: 0) }")
+        .timeout(Duration::from_millis(500))
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 10 }));
+
+    let err = QueryBuilder::new("{ value(delay: 1000) }")
+        .timeout(Duration::from_millis(50))
+        .execute(&schema)
+        .await
+        .unwrap_err();
+    assert_eq!(err, Error::Timeout);
+    assert_eq!(
+        serde_json::to_value(http::GQLError(&err)).unwrap(),
+        serde_json::json!([{
+            "message": "Query timeout",
+            "extensions": { "code": "TIMEOUT" },
+        }])
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "tower")]
+
+use async_graphql::http::GraphQLService;
+use async_graphql::*;
+use bytes::Bytes;
+use tower_service::Service;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn value(&self, n: i32) -> i32 {
+        n
+    }
+}
+
+fn body(data: &str) -> impl futures::Stream<Item = std::io::Result<Bytes>> + Unpin {
+    futures::stream::iter(vec![Ok(Bytes::from(data.to_string()))])
+}
+
+#[async_std::test]
+pub async fn test_tower_service() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let mut service = GraphQLService::new(schema);
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body(body(r#"{"query": "{ value(n: 10) }"}"#))
+        .unwrap();
+    let resp = service.call(req).await.unwrap();
+    assert_eq!(resp.status(), ::http::StatusCode::OK);
+    assert_eq!(resp.headers()["content-type"], "application/json");
+    assert_eq!(resp.body(), r#"{"data":{"value":10}}"#);
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body(body(
+            r#"[{"query": "{ value(n: 1) }"}, {"query": "{ value(n: 2) }"}]"#,
+        ))
+        .unwrap();
+    let resp = service.call(req).await.unwrap();
+    assert_eq!(
+        resp.body(),
+        r#"[{"data":{"value":1}},{"data":{"value":2}}]"#
+    );
+
+    let req = ::http::Request::post("/")
+        .header("content-type", "application/json")
+        .body(body("not json"))
+        .unwrap();
+    let resp = service.call(req).await.unwrap();
+    assert_eq!(resp.status(), ::http::StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()["cache-control"], "no-store");
+}
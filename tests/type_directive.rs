@@ -0,0 +1,87 @@
+use async_graphql::*;
+
+#[SimpleObject(directive(tag(name = "internal")))]
+struct MyObj {
+    #[field(directive(tag(name = "secret", weight = 2), shareable))]
+    a: i32,
+}
+
+#[Enum(directive(tag(name = "enum")))]
+enum MyEnum {
+    A,
+}
+
+#[InputObject]
+struct MyInput {
+    #[field(directive(tag(name = "input")))]
+    a: i32,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn obj(&self) -> MyObj {
+        MyObj { a: 1 }
+    }
+
+    async fn value(&self, input: MyInput) -> MyEnum {
+        let _ = input.a;
+        MyEnum::A
+    }
+}
+
+#[async_std::test]
+pub async fn test_type_directive_sdl() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_federation()
+        .finish();
+    let sdl = schema.execute("{ _service { sdl } }").await.unwrap().data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    assert!(sdl.contains(r#"type MyObj @tag(name: "internal") {"#));
+    assert!(sdl.contains(r#"a: Int! @tag(name: "secret", weight: 2) @shareable"#));
+    assert!(sdl.contains(r#"enum MyEnum @tag(name: "enum") {"#));
+    assert!(sdl.contains(r#"a: Int! @tag(name: "input")"#));
+}
+
+#[async_std::test]
+pub async fn test_type_directive_introspection() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        obj: __type(name: "MyObj") {
+            appliedDirectives { name args { name value } }
+            fields { appliedDirectives { name args { name value } } }
+        }
+        input: __type(name: "MyInput") {
+            inputFields { appliedDirectives { name } }
+        }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "obj": {
+                "appliedDirectives": [
+                    { "name": "tag", "args": [{ "name": "name", "value": "\"internal\"" }] },
+                ],
+                "fields": [{
+                    "appliedDirectives": [
+                        {
+                            "name": "tag",
+                            "args": [
+                                { "name": "name", "value": "\"secret\"" },
+                                { "name": "weight", "value": "2" },
+                            ],
+                        },
+                        { "name": "shareable", "args": [] },
+                    ],
+                }],
+            },
+            "input": {
+                "inputFields": [{ "appliedDirectives": [{ "name": "tag" }] }],
+            },
+        })
+    );
+}
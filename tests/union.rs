@@ -285,3 +285,55 @@ pub async fn test_union_field_result() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_union_derive() {
+    #[async_graphql::SimpleObject]
+    struct MyObj {
+        id: i32,
+    }
+
+    #[derive(GQLUnion)]
+    #[graphql(name = "MyNode", desc = "A node")]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn node(&self) -> Node {
+            MyObj { id: 33 }.into()
+        }
+    }
+
+    let query = r#"{
+            node {
+                __typename
+                ... on MyObj {
+                    id
+                }
+            }
+            __type(name: "MyNode") {
+                kind
+                description
+                possibleTypes { name }
+            }
+        }"#;
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "node": {
+                "__typename": "MyObj",
+                "id": 33,
+            },
+            "__type": {
+                "kind": "UNION",
+                "description": "A node",
+                "possibleTypes": [{ "name": "MyObj" }],
+            }
+        })
+    );
+}
@@ -0,0 +1,288 @@
+use async_graphql::*;
+use futures::AsyncReadExt;
+use std::io::Read;
+
+fn multipart_body(parts: &[(&str, Option<&str>, &str)]) -> Vec<u8> {
+    let mut body = String::new();
+    for (name, filename, content) in parts {
+        body.push_str("--abbc761f78ff4d7cb7573b5a23f96ef0\r\n");
+        match filename {
+            Some(filename) => body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                name, filename
+            )),
+            None => body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{}\"\r\n",
+                name
+            )),
+        }
+        body.push_str("\r\n");
+        body.push_str(content);
+        body.push_str("\r\n");
+    }
+    body.push_str("--abbc761f78ff4d7cb7573b5a23f96ef0--\r\n");
+    body.into_bytes()
+}
+
+const CONTENT_TYPE: &str = "multipart/form-data; boundary=abbc761f78ff4d7cb7573b5a23f96ef0";
+
+struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn upload(&self, file: Upload) -> String {
+        let filename = file.filename().to_string();
+        let mut content = String::new();
+        file.into_read().read_to_string(&mut content).unwrap();
+        format!("{}:{}", filename, content)
+    }
+
+    async fn upload_async(&self, file: Upload) -> String {
+        let filename = file.filename().to_string();
+        let mut content = String::new();
+        file.into_async_read()
+            .read_to_string(&mut content)
+            .await
+            .unwrap();
+        format!("{}:{}", filename, content)
+    }
+}
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {}
+
+#[async_std::test]
+pub async fn test_upload() {
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let body = multipart_body(&[
+        (
+            "operations",
+            None,
+            r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#,
+        ),
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("0", Some("a.txt"), "hello"),
+    ]);
+    let builder = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder()
+        .await
+        .unwrap();
+    assert_eq!(
+        builder.execute(&schema).await.unwrap().data,
+        serde_json::json!({ "upload": "a.txt:hello" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_upload_async_read() {
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let body = multipart_body(&[
+        (
+            "operations",
+            None,
+            r#"{ "query": "mutation ($file: Upload!) { uploadAsync(file: $file) }", "variables": { "file": null } }"#,
+        ),
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("0", Some("a.txt"), "hello"),
+    ]);
+    let builder = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder()
+        .await
+        .unwrap();
+    assert_eq!(
+        builder.execute(&schema).await.unwrap().data,
+        serde_json::json!({ "uploadAsync": "a.txt:hello" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_upload_parts_in_any_order() {
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let body = multipart_body(&[
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("0", Some("a.txt"), "hello"),
+        (
+            "operations",
+            None,
+            r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#,
+        ),
+    ]);
+    let builder = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder()
+        .await
+        .unwrap();
+    assert_eq!(
+        builder.execute(&schema).await.unwrap().data,
+        serde_json::json!({ "upload": "a.txt:hello" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_upload_too_large() {
+    let body = multipart_body(&[
+        (
+            "operations",
+            None,
+            r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#,
+        ),
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("0", Some("a.txt"), "hello world"),
+    ]);
+    let res = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&IntoQueryBuilderOpts {
+            max_file_size: Some(5),
+            max_num_files: Some(1),
+            ..Default::default()
+        })
+        .await;
+    assert!(matches!(res, Err(ParseRequestError::PayloadTooLarge)));
+}
+
+#[async_std::test]
+pub async fn test_upload_too_many_files() {
+    let body = multipart_body(&[
+        (
+            "operations",
+            None,
+            r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#,
+        ),
+        (
+            "map",
+            None,
+            r#"{ "0": ["variables.file"], "1": ["variables.file"] }"#,
+        ),
+        ("0", Some("a.txt"), "hello"),
+        ("1", Some("b.txt"), "world"),
+    ]);
+    let res = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&IntoQueryBuilderOpts {
+            max_file_size: None,
+            max_num_files: Some(1),
+            ..Default::default()
+        })
+        .await;
+    assert!(matches!(res, Err(ParseRequestError::TooManyFiles)));
+}
+
+#[async_std::test]
+pub async fn test_request_too_large() {
+    let body = br#"{ "query": "{ __typename }" }"#.to_vec();
+    let opts = IntoQueryBuilderOpts {
+        max_request_size: Some(body.len()),
+        ..Default::default()
+    };
+    assert!((
+        Some("application/json"),
+        futures::io::Cursor::new(body.clone())
+    )
+        .into_query_builder_opts(&opts)
+        .await
+        .is_ok());
+
+    let opts = IntoQueryBuilderOpts {
+        max_request_size: Some(body.len() - 1),
+        ..Default::default()
+    };
+    let res = (Some("application/json"), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&opts)
+        .await;
+    assert!(matches!(res, Err(ParseRequestError::PayloadTooLarge)));
+}
+
+#[async_std::test]
+pub async fn test_upload_operations_too_large() {
+    let operations = r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#;
+    let body = multipart_body(&[
+        ("operations", None, operations),
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("0", Some("a.txt"), &"a".repeat(operations.len() * 2)),
+    ]);
+    let opts = IntoQueryBuilderOpts {
+        max_request_size: Some(operations.len()),
+        ..Default::default()
+    };
+    assert!((Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&opts)
+        .await
+        .is_ok());
+
+    let body = multipart_body(&[
+        ("operations", None, operations),
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("0", Some("a.txt"), "hello"),
+    ]);
+    let opts = IntoQueryBuilderOpts {
+        max_request_size: Some(operations.len() - 1),
+        ..Default::default()
+    };
+    let res = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&opts)
+        .await;
+    assert!(matches!(res, Err(ParseRequestError::PayloadTooLarge)));
+}
+
+#[async_std::test]
+pub async fn test_multipart_request_too_large() {
+    let operations = r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#;
+    let padding = "a".repeat(128 * 1024);
+    let body = multipart_body(&[
+        ("operations", None, operations),
+        ("map", None, r#"{ "0": ["variables.file"] }"#),
+        ("padding", None, &padding),
+        ("0", Some("a.txt"), "hello"),
+    ]);
+
+    let opts = IntoQueryBuilderOpts {
+        max_file_size: Some(5),
+        max_num_files: Some(1),
+        max_request_size: Some(operations.len()),
+        ..Default::default()
+    };
+    let res = (Some(CONTENT_TYPE), futures::io::Cursor::new(body.clone()))
+        .into_query_builder_opts(&opts)
+        .await;
+    assert!(matches!(res, Err(ParseRequestError::PayloadTooLarge)));
+
+    // The whole request is only limited when all the limits are set.
+    let opts = IntoQueryBuilderOpts {
+        max_request_size: None,
+        ..opts
+    };
+    assert!((Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&opts)
+        .await
+        .is_ok());
+}
+
+#[async_std::test]
+pub async fn test_upload_spill_threshold() {
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let body = multipart_body(&[
+        (
+            "operations",
+            None,
+            r#"{ "query": "mutation ($a: Upload!, $b: Upload!) { a: upload(file: $a) b: uploadAsync(file: $b) }", "variables": { "a": null, "b": null } }"#,
+        ),
+        (
+            "map",
+            None,
+            r#"{ "0": ["variables.a"], "1": ["variables.b"] }"#,
+        ),
+        ("0", Some("a.txt"), "hi"),
+        ("1", Some("b.txt"), "hello"),
+    ]);
+    let opts = IntoQueryBuilderOpts {
+        file_spill_threshold: Some(4),
+        ..Default::default()
+    };
+    let builder = (Some(CONTENT_TYPE), futures::io::Cursor::new(body))
+        .into_query_builder_opts(&opts)
+        .await
+        .unwrap();
+    assert_eq!(
+        builder.execute(&schema).await.unwrap().data,
+        serde_json::json!({ "a": "a.txt:hi", "b": "b.txt:hello" })
+    );
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "url")]
+
+use async_graphql::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use url::Url;
+
+#[async_std::test]
+pub async fn test_url() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn avatar_url(&self, ctx: &Context<'_>, value: Url) -> Url {
+            ctx.data::<Arc<AtomicUsize>>()
+                .fetch_add(1, Ordering::SeqCst);
+            value
+        }
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(calls.clone())
+        .finish();
+    assert_eq!(
+        schema
+            .execute(r#"{ avatarUrl(value: "https://example.com/a.png") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "avatarUrl": "https://example.com/a.png" })
+    );
+
+    match schema
+        .execute(r#"{ avatarUrl(value: "not a url") }"#)
+        .await
+        .err()
+        .unwrap()
+    {
+        Error::Rule { errors } => assert_eq!(errors.len(), 1),
+        err => panic!("unexpected error: {:?}", err),
+    }
+
+    match QueryBuilder::new("query($url: Url!) { avatarUrl(value: $url) }")
+        .variables(Variables::parse_from_json(serde_json::json!({ "url": "/a.png" })).unwrap())
+        .execute(&schema)
+        .await
+        .err()
+        .unwrap()
+    {
+        Error::Query {
+            err: QueryError::ParseInputValue { reason },
+            ..
+        } => assert_eq!(
+            reason,
+            r#"Invalid Url "/a.png": relative URL without a base"#
+        ),
+        err => panic!("unexpected error: {:?}", err),
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "uuid")]
+
+use async_graphql::*;
+use uuid::Uuid;
+
+#[async_std::test]
+pub async fn test_uuid() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn uuid(&self, value: Uuid) -> Uuid {
+            value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    hyphenated: uuid(value: "67e55044-10b1-426f-9247-bb680e5fe0c8")
+                    simple: uuid(value: "67e5504410b1426f9247bb680e5fe0c8")
+                    upper: uuid(value: "67E55044-10B1-426F-9247-BB680E5FE0C8")
+                    urn: uuid(value: "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8")
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "hyphenated": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "simple": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "upper": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "urn": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        })
+    );
+    assert!(schema.execute(r#"{ uuid(value: "abc") }"#).await.is_err());
+}
@@ -0,0 +1,371 @@
+use async_graphql::*;
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+fn is_admin(ctx: &VisibleContext<'_>) -> bool {
+    ctx.data_opt::<Role>() == Some(&Role::Admin)
+}
+
+#[async_std::test]
+pub async fn test_visible_field() {
+    #[SimpleObject]
+    struct MyObj {
+        a: i32,
+        #[field(visible = "is_admin")]
+        b: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 1, b: 2 }
+        }
+
+        #[field(visible = "is_admin")]
+        async fn secret(&self) -> i32 {
+            42
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = r#"{ __type(name: "Query") { fields { name } } }"#;
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Admin)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "fields": [{ "name": "obj" }, { "name": "secret" }] }
+        })
+    );
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Guest)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "fields": [{ "name": "obj" }] }
+        })
+    );
+
+    let query = "{ secret obj { a b } }";
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Admin)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "secret": 42,
+            "obj": { "a": 1, "b": 2 },
+        })
+    );
+
+    let query = "{ secret }";
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Guest)
+            .execute(&schema)
+            .await
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 3 }],
+                message: r#"Unknown field "secret" on type "Query"."#.to_string(),
+            }],
+        }
+    );
+
+    let query = "{ obj { b } }";
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Guest)
+            .execute(&schema)
+            .await
+            .unwrap_err(),
+        Error::Rule {
+            errors: vec![RuleError {
+                locations: vec![Pos { line: 1, column: 9 }],
+                message: r#"Unknown field "b" on type "MyObj"."#.to_string(),
+            }],
+        }
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .validation_mode(ValidationMode::Fast)
+        .finish();
+    assert!(QueryBuilder::new("{ secret }")
+        .data(Role::Guest)
+        .execute(&schema)
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_visible_type_and_enum_item() {
+    #[Enum]
+    enum MyEnum {
+        A,
+        #[item(visible = "is_admin")]
+        B,
+    }
+
+    #[SimpleObject(visible = "is_admin")]
+    struct AdminObj {
+        value: i32,
+    }
+
+    #[InputObject]
+    struct MyInput {
+        a: i32,
+        #[field(visible = "is_admin", default)]
+        b: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput) -> MyEnum {
+            let _ = input.a + input.b;
+            MyEnum::A
+        }
+
+        #[field(visible = "is_admin")]
+        async fn admin(&self) -> AdminObj {
+            AdminObj { value: 1 }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = r#"{
+        obj: __type(name: "AdminObj") { name }
+        enum: __type(name: "MyEnum") { enumValues { name } }
+        input: __type(name: "MyInput") { inputFields { name } }
+    }"#;
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Admin)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "obj": { "name": "AdminObj" },
+            "enum": { "enumValues": [{ "name": "A" }, { "name": "B" }] },
+            "input": { "inputFields": [{ "name": "a" }, { "name": "b" }] },
+        })
+    );
+    assert_eq!(
+        QueryBuilder::new(query)
+            .data(Role::Guest)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "obj": null,
+            "enum": { "enumValues": [{ "name": "A" }] },
+            "input": { "inputFields": [{ "name": "a" }] },
+        })
+    );
+
+    let query = "{ __schema { types { name } } }";
+    let has_admin_obj = |data: serde_json::Value| {
+        data["__schema"]["types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|ty| ty["name"] == "AdminObj")
+    };
+    assert!(has_admin_obj(
+        QueryBuilder::new(query)
+            .data(Role::Admin)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data
+    ));
+    assert!(!has_admin_obj(
+        QueryBuilder::new(query)
+            .data(Role::Guest)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data
+    ));
+}
+
+#[async_std::test]
+pub async fn test_visible_validation() {
+    #[Enum]
+    enum MyEnum {
+        A,
+        #[item(visible = "is_admin")]
+        B,
+    }
+
+    #[SimpleObject(visible = "is_admin")]
+    struct AdminObj {
+        value: i32,
+    }
+
+    #[InputObject(visible = "is_admin")]
+    struct AdminInput {
+        value: i32,
+    }
+
+    #[InputObject]
+    struct MyInput {
+        a: i32,
+        #[field(visible = "is_admin", default)]
+        b: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput, value: Option<MyEnum>) -> i32 {
+            let _ = value;
+            input.a + input.b
+        }
+
+        async fn admin(&self) -> AdminObj {
+            AdminObj { value: 1 }
+        }
+
+        async fn admin_input(&self, input: Option<AdminInput>) -> i32 {
+            input.map(|input| input.value).unwrap_or_default()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let errors = |query: &str, variables: serde_json::Value, role: Role| {
+        let schema = schema.clone();
+        let query = query.to_string();
+        async move {
+            match QueryBuilder::new(query)
+                .variables(Variables::parse_from_json(variables).unwrap())
+                .data(role)
+                .execute(&schema)
+                .await
+            {
+                Ok(_) => Vec::new(),
+                Err(Error::Rule { errors }) => errors.into_iter().map(|err| err.message).collect(),
+                Err(err) => panic!("unexpected error: {:?}", err),
+            }
+        }
+    };
+
+    let queries: Vec<(&str, serde_json::Value, &[&str])> = vec![
+        (
+            "{ admin { value } }",
+            serde_json::json!({}),
+            &[r#"Unknown field "admin" on type "Query"."#],
+        ),
+        (
+            "{ adminInput(input: { value: 1 }) }",
+            serde_json::json!({}),
+            &[r#"Unknown argument "input" on field "adminInput" of type "Query"."#],
+        ),
+        (
+            "query($input: AdminInput) { adminInput(input: $input) }",
+            serde_json::json!({ "input": { "value": 1 } }),
+            &[
+                r#"Unknown type "AdminInput""#,
+                r#"Unknown argument "input" on field "adminInput" of type "Query"."#,
+            ],
+        ),
+        (
+            "{ admin { ...AdminFields } } fragment AdminFields on AdminObj { value }",
+            serde_json::json!({}),
+            &[
+                r#"Unknown field "admin" on type "Query"."#,
+                r#"Unknown type "AdminObj""#,
+            ],
+        ),
+        (
+            "{ value(input: { a: 1 }, value: B) }",
+            serde_json::json!({}),
+            &[
+                r#"Invalid value for argument "value", enumeration type "MyEnum" does not contain the value "B""#,
+            ],
+        ),
+        (
+            "query($value: MyEnum) { value(input: { a: 1 }, value: $value) }",
+            serde_json::json!({ "value": "B" }),
+            &[
+                r#"Invalid value for variable "value", enumeration type "MyEnum" does not contain the value "B""#,
+            ],
+        ),
+        (
+            "{ value(input: { a: 1, b: 2 }) }",
+            serde_json::json!({}),
+            &[r#"Invalid value for argument "input", unknown field "b" of type "MyInput""#],
+        ),
+        (
+            "query($input: MyInput!) { value(input: $input) }",
+            serde_json::json!({ "input": { "a": 1, "b": 2 } }),
+            &[r#"Invalid value for variable "input", unknown field "b" of type "MyInput""#],
+        ),
+    ];
+
+    for (query, variables, messages) in queries {
+        assert_eq!(
+            errors(query, variables.clone(), Role::Guest).await,
+            messages,
+            "{}",
+            query
+        );
+        assert!(
+            errors(query, variables, Role::Admin).await.is_empty(),
+            "{}",
+            query
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_visible_schema_data() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        #[field(visible = "is_admin")]
+        async fn secret(&self) -> i32 {
+            42
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Role::Admin)
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ secret }").await.unwrap().data,
+        serde_json::json!({ "secret": 42 })
+    );
+    assert!(QueryBuilder::new("{ secret }")
+        .data(Role::Guest)
+        .execute(&schema)
+        .await
+        .is_err());
+}